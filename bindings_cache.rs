@@ -0,0 +1,254 @@
+//! A cache of generated bindings shared by all builds of the user, so that bindgen runs once per
+//! SCIP installation rather than once per target directory and profile.
+//!
+//! An entry is `<cache>/bindings-<key>.rs`, where the key is a SHA-256 of everything the bindings
+//! are made from: SCIP's headers, the version of bindgen, the bindgen flags (clang arguments,
+//! blocklists, ...), `BINDGEN_EXTRA_CLANG_ARGS`, the libclang chosen by `LIBCLANG_PATH`, the target
+//! and the code of the build script, which configures the callbacks. Computing the key does not
+//! need libclang, so a hit builds without it. Next to the bindings, `bindings-<key>.untranslated`
+//! lists the macros the callbacks could not translate. The cache is `SCIP_SYS_CACHE_DIR`, or
+//! `scip-sys` in the user's cache directory. `SCIP_SYS_NO_CACHE=1` turns it off, and
+//! `SCIP_SYS_REGENERATE_BINDINGS` bypasses it.
+
+use crate::error::{BuildError, Result};
+use crate::header_modules::SCIP_DIRECTORIES;
+use crate::{paths, report};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The parts of the build script that decide what bindgen generates.
+const GENERATOR: [&str; 5] = [
+    include_str!("build.rs"),
+    include_str!("callback.rs"),
+    include_str!("decl_aliases.rs"),
+    include_str!("doxygen.rs"),
+    include_str!("header_modules.rs"),
+];
+
+/// Where the bindings for one set of inputs are cached.
+pub struct Entry {
+    pub path: PathBuf,
+    /// The headers that went into the key
+    headers: Vec<PathBuf>,
+}
+
+impl Entry {
+    /// The entry for the bindings `builder` generates from the headers in `include_dir`. `None`
+    /// when the cache is turned off or there is no cache directory.
+    pub fn new(
+        builder: &bindgen::Builder,
+        include_dir: &Path,
+        out_path: &Path,
+    ) -> Result<Option<Entry>> {
+        println!("cargo:rerun-if-env-changed=SCIP_SYS_NO_CACHE");
+        println!("cargo:rerun-if-env-changed=SCIP_SYS_CACHE_DIR");
+        if matches!(env::var("SCIP_SYS_NO_CACHE").as_deref(), Ok(value) if !matches!(value, "" | "0" | "false"))
+        {
+            return Ok(None);
+        }
+        let Some(dir) = cache_dir() else {
+            return Ok(None);
+        };
+        let Some(bindgen_version) = bindgen_version() else {
+            report::note("Not caching the bindings, as the version of bindgen is unknown");
+            return Ok(None);
+        };
+
+        let mut key = Sha256::new();
+        let mut field = |name: &str, value: &[u8]| {
+            key.update(name.as_bytes());
+            key.update((value.len() as u64).to_le_bytes());
+            key.update(value);
+        };
+        field("scip-sys", env!("CARGO_PKG_VERSION").as_bytes());
+        for source in GENERATOR {
+            field("generator", source.as_bytes());
+        }
+        field("bindgen", bindgen_version.as_bytes());
+        let target = env::var("TARGET").unwrap_or_default();
+        field("target", target.as_bytes());
+        // the wrapper header lives in OUT_DIR, which differs between profiles and target dirs,
+        // but is made from the headers and the generator only
+        let out_dir = paths::utf8(out_path)?;
+        for flag in builder.command_line_flags() {
+            field("flag", flag.replace(&out_dir, "<OUT_DIR>").as_bytes());
+        }
+        for var in [
+            "LIBCLANG_PATH".to_string(),
+            "BINDGEN_EXTRA_CLANG_ARGS".to_string(),
+            format!("BINDGEN_EXTRA_CLANG_ARGS_{target}"),
+            format!("BINDGEN_EXTRA_CLANG_ARGS_{}", target.replace('-', "_")),
+        ] {
+            let value = env::var_os(&var).unwrap_or_default();
+            field(&var, value.as_encoded_bytes());
+        }
+
+        let mut headers: Vec<PathBuf> = SCIP_DIRECTORIES
+            .iter()
+            .flat_map(|dir| headers_below(&include_dir.join(dir)))
+            .collect();
+        headers.sort();
+        for header in &headers {
+            let name = header.strip_prefix(include_dir).unwrap_or(header);
+            field("header", name.as_os_str().as_encoded_bytes());
+            let contents = fs::read(header).map_err(|e| {
+                BuildError::new(format!("failed to read {}", header.display())).caused_by(e)
+            })?;
+            field("contents", &contents);
+        }
+
+        let key: String = key
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        Ok(Some(Entry {
+            path: dir.join(format!("bindings-{key}.rs")),
+            headers,
+        }))
+    }
+
+    /// The cached bindings and the macros that were left untranslated, if there are any.
+    pub fn load(&self) -> Result<Option<(String, Vec<String>)>> {
+        let Ok(bindings) = fs::read_to_string(&self.path) else {
+            return Ok(None);
+        };
+        let Ok(untranslated) = fs::read_to_string(self.untranslated_path()) else {
+            return Ok(None);
+        };
+        // bindgen's `CargoCallbacks` are not run, so name the headers here
+        for header in &self.headers {
+            println!("cargo:rerun-if-changed={}", paths::utf8(header)?);
+        }
+        Ok(Some((
+            bindings,
+            untranslated
+                .lines()
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )))
+    }
+
+    /// Cache `bindings` and the names of the `untranslated` macros. Failing to is not an error,
+    /// the next build generates them again.
+    pub fn store(&self, bindings: &str, untranslated: &[String]) {
+        let mut untranslated = untranslated.join("\n");
+        untranslated.push('\n');
+        // the bindings go last, as `load` needs both
+        let stored = write_atomically(&self.untranslated_path(), &untranslated)
+            .and_then(|_| write_atomically(&self.path, bindings));
+        match stored {
+            Ok(()) => report::note(format!("Cached the bindings in {}", self.path.display())),
+            Err(e) => report::note(format!(
+                "Could not cache the bindings in {}: {e}",
+                self.path.display()
+            )),
+        }
+    }
+
+    fn untranslated_path(&self) -> PathBuf {
+        self.path.with_extension("untranslated")
+    }
+}
+
+/// Write `contents` to `path` under another name first, so that a concurrent build never reads
+/// half of it.
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(format!(".{}.partial", std::process::id()));
+    let partial = PathBuf::from(partial);
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&partial, contents))
+        .and_then(|_| fs::rename(&partial, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    written
+}
+
+/// `SCIP_SYS_CACHE_DIR`, or `scip-sys` in the cache directory of the platform.
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("SCIP_SYS_CACHE_DIR").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    let var = |name: &str| {
+        env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(windows) {
+        var("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Caches"))
+    } else {
+        var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|home| home.join(".cache")))
+    };
+    Some(base?.join("scip-sys"))
+}
+
+/// The `*.h` files below `dir`.
+fn headers_below(dir: &Path) -> Vec<PathBuf> {
+    let mut headers = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for path in fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+        {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "h") {
+                headers.push(path);
+            }
+        }
+    }
+    headers
+}
+
+/// The version of bindgen this build uses, from the `Cargo.lock` of the workspace being built.
+/// bindgen only states it in the bindings it generates, which would need libclang. `None` when
+/// there is no lock file or bindgen is not in it.
+fn bindgen_version() -> Option<String> {
+    // the target directory is usually inside the workspace; a path dependency is as well. A
+    // published package (with its Cargo.toml.orig) may ship a lock file of its own, which says
+    // nothing about this build.
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+        .filter(|dir| !Path::new(dir).join("Cargo.toml.orig").exists());
+    let lock = env::var_os("OUT_DIR")
+        .into_iter()
+        .chain(manifest_dir)
+        .flat_map(|dir| {
+            Path::new(&dir)
+                .ancestors()
+                .map(|dir| dir.join("Cargo.lock"))
+                .collect::<Vec<_>>()
+        })
+        .find(|lock| lock.is_file())?;
+    let lock = fs::read_to_string(lock).ok()?;
+    locked_versions(&lock, "bindgen")
+}
+
+/// The versions of the package `name` in the lock file `lock`, e.g. `0.72.1`, or several joined
+/// by commas if the workspace uses more than one.
+fn locked_versions(lock: &str, name: &str) -> Option<String> {
+    let name = format!("name = \"{name}\"");
+    let mut versions: Vec<&str> = lock
+        .split("[[package]]")
+        .filter(|package| package.lines().any(|line| line.trim() == name))
+        .filter_map(|package| {
+            package
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("version = \"")?.strip_suffix('"'))
+        })
+        .collect();
+    versions.sort();
+    versions.dedup();
+    (!versions.is_empty()).then(|| versions.join(","))
+}
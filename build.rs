@@ -1,128 +1,194 @@
+#[cfg(feature = "bindgen")]
+mod bindings_cache;
+mod build_info;
 mod bundled;
 #[cfg(feature = "bindgen")]
 mod callback;
-// `from_source` is only needed by the from-source build and by the generated
-// (bindgen, non-bundled) path that checks the from-source flag.
-#[cfg(any(
-    feature = "from-source",
-    all(feature = "bindgen", not(feature = "bundled"))
-))]
+mod check_only;
+#[cfg(feature = "bindgen")]
+mod decl_aliases;
+#[cfg(feature = "bindgen")]
+mod doxygen;
+mod feature_groups;
+mod header_modules;
+mod licenses;
+mod manifest;
+mod prebuilt;
+#[cfg(feature = "runtime-load")]
+mod runtime_load;
+#[cfg(not(feature = "runtime-load"))]
+mod shim;
+// `from_source` is only used by the non-bundled path, which checks the from-source
+// flag; with `bundled` enabled as well, the bundled SCIP is used.
+#[cfg(not(feature = "bundled"))]
 mod from_source;
 
-#[cfg(any(feature = "bundled", feature = "from-source"))]
-mod download;
+// Finding, downloading, building and linking SCIP live in `scip-sys-build`, so
+// that other crates linking SCIP can share them.
+#[cfg(not(feature = "bundled"))]
+use scip_sys_build::discover;
+use scip_sys_build::{elf_deps, error, link, paths, report, version};
 
+use crate::error::{BuildError, Result};
 use std::env;
+#[cfg(feature = "bindgen")]
 use std::error::Error;
 use std::path::{Path, PathBuf};
 
 /// Emit the `cargo:` link-search / rpath directives for a SCIP install directory
-/// (one containing `lib/` and `include/`). This is independent of bindgen and is
-/// shared by every path that links against SCIP.
-#[cfg(any(feature = "bundled", feature = "bindgen"))]
-fn emit_link_search(path: &str) {
-    let lib_dir = PathBuf::from(&path).join("lib");
-    let lib_dir_path = lib_dir.to_str().unwrap();
-
-    if lib_dir.exists() {
-        println!("cargo:warning=Using SCIP from {}", lib_dir_path);
-        println!("cargo:rustc-link-search={}", lib_dir_path);
-        println!("cargo:libdir={}", lib_dir_path);
-
-        #[cfg(windows)]
-        let lib_dir_path = PathBuf::from(&path).join("bin");
-        #[cfg(windows)]
-        println!("cargo:rustc-link-search={}", lib_dir_path.to_str().unwrap());
-    } else {
-        panic!(
-            "{}/lib does not exist, please check your SCIP installation",
-            path
-        );
+/// (one containing `lib/` and `include/`), see `link::search`. With
+/// `runtime-load` nothing is linked, so only the directory is checked.
+fn emit_link_search(path: &Path) -> Result<()> {
+    if cfg!(feature = "runtime-load") {
+        return link::lib_dir(path).map(|_| ());
     }
-
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_dir_path);
+    link::search(path)
 }
 
 /// Emit the `cargo:` link-lib directives: the SCIP library itself, plus the C++
-/// runtime and SoPlex when building from source.
-#[cfg(any(feature = "bundled", feature = "bindgen"))]
-fn emit_link_libs() {
-    #[cfg(windows)]
-    {
-        println!("cargo:rustc-link-lib=libscip");
-    }
-    #[cfg(not(windows))]
-    {
-        println!("cargo:rustc-link-lib=scip");
+/// runtime and SoPlex when building from source. Returns the linked libraries,
+/// none with `runtime-load`.
+fn emit_link_libs() -> Vec<&'static str> {
+    if cfg!(feature = "runtime-load") {
+        return Vec::new();
     }
+    link::libs(&link::scip_libs(cfg!(feature = "from-source")))
+}
 
-    #[cfg(feature = "from-source")]
-    {
-        let target = env::var("TARGET").unwrap();
-        let apple = target.contains("apple");
-        let linux = target.contains("linux");
-        let mingw = target.contains("pc-windows-gnu");
-        if apple {
-            println!("cargo:rustc-link-lib=dylib=c++");
-        } else if linux || mingw {
-            println!("cargo:rustc-link-lib=dylib=stdc++");
-        }
-
-        #[cfg(windows)]
-        {
-            println!("cargo:rustc-link-lib=libsoplex");
-        }
-        #[cfg(not(windows))]
-        {
-            println!("cargo:rustc-link-lib=soplex");
-        }
+/// Publish the SCIP installation to the build scripts of dependent crates, as
+/// `DEP_SCIP_INCLUDE`, `DEP_SCIP_ROOT`, `DEP_SCIP_LIBDIR`, `DEP_SCIP_STATIC` and
+/// `DEP_SCIP_LINK_LIBS` (comma separated), see `link::metadata`. The version is
+/// published by `build_info`. Returns the directory containing libscip, if it
+/// was found.
+fn emit_metadata(include_dir: &Path, link_libs: &[&str]) -> Result<Option<PathBuf>> {
+    // for the conformance checks in `tests/abi.rs`
+    println!(
+        "cargo:rustc-env=SCIP_SYS_INCLUDE_DIR={}",
+        paths::utf8(include_dir)?
+    );
+    println!(
+        "cargo:rustc-env=SCIP_SYS_TARGET={}",
+        env::var("TARGET").unwrap()
+    );
+    // for the comparison with the committed bindings in `tests/prebuilt_bindings.rs`
+    if let Some(name) = bundled::try_target_string() {
+        println!("cargo:rustc-env=SCIP_SYS_PREBUILT={name}");
     }
+    let lib_dir = link::metadata(include_dir, link_libs)?;
+    if let Some(lib_dir) = &lib_dir {
+        println!("cargo:rustc-env=SCIP_SYS_LIB_DIR={}", paths::utf8(lib_dir)?);
+    }
+    Ok(lib_dir)
 }
 
-/// Build a bindgen `Builder` pointed at the SCIP headers inside an install
-/// directory (`<path>/include/scip/{scip,scipdefplugins,def}.h`).
+/// Build a bindgen `Builder` pointed at the SCIP headers inside an include
+/// directory (`<include_dir>/scip/{scip,scipdefplugins,def}.h`).
+///
+/// The headers are pulled in through a generated wrapper that first turns the
+/// `SCIP_DECL_*` callback macros into named typedefs (see `decl_aliases.rs`).
 #[cfg(feature = "bindgen")]
-fn scip_dir_bindgen_builder(path: &str) -> bindgen::Builder {
-    let include_dir = PathBuf::from(&path).join("include");
-    let include_dir_path = include_dir.to_str().unwrap();
-    let scip_header_file = PathBuf::from(&path)
-        .join("include")
-        .join("scip")
-        .join("scip.h")
-        .to_str()
-        .unwrap()
-        .to_owned();
-    let scipdefplugins_header_file = PathBuf::from(&path)
-        .join("include")
-        .join("scip")
-        .join("scipdefplugins.h")
-        .to_str()
-        .unwrap()
-        .to_owned();
-    let scipdef_file = PathBuf::from(&path)
-        .join("include")
-        .join("scip")
-        .join("def.h")
-        .to_str()
-        .unwrap()
-        .to_owned();
-
-    bindgen::Builder::default()
-        .header(scip_header_file)
-        .header(scipdefplugins_header_file)
-        .header(scipdef_file)
+fn scip_bindgen_builder(
+    include_dir: &Path,
+    out_path: &Path,
+) -> Result<bindgen::Builder, Box<dyn Error>> {
+    let include_dir_path = paths::utf8(include_dir)?;
+    let decl_macros = decl_aliases::scan_decl_macros(include_dir)?;
+    let wrapper =
+        decl_aliases::write_wrapper_header(&decl_macros, &header_modules::ROOT_HEADERS, out_path)?;
+
+    Ok(bindgen::Builder::default()
+        .header(paths::utf8(&wrapper)?)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .clang_arg(format!("-I{}", include_dir_path))
+        .clang_arg(format!("-I{}", include_dir_path)))
 }
 
 /// Apply the SCIP-specific bindgen tweaks, generate the bindings and write them
-/// to `<out_path>/bindings.rs`.
+/// to `<out_path>/bindings.rs`. Bindings generated before from the same inputs are
+/// taken from the cache instead (see `bindings_cache.rs`).
 #[cfg(feature = "bindgen")]
-fn finalize_and_generate(builder: bindgen::Builder, out_path: &Path) -> Result<(), Box<dyn Error>> {
-    use callback::DeriveCastedConstant;
+fn finalize_and_generate(
+    builder: bindgen::Builder,
+    include_dir: &Path,
+    out_path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    use callback::{CastKind, DeriveCastedConstant, DoxygenComments};
+
+    // Setup the DeriveCastedConstant callback to recover the casted and computed constants of
+    // every SCIP macro (SCIP_INVALID, SCIP_REAL_MAX, SCIP_LONGINT_MAX, event type masks, ...)
+    let mut derive_casted_constant = DeriveCastedConstant::new()
+        .prefix("SCIP_")
+        .cast_type("SCIP_Real", CastKind::Float)
+        .cast_type(
+            "SCIP_Longint",
+            CastKind::Integer {
+                bits: 64,
+                signed: true,
+            },
+        )
+        .cast_type(
+            "SCIP_Shortbool",
+            CastKind::Integer {
+                bits: 8,
+                signed: false,
+            },
+        )
+        .cast_type(
+            "SCIP_EVENTTYPE",
+            CastKind::Integer {
+                bits: 64,
+                signed: false,
+            },
+        );
+    for ty in [
+        "SCIP_Bool",
+        "SCIP_HEURTIMING",
+        "SCIP_PROPTIMING",
+        "SCIP_PRESOLTIMING",
+    ] {
+        let unsigned_int = CastKind::Integer {
+            bits: 32,
+            signed: false,
+        };
+        derive_casted_constant = derive_casted_constant.cast_type(ty, unsigned_int);
+    }
 
-    // Setup the DeriveCastedConstant callback to target SCIP_INVALID
-    let derive_casted_constant = DeriveCastedConstant::new().target("SCIP_INVALID");
+    // The limits and types that depend on the data model of the target
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let (size_max, ptrdiff_max) = if pointer_width == "32" {
+        for (ty, signed) in [
+            ("size_t", false),
+            ("ptrdiff_t", true),
+            ("intptr_t", true),
+            ("uintptr_t", false),
+        ] {
+            let kind = CastKind::Integer { bits: 32, signed };
+            derive_casted_constant = derive_casted_constant.cast_type(ty, kind);
+        }
+        ("4294967295U", "2147483647")
+    } else {
+        ("18446744073709551615ULL", "9223372036854775807LL")
+    };
+    let long_is_32_bit =
+        env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows") || pointer_width == "32";
+    let (long_max, ulong_max) = if long_is_32_bit {
+        derive_casted_constant = derive_casted_constant.long_bits(32);
+        ("2147483647L", "4294967295UL")
+    } else {
+        ("9223372036854775807L", "18446744073709551615UL")
+    };
+    for (name, value) in [
+        ("SIZE_MAX", size_max),
+        ("PTRDIFF_MAX", ptrdiff_max),
+        ("LONG_MAX", long_max),
+        ("ULONG_MAX", ulong_max),
+    ] {
+        derive_casted_constant = derive_casted_constant
+            .literal(name, value)
+            .literal(&format!("__{name}__"), value);
+    }
+    let long_min = format!("(-{} - 1)", long_max);
+    derive_casted_constant = derive_casted_constant.literal("LONG_MIN", &long_min);
+    let untranslated = derive_casted_constant.untranslated();
 
     let builder = builder
         // SCIP 10 annotates the deprecated `SCIP_VARTYPE_IMPLINT` enumerator with
@@ -135,206 +201,517 @@ fn finalize_and_generate(builder: bindgen::Builder, out_path: &Path) -> Result<(
         .blocklist_item("FP_SUBNORMAL")
         .blocklist_item("FP_NORMAL")
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .parse_callbacks(Box::new(derive_casted_constant));
+        .parse_callbacks(Box::new(derive_casted_constant))
+        .parse_callbacks(Box::new(DoxygenComments));
+
+    let cache = bindings_cache::Entry::new(&builder, include_dir, out_path)?;
+    let regenerate = env::var_os("SCIP_SYS_REGENERATE_BINDINGS").is_some();
+    if let (Some(cache), false) = (&cache, regenerate) {
+        if let Some((bindings, untranslated)) = cache.load()? {
+            write_bindings(out_path, &bindings)?;
+            report::bindings("cached", Some(&cache.path));
+            report_untranslated(&untranslated);
+            return Ok(());
+        }
+    }
 
-    let bindings = builder.generate()?;
-    bindings.write_to_file(out_path.join("bindings.rs"))?;
+    load_libclang()?;
+    // The parameters are documented next to their declaration, which bindgen drops
+    let param_docs = doxygen::scan_param_docs(include_dir)?;
+    let bindings = builder.generate()?.to_string();
+    let bindings = doxygen::add_argument_docs(&bindings, &param_docs);
+    write_bindings(out_path, &bindings)?;
+    report::bindings("generated", None);
+    let untranslated: Vec<String> = untranslated.lock().unwrap().iter().cloned().collect();
+    if let Some(cache) = &cache {
+        cache.store(&bindings, &untranslated);
+    }
+    report_untranslated(&untranslated);
     Ok(())
 }
 
-#[cfg(all(feature = "bindgen", not(feature = "bundled")))]
-fn lib_scip_in_dir(path: &str) -> bool {
-    use glob::glob;
-    glob(&format!("{}/lib/libscip*", path)).unwrap().count() > 0
+/// Load libclang for bindgen, which would panic if it is missing.
+#[cfg(feature = "bindgen")]
+fn load_libclang() -> Result<()> {
+    println!("cargo:rerun-if-env-changed=LIBCLANG_PATH");
+    clang_sys::load().map_err(|e| {
+        BuildError::new("libclang, which bindgen needs to generate the bindings, was not found")
+            .caused_by(e)
+            .remedy("Install libclang, e.g. `apt install libclang-dev` or `brew install llvm`")
+            .remedy("Set LIBCLANG_PATH to the directory containing libclang")
+    })
 }
 
-#[cfg(all(feature = "bindgen", not(feature = "bundled")))]
-fn look_in_scipoptdir_and_conda_env() -> Option<bindgen::Builder> {
-    let env_vars = vec!["SCIPOPTDIR", "CONDA_PREFIX"];
-
-    for env_var_name in env_vars {
-        println!("cargo:rerun-if-env-changed={}", env_var_name);
-        let env_var = env::var(env_var_name);
-        if let Ok(scip_dir) = env_var {
-            println!("cargo:warning=Looking for SCIP in {}", scip_dir);
-            if lib_scip_in_dir(&scip_dir) {
-                emit_link_search(&scip_dir);
-                return Some(scip_dir_bindgen_builder(&scip_dir));
-            } else {
-                println!("cargo:warning=SCIP was not found in {}", scip_dir);
-            }
-        } else {
-            println!("cargo:warning={} is not set", env_var_name);
-        }
+/// Note the SCIP macros the callbacks could not turn into constants.
+#[cfg(feature = "bindgen")]
+fn report_untranslated(untranslated: &[String]) {
+    if !untranslated.is_empty() {
+        report::note(format!(
+            "{} SCIP macros could not be translated into constants: {}",
+            untranslated.len(),
+            untranslated.join(", ")
+        ));
     }
+}
 
-    None
+/// `src/bindings/`, where the prebuilt and pregenerated bindings are committed.
+fn committed_bindings_dir() -> PathBuf {
+    PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bindings")
 }
 
-#[cfg(all(feature = "bindgen", not(feature = "bundled")))]
-fn try_system_include_paths() -> Option<bindgen::Builder> {
-    println!("cargo:warning=Searching for SCIP in standard system directories");
-
-    // Common system include paths
-    let search_paths = vec![
-        "/usr/include",
-        "/usr/local/include",
-        "/opt/local/include",          // MacPorts
-        "/opt/homebrew/include",       // Homebrew ARM Mac
-        "/usr/local/opt/scip/include", // Homebrew Intel Mac
-    ];
-
-    for base_path in search_paths {
-        let base = PathBuf::from(base_path);
-        let scip_h = base.join("scip").join("scip.h");
-        let scipdefplugins_h = base.join("scip").join("scipdefplugins.h");
-        let def_h = base.join("scip").join("def.h");
-
-        if scip_h.exists() && scipdefplugins_h.exists() && def_h.exists() {
-            println!("cargo:warning=Found SCIP headers in {}", base_path);
-
-            return Some(
-                bindgen::Builder::default()
-                    .header(scip_h.to_str().unwrap())
-                    .header(scipdefplugins_h.to_str().unwrap())
-                    .header(def_h.to_str().unwrap())
-                    .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-                    .clang_arg(format!("-I{}", base_path)),
-            );
-        }
+/// Take the bindings below `SCIP_SYS_IMPORT_BINDINGS` over into `src/bindings/`, see
+/// `prebuilt::import`. The `generate-bindings` workflow merges those of its runners this way.
+fn import_bindings() -> Result<()> {
+    println!("cargo:rerun-if-env-changed=SCIP_SYS_IMPORT_BINDINGS");
+    let Some(source) = env::var_os("SCIP_SYS_IMPORT_BINDINGS").map(PathBuf::from) else {
+        return Ok(());
+    };
+    let imported = prebuilt::import(&source, &committed_bindings_dir()).map_err(|e| {
+        BuildError::new(format!(
+            "failed to import the bindings from {}",
+            source.display()
+        ))
+        .caused_by(e)
+    })?;
+    if imported.is_empty() {
+        return Err(BuildError::new(format!(
+            "{} has no bindings-<name>/ directories with bindings to import",
+            source.display()
+        )));
     }
+    report::note(format!(
+        "Imported the {} bindings into src/bindings/",
+        imported.join(", ")
+    ));
+    Ok(())
+}
+
+/// The committed bindings `name` (a target or `pregenerated`), assembled from
+/// `src/bindings/common.rs` and `src/bindings/<name>.rs`, and the path of the latter.
+fn committed_bindings(name: &str) -> Result<Option<(String, PathBuf)>> {
+    let dir = committed_bindings_dir();
+    let [common, file] = prebuilt::files(&dir, name);
+    let bindings = prebuilt::read(&dir, name).map_err(|e| {
+        BuildError::new(format!("the committed bindings {name} are broken"))
+            .caused_by(e)
+            .remedy("Restore src/bindings/ from git, or regenerate the bindings")
+    })?;
+    let Some(bindings) = bindings else {
+        return Ok(None);
+    };
+    println!("cargo:rerun-if-changed={}", paths::utf8(&common)?);
+    println!("cargo:rerun-if-changed={}", paths::utf8(&file)?);
+    Ok(Some((header_modules::escape_brackets(&bindings), file)))
+}
 
-    println!("cargo:warning=Could not find SCIP headers in standard system directories");
-    None
+/// The committed bindings for builds without SCIP on any target, e.g. on docs.rs.
+fn pregenerated_bindings() -> Result<(String, PathBuf)> {
+    committed_bindings("pregenerated")?.ok_or_else(|| {
+        BuildError::new("src/bindings/pregenerated.rs is missing")
+            .remedy("Restore src/bindings/ from git")
+    })
+}
+
+/// Copy the bindings named by `SCIP_SYS_BINDINGS_PATH` to `<out_path>/bindings.rs`, for builds
+/// that can install neither libclang nor a SCIP release with committed bindings. The file must
+/// have been generated for the API version of the SCIP in `include_dir`. Returns whether the
+/// variable was set.
+fn write_user_bindings(include_dir: &Path, out_path: &Path) -> Result<bool> {
+    println!("cargo:rerun-if-env-changed=SCIP_SYS_BINDINGS_PATH");
+    let Some(path) = env::var_os("SCIP_SYS_BINDINGS_PATH").filter(|path| !path.is_empty()) else {
+        return Ok(false);
+    };
+    let path = PathBuf::from(path);
+    println!("cargo:rerun-if-changed={}", paths::utf8(&path)?);
+    let bindings = std::fs::read_to_string(&path).map_err(|e| {
+        BuildError::new(format!(
+            "could not read SCIP_SYS_BINDINGS_PATH={}",
+            path.display()
+        ))
+        .caused_by(e)
+        .remedy("Set SCIP_SYS_BINDINGS_PATH to the absolute path of a bindings file")
+    })?;
+
+    let Some(theirs) = version::ScipVersion::from_bindings(&bindings) else {
+        return Err(BuildError::new(format!(
+            "the bindings in SCIP_SYS_BINDINGS_PATH={} have no SCIP_APIVERSION, so they cannot \
+             be checked against the SCIP being linked",
+            path.display()
+        ))
+        .remedy("Generate them from a wrapper including scip/scip.h, which defines the version")
+        .remedy("Unset SCIP_SYS_BINDINGS_PATH"));
+    };
+    match version::ScipVersion::from_headers(include_dir) {
+        Some(installed) if installed.api != theirs.api => {
+            return Err(BuildError::new(format!(
+                "the bindings in SCIP_SYS_BINDINGS_PATH={} are for SCIP {theirs}, but the SCIP \
+                 in {} is {installed}",
+                path.display(),
+                include_dir.display()
+            ))
+            .remedy("Regenerate the bindings against the headers of the SCIP being linked")
+            .remedy("Unset SCIP_SYS_BINDINGS_PATH"))
+        }
+        Some(_) => {}
+        None => report::warn(format!(
+            "Could not read the SCIP version from {}, so SCIP_SYS_BINDINGS_PATH is not checked",
+            include_dir.join("scip").join("config.h").display()
+        )),
+    }
+    write_bindings(out_path, &bindings)?;
+    report::bindings("user", Some(&path));
+    Ok(true)
 }
 
 /// Produce `<out_path>/bindings.rs` for the bundled path.
 ///
 /// The bundled SCIP release is pinned, so the generated bindings are
-/// deterministic for a given target and are committed under
-/// `src/bindings/<target>.rs`. Copying the prebuilt file lets the bundled build
-/// skip bindgen (and libclang) entirely. If no prebuilt file is committed for
-/// this target yet, we fall back to bindgen when that feature is available so
-/// the build still succeeds.
+/// deterministic for a given target and are committed in `src/bindings/` (see
+/// `prebuilt.rs`). Using the prebuilt bindings lets the bundled build skip
+/// bindgen (and libclang) entirely. If none are committed for this target yet,
+/// we fall back to bindgen when that feature is available so the build still
+/// succeeds.
 #[cfg(feature = "bundled")]
-fn write_bundled_bindings(scip_install: &Path, out_path: &Path) -> Result<(), Box<dyn Error>> {
-    let target = bundled::target_string();
-    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-    let prebuilt = manifest_dir
-        .join("src")
-        .join("bindings")
-        .join(format!("{target}.rs"));
+fn write_bundled_bindings(
+    manifest: &scip_sys_build::manifest::Manifest,
+    scip_install: &Path,
+    out_path: &Path,
+) -> Result<()> {
+    if write_user_bindings(&scip_install.join("include"), out_path)? {
+        return Ok(());
+    }
+    let target = bundled::target_string()?;
 
     // Opt-in escape hatch (used by the `generate-bindings` CI job): force bindgen
     // to regenerate and write the result back into the committed source tree.
     println!("cargo:rerun-if-env-changed=SCIP_SYS_REGENERATE_BINDINGS");
     let regenerate = env::var_os("SCIP_SYS_REGENERATE_BINDINGS").is_some();
 
-    if prebuilt.exists() && !regenerate {
-        println!("cargo:warning=Using prebuilt bundled bindings src/bindings/{target}.rs");
-        println!("cargo:rerun-if-changed={}", prebuilt.to_str().unwrap());
-        std::fs::copy(&prebuilt, out_path.join("bindings.rs"))?;
-        return Ok(());
+    if !regenerate {
+        if let Some((bindings, prebuilt)) = committed_bindings(&target)? {
+            manifest::check_bindings(manifest, &target, &bindings)?;
+            write_bindings(out_path, &bindings)?;
+            report::bindings("prebuilt", Some(&prebuilt));
+            return Ok(());
+        }
     }
 
     #[cfg(feature = "bindgen")]
     {
         if regenerate {
-            println!("cargo:warning=Regenerating bundled bindings for target '{target}'");
+            report::note(format!(
+                "Regenerating bundled bindings for target '{target}'"
+            ));
         } else {
-            println!(
-                "cargo:warning=No prebuilt bindings for target '{target}'; generating with bindgen. \
+            report::warn(format!(
+                "No prebuilt bindings for target '{target}'; generating with bindgen. \
                  Run the generate-bindings workflow and commit src/bindings/{target}.rs to skip this."
-            );
+            ));
         }
 
-        let builder = scip_dir_bindgen_builder(scip_install.to_str().unwrap());
-        finalize_and_generate(builder, out_path)?;
+        let include_dir = scip_install.join("include");
+        finalize_and_generate(
+            scip_bindgen_builder(&include_dir, out_path)?,
+            &include_dir,
+            out_path,
+        )?;
 
         if regenerate {
-            std::fs::create_dir_all(prebuilt.parent().unwrap())?;
-            std::fs::copy(out_path.join("bindings.rs"), &prebuilt)?;
-            println!("cargo:warning=Wrote src/bindings/{target}.rs");
+            let dir = committed_bindings_dir();
+            let bindings = read_bindings(out_path)?;
+            prebuilt::replace(&dir, &target, bindings.clone()).map_err(|e| {
+                BuildError::new(format!("failed to write the bindings to {}", dir.display()))
+                    .caused_by(e)
+            })?;
+            report::note(format!(
+                "Wrote src/bindings/{target}.rs and src/bindings/common.rs"
+            ));
+
+            // Record where each item comes from for the builds without headers (docs.rs)
+            let map = dir.join("headers.txt");
+            header_modules::write_header_map(
+                &header_modules::scan_headers(&include_dir)?,
+                &bindings,
+                &map,
+            )
+            .map_err(|e| {
+                BuildError::new(format!("failed to write {}", map.display())).caused_by(e)
+            })?;
+            report::note("Wrote src/bindings/headers.txt");
         }
-        return Ok(());
+        Ok(())
     }
 
     #[cfg(not(feature = "bindgen"))]
     {
         let _ = scip_install;
-        panic!(
-            "scip-sys: prebuilt bundled bindings for target '{target}' are missing (or \
+        Err(BuildError::new(format!(
+            "the prebuilt bundled bindings for target '{target}' are missing (or \
              regeneration was requested), and the `bindgen` feature is disabled so they \
-             cannot be generated.\n\
-             Either build with default features enabled, or commit src/bindings/{target}.rs."
-        );
+             cannot be generated"
+        ))
+        .remedy("Build with default features enabled, or enable the `bindgen` feature")
+        .remedy(format!(
+            "Commit src/bindings/{target}.rs and src/bindings/common.rs"
+        )))
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+/// Produce `<out_path>/bindings.rs` for an installed SCIP (SCIPOPTDIR, conda,
+/// system or from-source).
+///
+/// Bindings given in `SCIP_SYS_BINDINGS_PATH` take precedence over everything else.
+/// The version of the installation is read from its headers. When the committed
+/// bindings of the target were generated for the same API version, they are
+/// used as is, so these paths do not need bindgen either for a known release.
+/// Otherwise the bindings are generated, which requires the `bindgen` feature.
+#[cfg(not(feature = "bundled"))]
+fn write_installed_bindings(include_dir: &Path, out_path: &Path) -> Result<()> {
+    if write_user_bindings(include_dir, out_path)? {
+        return Ok(());
+    }
+    println!("cargo:rerun-if-env-changed=SCIP_SYS_REGENERATE_BINDINGS");
+    let regenerate = env::var_os("SCIP_SYS_REGENERATE_BINDINGS").is_some();
+
+    let installed = version::ScipVersion::from_headers(include_dir);
+    if installed.is_none() {
+        report::warn(format!(
+            "Could not read the SCIP version from {}",
+            include_dir.join("scip").join("config.h").display()
+        ));
+    }
+
+    let target = bundled::try_target_string();
+    if let (Some(installed), Some(target), false) = (installed, &target, regenerate) {
+        let (bindings, prebuilt) = committed_bindings(target)?.unwrap_or_default();
+        match version::ScipVersion::from_bindings(&bindings) {
+            Some(committed) if committed.api == installed.api => {
+                write_bindings(out_path, &bindings)?;
+                report::bindings("prebuilt", Some(&prebuilt));
+                return Ok(());
+            }
+            Some(committed) => report::note(format!(
+                "The prebuilt bindings are for SCIP {committed}, not for the installed SCIP {installed}"
+            )),
+            None => {}
+        }
+    }
+
+    #[cfg(feature = "bindgen")]
+    {
+        finalize_and_generate(
+            scip_bindgen_builder(include_dir, out_path)?,
+            include_dir,
+            out_path,
+        )?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "bindgen"))]
+    {
+        let target = target.as_deref().unwrap_or("unsupported");
+        Err(BuildError::new(format!(
+            "there are no prebuilt bindings for the installed SCIP on target '{target}', \
+             and the `bindgen` feature is disabled so they cannot be generated"
+        ))
+        .remedy("Enable the `bindgen` feature")
+        .remedy(
+            "Install the SCIP release the prebuilt bindings in src/bindings/ were generated for",
+        )
+        .remedy("Set SCIP_SYS_BINDINGS_PATH to bindings generated for the installed SCIP"))
+    }
+}
+
+/// Use the header of every item to drop the API groups whose feature is disabled from
+/// `<out_path>/bindings.rs`, and to write the per-header modules to `<out_path>/modules.rs`.
+/// With `runtime-load`, the functions of libscip are also moved into `ScipLibrary`.
+///
+/// Write the bindings the crate includes, `<out_path>/bindings.rs`.
+fn write_bindings(out_path: &Path, bindings: &str) -> Result<()> {
+    let path = out_path.join("bindings.rs");
+    std::fs::write(&path, bindings)
+        .map_err(|e| BuildError::new(format!("failed to write {}", path.display())).caused_by(e))
+}
+
+/// Read back `<out_path>/bindings.rs`.
+fn read_bindings(out_path: &Path) -> Result<String> {
+    let path = out_path.join("bindings.rs");
+    std::fs::read_to_string(&path)
+        .map_err(|e| BuildError::new(format!("failed to read {}", path.display())).caused_by(e))
+}
+
+/// The headers below `include_dir` are scanned when they are available; otherwise the map
+/// committed in `src/bindings/headers.txt` is used. Without either, nothing is removed and the
+/// per-header modules are empty, which is reported as a warning. Which map was used is passed on
+/// as `SCIP_SYS_HEADER_MAP` (`headers`, `committed` or `none`).
+fn apply_header_map(include_dir: Option<&Path>, out_path: &Path) -> Result<()> {
+    let bindings = read_bindings(out_path)?;
+    let committed = committed_bindings_dir().join("headers.txt");
+    let (map, source) = match include_dir {
+        Some(include_dir) => (header_modules::scan_headers(include_dir)?, "headers"),
+        None if committed.exists() => {
+            println!("cargo:rerun-if-changed={}", paths::utf8(&committed)?);
+            (header_modules::read_header_map(&committed)?, "committed")
+        }
+        None => {
+            // picks up the map once it is added
+            println!(
+                "cargo:rerun-if-changed={}",
+                paths::utf8(&committed_bindings_dir())?
+            );
+            report::warn(
+                "src/bindings/headers.txt is missing, so the API features remove nothing and \
+                 the per-header modules are empty; run the generate-bindings workflow to \
+                 write it",
+            );
+            (header_modules::HeaderMap::new(), "none")
+        }
+    };
+    println!("cargo:rustc-env=SCIP_SYS_HEADER_MAP={source}");
+    let (bindings, removed) = feature_groups::strip_disabled(&bindings, &map);
+    #[cfg(feature = "runtime-load")]
+    let (bindings, removed) = {
+        let (bindings, moved) = runtime_load::into_library_struct(&bindings, &map);
+        (bindings, removed + moved)
+    };
+    let (bindings, unlinked) = header_modules::unlink_missing(&bindings);
+    if removed + unlinked > 0 {
+        write_bindings(out_path, &bindings)?;
+    }
+    header_modules::write_modules(&map, &bindings, out_path)?;
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let out_path = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    // `--cfg scip_debug` turns on `scip_debug_msg!`, like `SCIP_DEBUG` in C
+    println!("cargo:rustc-check-cfg=cfg(scip_debug)");
+    import_bindings()?;
+    let manifest = manifest::read()?;
 
     // Detect docs.rs build environment (no network access)
     if env::var("DOCS_RS").is_ok() {
-        println!("cargo:warning=Building on docs.rs, using pre-generated bindings");
-        std::fs::copy("src/bindings_pregenerated.rs", out_path.join("bindings.rs"))?;
+        report::strategy("docs.rs");
+        let (bindings, pregenerated) = pregenerated_bindings()?;
+        write_bindings(&out_path, &bindings)?;
+        report::bindings("pregenerated", Some(&pregenerated));
+        apply_header_map(None, &out_path)?;
+        build_info::emit(None, &out_path)?;
+        licenses::emit(None, &out_path)?;
+        report::finish(&out_path)?;
         return Ok(());
     }
 
+    // Check-only build (`cargo check`, clippy, rust-analyzer): no SCIP, no download, no linking
+    if let Some(reason) = check_only::reason() {
+        check_only::emit(reason, &out_path)?;
+        licenses::emit(None, &out_path)?;
+        report::finish(&out_path)?;
+        return Ok(());
+    }
+    println!("cargo:check_only=false");
+
     // Bundled path: the SCIP version is pinned, so bindings are deterministic per
     // target. Use the committed prebuilt bindings and skip bindgen/libclang.
     #[cfg(feature = "bundled")]
     {
-        bundled::download_scip();
-        let path = out_path.join("scip_install");
-        emit_link_search(path.to_str().unwrap());
-        emit_link_libs();
-        write_bundled_bindings(&path, &out_path)?;
-        return Ok(());
+        report::strategy("bundled");
+        let scip = bundled::download_scip(&out_path)?;
+        bundled::validate_download(&scip.root)?;
+        emit_link_search(&scip.root)?;
+        #[cfg(not(feature = "runtime-load"))]
+        shim::compile(&scip.include_dir)?;
+        let link_libs = emit_link_libs();
+        if let Some(lib_dir) = emit_metadata(&scip.include_dir, &link_libs)? {
+            elf_deps::check(&lib_dir);
+        }
+        write_bundled_bindings(&manifest, &scip.root, &out_path)?;
+        apply_header_map(Some(&scip.include_dir), &out_path)?;
+        build_info::emit(Some(&scip.include_dir), &out_path)?;
+        let origin = licenses::bundled(&manifest, &bundled::target_string()?, &scip.root)?;
+        licenses::emit(Some(&origin), &out_path)?;
+        report::finish(&out_path)?;
+        Ok(())
     }
 
-    // Every other path (from-source, SCIPOPTDIR/conda, system) targets a SCIP
-    // whose ABI is not known ahead of time, so the bindings must be generated.
-    #[cfg(all(feature = "bindgen", not(feature = "bundled")))]
+    // Every other path (from-source, SCIPOPTDIR/conda, system) targets an
+    // installed SCIP, whose version is read from its headers to decide whether the
+    // prebuilt bindings fit or they must be generated.
+    #[cfg(not(feature = "bundled"))]
     {
         use crate::from_source::is_from_source_feature_enabled;
 
-        let builder = if is_from_source_feature_enabled() {
-            let source_path = crate::from_source::download_scip_source();
-            let build_path = crate::from_source::compile_scip(source_path);
-            emit_link_search(build_path.to_str().unwrap());
-            scip_dir_bindgen_builder(build_path.to_str().unwrap())
+        let mut origin = None;
+        let scip = if is_from_source_feature_enabled() {
+            let source_path = crate::from_source::download_scip_source()?;
+            origin = Some(licenses::from_source(&manifest, &source_path)?);
+            let scip = crate::from_source::compile_scip(source_path)?;
+            report::strategy("from-source");
+            report::probe(scip.root.display().to_string(), "built from source");
+            Some(scip)
         } else {
-            look_in_scipoptdir_and_conda_env().unwrap_or_else(|| {
-                println!("cargo:warning=SCIP was not found in SCIPOPTDIR or in Conda environment");
-                println!("cargo:warning=Looking for SCIP in system libraries");
-
-                try_system_include_paths().unwrap_or_else(|| {
-                    panic!(
-                        "Could not find SCIP installation.\n\
-                        Please either:\n\
-                        - Set SCIPOPTDIR environment variable to point to your SCIP installation\n\
-                        - Install SCIP system-wide (headers in /usr/include or /usr/local/include)\n\
-                        - Use --features bundled to download and use a bundled version\n\
-                        - Use --features from-source to build SCIP from source"
-                    )
-                })
-            })
+            discover::from_env(&discover::ENV_VARS)
+                .or_else(|| discover::system(&discover::SYSTEM_INCLUDE_DIRS))
         };
+        if let Some(scip) = scip.as_ref().filter(|scip| !scip.system) {
+            emit_link_search(&scip.root)?;
+        }
+        let include_dir = scip.map(|scip| scip.include_dir);
+
+        // libscip is only needed when the program runs, so the prebuilt bindings
+        // can stand in for an installation
+        #[cfg(feature = "runtime-load")]
+        if include_dir.is_none() {
+            let prebuilt = match bundled::try_target_string() {
+                Some(target) => committed_bindings(&target)?,
+                None => None,
+            };
+            let (bindings, prebuilt) = prebuilt.ok_or_else(|| {
+                BuildError::new(
+                    "SCIP was not found, and there are no prebuilt bindings for this target \
+                     to build `runtime-load` without it",
+                )
+                .with_attempts()
+                .remedy("Set SCIPOPTDIR to a SCIP installation to generate the bindings from")
+            })?;
+            report::strategy("runtime-load without SCIP");
+            write_bindings(&out_path, &bindings)?;
+            report::bindings("prebuilt", Some(&prebuilt));
+            apply_header_map(None, &out_path)?;
+            build_info::emit(None, &out_path)?;
+            licenses::emit(None, &out_path)?;
+            report::finish(&out_path)?;
+            return Ok(());
+        }
 
-        emit_link_libs();
-        finalize_and_generate(builder, &out_path)?;
-        return Ok(());
-    }
-
-    // Neither `bundled` nor `bindgen` is enabled: there is no way to obtain
-    // bindings. (Reachable only with `--no-default-features` and no path feature.)
-    #[cfg(all(not(feature = "bundled"), not(feature = "bindgen")))]
-    {
-        panic!(
-            "scip-sys: built without `bundled` and without the `bindgen` feature, so no SCIP \
-             bindings can be produced.\n\
-             Enable default features, or use `--features bundled` / `--features from-source`."
-        );
+        let include_dir = include_dir.ok_or_else(|| {
+            BuildError::new("could not find a SCIP installation")
+                .with_attempts()
+                .remedy(
+                    "Set the SCIPOPTDIR environment variable to point to your SCIP installation",
+                )
+                .remedy(
+                    "Install SCIP in a conda environment: conda install --channel conda-forge scip",
+                )
+                .remedy("Install SCIP system-wide (headers in /usr/include or /usr/local/include)")
+                .remedy("Use --features bundled to download and use a bundled version")
+                .remedy("Use --features from-source to build SCIP from source")
+        })?;
+
+        #[cfg(not(feature = "runtime-load"))]
+        shim::compile(&include_dir)?;
+        let link_libs = emit_link_libs();
+        if let Some(lib_dir) = emit_metadata(&include_dir, &link_libs)? {
+            elf_deps::check(&lib_dir);
+        }
+        write_installed_bindings(&include_dir, &out_path)?;
+        apply_header_map(Some(&include_dir), &out_path)?;
+        build_info::emit(Some(&include_dir), &out_path)?;
+        let origin = origin.unwrap_or_else(|| licenses::installed(&include_dir));
+        licenses::emit(Some(&origin), &out_path)?;
+        report::finish(&out_path)?;
+        Ok(())
     }
 }
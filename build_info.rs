@@ -0,0 +1,157 @@
+//! Facts about the SCIP the crate is built against: its version and the optional components it
+//! was compiled with.
+//!
+//! They are emitted three ways: as `rustc-cfg` flags for this crate, as `DEP_SCIP_*` metadata for
+//! the build scripts of dependent crates, and as constants in `<OUT_DIR>/build_info.rs`, which
+//! `src/lib.rs` exposes as the `build_info` module.
+
+use crate::version::{header_defines, ScipVersion};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// The optional components of SCIP that are reported, with the `config.h` macro enabling them
+/// and their description.
+const COMPONENTS: [(&str, &str, &str); 5] = [
+    ("exact", "SCIP_WITH_EXACTSOLVE", "exact solving"),
+    ("ipopt", "SCIP_WITH_IPOPT", "Ipopt"),
+    ("papilo", "SCIP_WITH_PAPILO", "PaPILO"),
+    ("zlib", "SCIP_WITH_ZLIB", "zlib"),
+    ("gmp", "SCIP_WITH_GMP", "GMP"),
+];
+
+/// The version and components of the SCIP whose headers are in `include_dir`. Without headers
+/// (docs.rs) the version comes from the bindings in `<out_path>/bindings.rs` and no optional
+/// component is reported.
+pub fn emit(include_dir: Option<&Path>, out_path: &Path) -> Result<(), Box<dyn Error>> {
+    let defines = include_dir.map(header_defines).unwrap_or_default();
+    let version = match include_dir.and_then(ScipVersion::from_headers) {
+        Some(version) => Some(version),
+        None => ScipVersion::from_bindings(&std::fs::read_to_string(out_path.join("bindings.rs"))?),
+    };
+
+    let mut components: Vec<(&str, bool)> = COMPONENTS
+        .iter()
+        .map(|&(name, define, _)| (name, defines.contains_key(define)))
+        .collect();
+    // Ipopt is linked into libscip without a mark in config.h, so also look for its library
+    if let Some(lib_dir) = include_dir.and_then(Path::parent).map(|p| p.join("lib")) {
+        let ipopt = components.iter_mut().find(|(name, _)| *name == "ipopt");
+        if let Some((_, found)) = ipopt {
+            *found |= has_library(&lib_dir, "ipopt");
+        }
+    }
+    let tpi = tpi(&defines);
+
+    for name in [
+        "scip_version_major",
+        "scip_version_minor",
+        "scip_api_version",
+    ] {
+        println!("cargo:rustc-check-cfg=cfg({name}, values(any()))");
+    }
+    println!("cargo:rustc-check-cfg=cfg(scip_tpi, values(\"none\", \"tny\", \"omp\"))");
+    for (name, _) in &components {
+        println!("cargo:rustc-check-cfg=cfg(scip_with_{name})");
+    }
+
+    if let Some(version) = version {
+        println!("cargo:rustc-cfg=scip_version_major=\"{}\"", version.major);
+        println!("cargo:rustc-cfg=scip_version_minor=\"{}\"", version.minor);
+        println!("cargo:rustc-cfg=scip_api_version=\"{}\"", version.api);
+        println!(
+            "cargo:version={}.{}.{}",
+            version.major, version.minor, version.patch
+        );
+        println!("cargo:version_major={}", version.major);
+        println!("cargo:version_minor={}", version.minor);
+        println!("cargo:version_patch={}", version.patch);
+        println!("cargo:api_version={}", version.api);
+    } else {
+        crate::report::warn("Could not determine the SCIP version");
+    }
+    println!("cargo:rustc-cfg=scip_tpi=\"{tpi}\"");
+    println!("cargo:tpi={tpi}");
+    for (name, enabled) in &components {
+        if *enabled {
+            println!("cargo:rustc-cfg=scip_with_{name}");
+        }
+        println!("cargo:with_{name}={enabled}");
+    }
+
+    crate::report::build_info(version, tpi, &components);
+    std::fs::write(
+        out_path.join("build_info.rs"),
+        constants(version, &components, tpi),
+    )?;
+    Ok(())
+}
+
+/// The task processing interface SCIP was built with: `none`, `tny` (TinyCThread) or `omp`.
+fn tpi(defines: &HashMap<String, String>) -> &'static str {
+    if defines.contains_key("TPI_OMP") {
+        "omp"
+    } else if defines.contains_key("TPI_TNY") || defines.contains_key("TPI_TNYC") {
+        "tny"
+    } else {
+        "none"
+    }
+}
+
+/// Whether `lib_dir` contains a library whose name starts with `lib<name>` (or `<name>` on
+/// Windows).
+fn has_library(lib_dir: &Path, name: &str) -> bool {
+    let Ok(entries) = std::fs::read_dir(lib_dir) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy().to_lowercase();
+        file_name.starts_with(&format!("lib{name}")) || file_name.starts_with(name)
+    })
+}
+
+/// The Rust source of the `build_info` module.
+fn constants(version: Option<ScipVersion>, components: &[(&str, bool)], tpi: &str) -> String {
+    let version = version.unwrap_or(ScipVersion {
+        major: 0,
+        minor: 0,
+        patch: 0,
+        api: 0,
+    });
+    let mut out = String::new();
+    for (doc, name, value) in [
+        ("Major version", "VERSION_MAJOR", version.major),
+        ("Minor version", "VERSION_MINOR", version.minor),
+        ("Patch version", "VERSION_PATCH", version.patch),
+        (
+            "API version (`SCIP_APIVERSION`)",
+            "API_VERSION",
+            version.api,
+        ),
+    ] {
+        writeln!(
+            out,
+            "/// {doc} of the SCIP the crate was built against, 0 if unknown.\n\
+             pub const {name}: u32 = {value};"
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "/// Task processing interface SCIP was built with: `\"none\"`, `\"tny\"` or `\"omp\"`.\n\
+         pub const TPI: &str = \"{tpi}\";"
+    )
+    .unwrap();
+    for ((name, enabled), (_, _, description)) in components.iter().zip(COMPONENTS) {
+        writeln!(
+            out,
+            "/// Whether SCIP was built with {description}.\n\
+             pub const WITH_{}: bool = {enabled};",
+            name.to_uppercase()
+        )
+        .unwrap();
+    }
+    out
+}
@@ -1,9 +1,11 @@
 #[cfg(feature = "bundled")]
-use crate::download::download_and_extract_zip;
+use crate::error::{BuildError, Result};
+#[cfg(feature = "bundled")]
+use scip_sys_build::Installation;
 #[cfg(feature = "bundled")]
 use std::env;
 #[cfg(feature = "bundled")]
-use std::path::PathBuf;
+use std::path::Path;
 
 /// Map the current target OS/arch to the platform tag used both for the
 /// prebuilt SCIP download and for selecting the matching prebuilt bindings in
@@ -11,60 +13,75 @@ use std::path::PathBuf;
 /// downloaded library and the committed bindings always refer to the same
 /// platform.
 #[cfg(feature = "bundled")]
-pub fn target_string() -> String {
-    let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
-    let arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap();
-
-    let os_string = if os == "linux" && arch == "x86_64" {
-        "linux"
-    } else if os == "linux" && arch == "aarch64" {
-        "linux-arm"
-    } else if os == "macos" && arch == "x86_64" {
-        "macos-intel"
-    } else if os == "macos" && arch == "aarch64" {
-        "macos-arm"
-    } else if os == "windows" && arch == "x86_64" {
-        "windows"
-    } else {
-        panic!("Unsupported OS-arch combination: {}-{}", os, arch);
-    };
+pub fn target_string() -> Result<String> {
+    try_target_string().ok_or_else(|| {
+        BuildError::new(format!(
+            "there is no bundled SCIP for {}-{}",
+            std::env::var("CARGO_CFG_TARGET_OS").unwrap(),
+            std::env::var("CARGO_CFG_TARGET_ARCH").unwrap()
+        ))
+        .remedy("Disable the `bundled` feature and point SCIPOPTDIR to a SCIP installation")
+        .remedy("Use --features from-source to build SCIP from source")
+    })
+}
 
-    os_string.to_string()
+/// The platform tag of the current target, or `None` if there is no prebuilt
+/// release for it.
+pub fn try_target_string() -> Option<String> {
+    scip_sys_build::bundled::platform().map(str::to_string)
 }
 
+/// Download the precompiled SCIP pinned in `versions.toml` for the target into
+/// `<out_path>/scip_install`.
 #[cfg(feature = "bundled")]
-pub fn download_scip() {
-    let extract_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-
-    if extract_path.join("scip_install").exists() {
-        println!("cargo:warning=SCIP was previously downloaded, skipping download");
-        return;
-    }
+pub fn download_scip(out_path: &Path) -> Result<Installation> {
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    crate::report::note(format!("Detected OS: {os}, arch: {arch}"));
 
-    let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
-    let arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap();
-    println!("cargo:warning=Detected OS: {}", os);
-    println!("cargo:warning=Detected arch: {}", arch);
+    let os_string = target_string()?;
+    let manifest = crate::manifest::read()?;
+    // debug builds of SCIP are not published yet; they would be `libscip-{target}-debug.zip`
+    let url = manifest.expand("deploy.url", &os_string)?;
 
-    let os_string = target_string();
+    let sha256 = manifest.required_sha256(&os_string)?;
+    scip_sys_build::bundled::download(&url, Some(sha256), out_path).map_err(|e| {
+        e.remedy("Disable the `bundled` feature and point SCIPOPTDIR to a SCIP installation")
+    })
+}
 
-    // if debug mode is enabled, download the debug version of SCIP
-    #[cfg(debug_assertions)]
-    let debug_str = "-debug";
-    #[cfg(not(debug_assertions))]
-    let debug_str = "";
-    // Only consumed by the (currently disabled) debug-build URL below.
-    let _ = debug_str;
+/// Check that the downloaded SCIP in `scip_install` fits the target and the
+/// prebuilt bindings, so a wrong archive fails the build instead of linking
+/// with a mismatched ABI: libscip must be a library of the target's format and
+/// architecture, and the version in `scip/config.h` must be the one the
+/// committed `src/bindings/<tag>.rs` were generated for.
+#[cfg(feature = "bundled")]
+pub fn validate_download(scip_install: &Path) -> Result<()> {
+    use crate::version::ScipVersion;
 
-    // TODO: enable this when debug builds are available
-    // let url = format!(
-    //     "https://github.com/scipopt/scipoptsuite-deploy/releases/download/v0.12.0/libscip-{os_string}{debug_str}.zip",
-    // );
-    // v0.12.0 ships SCIP 10.0.2 / SoPlex 8.0.2 / GCG 4.0.2 / IPOPT 3.14.19.
-    let url = format!(
-        "https://github.com/scipopt/scipoptsuite-deploy/releases/download/v0.12.0/libscip-{os_string}.zip",
-    );
+    scip_sys_build::bundled::check_library(scip_install)?;
 
-    download_and_extract_zip(&url, &extract_path)
-        .unwrap_or_else(|e| panic!("Failed to download and extract SCIP: {}", e));
+    println!("cargo:rerun-if-env-changed=SCIP_SYS_REGENERATE_BINDINGS");
+    if env::var_os("SCIP_SYS_REGENERATE_BINDINGS").is_some() {
+        return Ok(());
+    }
+    let Some((bindings, _)) = crate::committed_bindings(&target_string()?)? else {
+        return Ok(());
+    };
+    let installed = ScipVersion::from_headers(&scip_install.join("include"));
+    let committed = ScipVersion::from_bindings(&bindings);
+    if installed != committed {
+        let describe = |version: Option<ScipVersion>| {
+            version.map_or("an unknown version".to_string(), |v| format!("SCIP {v}"))
+        };
+        let target = target_string()?;
+        return Err(BuildError::new(format!(
+            "the downloaded SCIP is {}, but src/bindings/{target}.rs were generated for {}",
+            describe(installed),
+            describe(committed)
+        ))
+        .remedy("Update the download URL to the release the bindings were generated for")
+        .remedy("Regenerate the bindings with SCIP_SYS_REGENERATE_BINDINGS=1"));
+    }
+    Ok(())
 }
@@ -1,61 +1,818 @@
 //! This file contains some custom callbacks for bindgen.
 
-use bindgen::callbacks::{Token, TokenKind};
-use std::collections::HashSet;
+use bindgen::callbacks::{IntKind, Token, TokenKind};
+use cexpr::expr::{EvalResult, IdentifierParser};
+use std::collections::{BTreeSet, HashMap};
+use std::num::Wrapping;
+use std::sync::{Arc, Mutex};
 
-/// This callback will be used to remove the type casts.
-/// bindgen has a hard time parsing constants with type casts like
-/// ```rust
+/// What a cast target converts its operand to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastKind {
+    /// An integer type of the given width, e.g. `SCIP_Longint` is `{ bits: 64, signed: true }`
+    Integer {
+        bits: u32,
+        signed: bool,
+    },
+    /// `_Bool`, which turns every non-zero value into 1
+    Bool,
+    Float,
+}
+
+/// This callback evaluates the constant expressions bindgen cannot handle on its own. bindgen has
+/// a hard time parsing constants like
+/// ```c
 /// #define SCIP_INVALID (double)1e99
+/// #define SCIP_REAL_MAX (SCIP_Real)DBL_MAX
+/// #define SCIP_EVENTTYPE_VARFIXED UINT64_C(0x000000002)
+/// #define SCIP_MEM_NOLIMIT (SCIP_Longint)(SIZE_MAX>>20)
 /// ```
+/// because the casts may name typedefs (or macros standing in for types), the limits come from
+/// compiler builtins and `UINT64_C` is a function-like macro.
+///
+/// For every macro in scope the callback expands the `(U)INTn_C` literal macros, substitutes the
+/// [`literal`](Self::literal)s for builtin limits and then evaluates the expression itself, with
+/// C's integer types: every integer carries its width and signedness, casts to C keyword types
+/// and to the registered [`cast_type`](Self::cast_type)s convert it, and the operators follow the
+/// usual arithmetic conversions. So `(unsigned)~0` is `4294967295` and `(SCIP_Longint)1 << 40`
+/// does not overflow. The result replaces the macro body, so bindgen only ever sees a single
+/// literal. Macros the callback cannot evaluate are left as they are.
+///
+/// Macros that neither this callback nor bindgen can evaluate are collected and can be retrieved
+/// through the handle returned by [`untranslated`](Self::untranslated) once bindgen is done.
 ///
 /// ### Note;
 /// Maybe we should be careful on which macros we use this? I can see a situation where Rust and C
-/// would have different opinions on what the macro should look like.
+/// would have different opinions on what the macro should look like. This is why only the
+/// macros matching a [`prefix`](Self::prefix) are touched.
 #[derive(Debug)]
 pub struct DeriveCastedConstant {
-    /// Set of macros to target for removing type casts
-    targets: HashSet<String>,
+    /// Every macro starting with one of these prefixes is targeted
+    prefixes: Vec<String>,
+    /// Non-keyword identifiers that may appear as the target of a cast
+    cast_types: HashMap<String, CastKind>,
+    /// Identifiers that are not macros bindgen sees (compiler builtins, limits) and their value
+    literals: HashMap<String, String>,
+    /// Width of `long` on the target
+    long_bits: u32,
+    /// Values of all macros evaluated so far, used to resolve references between macros
+    known: Mutex<HashMap<Vec<u8>, EvalResult>>,
+    /// The typed values of the macros this callback evaluated itself
+    values: Mutex<HashMap<Vec<u8>, Value>>,
+    /// Targeted macros that could not be turned into a constant
+    untranslated: Arc<Mutex<BTreeSet<String>>>,
 }
 
 impl DeriveCastedConstant {
     pub fn new() -> Self {
-        DeriveCastedConstant {
-            targets: HashSet::new(),
+        let mut callback = DeriveCastedConstant {
+            prefixes: Vec::new(),
+            cast_types: HashMap::new(),
+            literals: HashMap::new(),
+            long_bits: 64,
+            known: Mutex::new(HashMap::new()),
+            values: Mutex::new(HashMap::new()),
+            untranslated: Arc::new(Mutex::new(BTreeSet::new())),
+        };
+
+        // `size_t` and friends assume a 64-bit target, the caller registers them again otherwise
+        for (ty, bits, signed) in [
+            ("int8_t", 8, true),
+            ("int16_t", 16, true),
+            ("int32_t", 32, true),
+            ("int64_t", 64, true),
+            ("uint8_t", 8, false),
+            ("uint16_t", 16, false),
+            ("uint32_t", 32, false),
+            ("uint64_t", 64, false),
+            ("uint_fast8_t", 8, false),
+            ("size_t", 64, false),
+            ("ptrdiff_t", 64, true),
+            ("intptr_t", 64, true),
+            ("uintptr_t", 64, false),
+        ] {
+            callback = callback.cast_type(ty, CastKind::Integer { bits, signed });
         }
+
+        // Limits whose value does not depend on the target. `SIZE_MAX`, `LONG_MAX` and friends
+        // do, so they have to be registered by the caller.
+        let limits = [
+            ("CHAR_BIT", "8"),
+            ("SCHAR_MAX", "127"),
+            ("SCHAR_MIN", "(-127 - 1)"),
+            ("UCHAR_MAX", "255"),
+            ("SHRT_MAX", "32767"),
+            ("SHRT_MIN", "(-32767 - 1)"),
+            ("USHRT_MAX", "65535"),
+            ("INT_MAX", "2147483647"),
+            ("INT_MIN", "(-2147483647 - 1)"),
+            ("UINT_MAX", "4294967295U"),
+            ("LLONG_MAX", "9223372036854775807LL"),
+            ("LLONG_MIN", "(-9223372036854775807LL - 1)"),
+            ("ULLONG_MAX", "18446744073709551615ULL"),
+            ("INT64_MAX", "9223372036854775807LL"),
+            ("INT64_MIN", "(-9223372036854775807LL - 1)"),
+            ("UINT64_MAX", "18446744073709551615ULL"),
+            ("INT32_MAX", "2147483647"),
+            ("INT32_MIN", "(-2147483647 - 1)"),
+            ("UINT32_MAX", "4294967295U"),
+            ("DBL_MAX", "1.7976931348623157e308"),
+            ("DBL_MIN", "2.2250738585072014e-308"),
+            ("DBL_EPSILON", "2.220446049250313e-16"),
+            ("FLT_MAX", "3.4028234663852886e38"),
+            ("FLT_MIN", "1.1754943508222875e-38"),
+            ("FLT_EPSILON", "1.1920928955078125e-7"),
+        ];
+        for (name, value) in limits {
+            callback = callback.literal(name, value);
+            // glibc and clang define most limits through the `__NAME__` builtins
+            callback = callback.literal(&format!("__{name}__"), value);
+        }
+        callback.literal("__LONG_LONG_MAX__", "9223372036854775807LL")
+    }
+
+    /// Target every macro whose name starts with `prefix`.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefixes.push(prefix.to_string());
+        self
     }
 
-    pub fn target(mut self, name: &str) -> Self {
-        self.targets.insert(name.to_string());
+    /// Register a non-keyword type name (a typedef, or a macro like `SCIP_Real`) that may be the
+    /// target of a cast.
+    pub fn cast_type(mut self, name: &str, kind: CastKind) -> Self {
+        self.cast_types.insert(name.to_string(), kind);
         self
     }
+
+    /// Replace the identifier `name` by the C expression `value` before evaluating a macro.
+    pub fn literal(mut self, name: &str, value: &str) -> Self {
+        self.literals.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Set the width of `long` on the target (64 by default, 32 on Windows and 32-bit targets).
+    pub fn long_bits(mut self, bits: u32) -> Self {
+        self.long_bits = bits;
+        self
+    }
+
+    /// Handle to the names of the targeted macros that could not be translated. It is filled
+    /// while bindgen runs, so read it after `generate()`.
+    pub fn untranslated(&self) -> Arc<Mutex<BTreeSet<String>>> {
+        self.untranslated.clone()
+    }
+
+    fn is_targeted(&self, name: &str) -> bool {
+        self.prefixes.iter().any(|p| name.starts_with(p.as_str()))
+    }
+
+    /// Whether a single token can be part of a type name.
+    fn is_type_token(&self, token: &Token) -> bool {
+        let Ok(raw) = std::str::from_utf8(&token.raw) else {
+            return false;
+        };
+        match token.kind {
+            TokenKind::Keyword => matches!(
+                raw,
+                "double"
+                    | "float"
+                    | "char"
+                    | "short"
+                    | "int"
+                    | "long"
+                    | "signed"
+                    | "unsigned"
+                    | "_Bool"
+                    | "const"
+            ),
+            TokenKind::Identifier => self.cast_types.contains_key(raw),
+            _ => false,
+        }
+    }
+
+    /// The type named by a sequence of type tokens, e.g. `unsigned long long` or `SCIP_Longint`.
+    fn cast_kind(&self, types: &[Token]) -> Option<CastKind> {
+        let mut names = Vec::with_capacity(types.len());
+        for token in types {
+            let raw = std::str::from_utf8(&token.raw).ok()?;
+            if let Some(kind) = self.cast_types.get(raw) {
+                return Some(*kind);
+            }
+            if raw != "const" {
+                names.push(raw);
+            }
+        }
+        let count = |name| names.iter().filter(|n| **n == name).count();
+        if count("double") + count("float") > 0 {
+            return Some(CastKind::Float);
+        }
+        if count("_Bool") > 0 {
+            return Some(CastKind::Bool);
+        }
+        let bits = match count("long") {
+            0 if count("char") > 0 => 8,
+            0 if count("short") > 0 => 16,
+            0 => 32,
+            1 => self.long_bits,
+            _ => 64,
+        };
+        let signed = count("unsigned") == 0;
+        (!names.is_empty()).then_some(CastKind::Integer { bits, signed })
+    }
+
+    /// The integer type of a C integer literal: the first of the candidate types its suffix and
+    /// base allow that can represent the value.
+    fn literal_type(&self, raw: &[u8], value: i128) -> Option<IntType> {
+        let suffix = &raw[trim_int_suffix(raw).len()..];
+        let unsigned = suffix.iter().any(|c| matches!(c, b'u' | b'U'));
+        let longs = suffix.iter().filter(|c| matches!(c, b'l' | b'L')).count();
+        let decimal = !(raw.len() > 1 && raw[0] == b'0');
+
+        let int = |bits, signed| IntType { bits, signed };
+        let widths = [32, self.long_bits, 64];
+        widths[longs.min(2)..]
+            .iter()
+            .flat_map(|&bits| {
+                let signed = (!unsigned).then_some(int(bits, true));
+                let unsigned = (unsigned || !decimal).then_some(int(bits, false));
+                [signed, unsigned]
+            })
+            .flatten()
+            .find(|ty| ty.contains(value))
+    }
+
+    /// Rewrite the body of a macro into what the evaluator understands: expand the `(U)INTn_C`
+    /// macros and substitute the registered literals.
+    fn rewrite(&self, body: &[Token]) -> Vec<Token> {
+        let mut out: Vec<Token> = Vec::with_capacity(body.len());
+        let mut i = 0;
+        while i < body.len() {
+            let token = &body[i];
+
+            if token.kind == TokenKind::Identifier {
+                let raw = std::str::from_utf8(&token.raw).unwrap_or_default();
+
+                // `UINT64_C(x)` and friends only append a suffix to `x`
+                if let Some(suffix) = int_c_suffix(raw) {
+                    if body.get(i + 1).is_some_and(|t| is_punct(t, b"("))
+                        && body
+                            .get(i + 2)
+                            .is_some_and(|t| t.kind == TokenKind::Literal)
+                        && body.get(i + 3).is_some_and(|t| is_punct(t, b")"))
+                    {
+                        let mut raw = body[i + 2].raw.to_vec();
+                        raw.extend_from_slice(suffix.as_bytes());
+                        out.push(literal(&raw));
+                        i += 4;
+                        continue;
+                    }
+                }
+
+                if let Some(value) = self.literals.get(raw) {
+                    out.extend(tokenize(value));
+                    i += 1;
+                    continue;
+                }
+            }
+
+            out.push(token.clone());
+            i += 1;
+        }
+        out
+    }
 }
 
 /// Implement the ParseCallbacks trait for DeriveCastedConstant
 impl bindgen::callbacks::ParseCallbacks for DeriveCastedConstant {
-    fn modify_macro(&self, _name: &str, _tokens: &mut Vec<Token>) {
-        // modify SCIP_INVALID
-        if self.targets.contains(_name) {
-            // So here we are looking for a pattern like ['(', type, ')']
-            let position_cast = _tokens.windows(3).position(|window| match window {
-                [Token {
-                    kind: TokenKind::Punctuation,
-                    raw: left_parenthesis,
-                }, Token {
-                    // this will not go off on a cast like (SCIP_Real) as SCIP_Real is not a
-                    // Clang-keyword
-                    kind: TokenKind::Keyword,
-                    ..
-                }, Token {
-                    kind: TokenKind::Punctuation,
-                    raw: right_parenthesis,
-                }] => **left_parenthesis == *b"(" && **right_parenthesis == *b")",
-                _ => false,
+    fn modify_macro(&self, name: &str, tokens: &mut Vec<Token>) {
+        // The first token is the name of the macro itself
+        if tokens.len() < 2 {
+            return;
+        }
+        let mut known = self.known.lock().unwrap();
+        let mut values = self.values.lock().unwrap();
+
+        if self.is_targeted(name) {
+            let body = self.rewrite(&tokens[1..]);
+            // Otherwise bindgen gets to see the macro as it is written in the header
+            if let Some(value) = Evaluator::new(self, &body, &known, &values).evaluate() {
+                tokens.truncate(1);
+                tokens.extend(value.tokens());
+                values.insert(name.as_bytes().to_vec(), value);
+            }
+        }
+
+        // Mirror what bindgen does next, so later macros can refer to this one and so we know
+        // whether it will end up in the bindings at all.
+        match IdentifierParser::new(&known).macro_definition(tokens) {
+            Ok((_, (_, value))) if !matches!(value, EvalResult::Invalid) => {
+                known.insert(name.as_bytes().to_vec(), value);
+            }
+            _ => {
+                // Macros standing in for a type (`#define SCIP_Real double`) are not constants
+                let is_type = tokens[1..].iter().all(|t| self.is_type_token(t));
+                if self.is_targeted(name) && !is_type {
+                    self.untranslated.lock().unwrap().insert(name.to_string());
+                }
+            }
+        }
+    }
+
+    /// Give the constants the type C gives them, e.g. `i64` for a cast to `SCIP_Longint`, instead
+    /// of the one bindgen picks from the value alone. Plain `int` results keep bindgen's choice.
+    fn int_macro(&self, name: &str, _value: i64) -> Option<IntKind> {
+        match self.values.lock().unwrap().get(name.as_bytes())? {
+            Value::Int(_, ty) if *ty != IntType::INT => ty.int_kind(),
+            _ => None,
+        }
+    }
+}
+
+fn is_punct(token: &Token, punct: &[u8]) -> bool {
+    token.kind == TokenKind::Punctuation && *token.raw == *punct
+}
+
+/// The suffix `INT64_C(x)` and friends append to `x`, for the macros of `<stdint.h>`.
+fn int_c_suffix(name: &str) -> Option<&'static str> {
+    match name {
+        "INT8_C" | "INT16_C" | "INT32_C" | "UINT8_C" | "UINT16_C" => Some(""),
+        "UINT32_C" => Some("U"),
+        "INT64_C" | "INTMAX_C" => Some("LL"),
+        "UINT64_C" | "UINTMAX_C" => Some("ULL"),
+        _ => None,
+    }
+}
+
+fn literal(raw: &[u8]) -> Token {
+    Token {
+        kind: TokenKind::Literal,
+        raw: raw.to_vec().into_boxed_slice(),
+    }
+}
+
+fn punct(raw: &[u8]) -> Token {
+    Token {
+        kind: TokenKind::Punctuation,
+        raw: raw.to_vec().into_boxed_slice(),
+    }
+}
+
+/// Split the small C expressions used for [`DeriveCastedConstant::literal`] into tokens.
+fn tokenize(expr: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let bytes = expr.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c.is_ascii_alphanumeric() || c == b'.' || c == b'_' {
+            let start = i;
+            while i < bytes.len() {
+                let c = bytes[i];
+                let exponent_sign = (c == b'-' || c == b'+')
+                    && matches!(bytes[i - 1], b'e' | b'E')
+                    && bytes[start].is_ascii_digit();
+                if c.is_ascii_alphanumeric() || c == b'.' || c == b'_' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            let kind = if bytes[start].is_ascii_digit() || bytes[start] == b'.' {
+                TokenKind::Literal
+            } else {
+                TokenKind::Identifier
+            };
+            tokens.push(Token {
+                kind,
+                raw: bytes[start..i].to_vec().into_boxed_slice(),
             });
-            if let Some(pos) = position_cast {
-                // position found! So a macro with a type cast exists. We remove the typecast.
-                *_tokens = [&_tokens[..pos], &_tokens[pos + 3..]].concat();
+        } else {
+            tokens.push(punct(&[c]));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Strip the `u`/`l` suffixes of an integer literal (they never collide with hexadecimal digits).
+fn trim_int_suffix(raw: &[u8]) -> &[u8] {
+    let end = raw
+        .iter()
+        .rposition(|c| !matches!(c, b'u' | b'U' | b'l' | b'L'))
+        .map_or(0, |p| p + 1);
+    &raw[..end]
+}
+
+fn parse_int(raw: &[u8]) -> Option<i128> {
+    let digits = std::str::from_utf8(trim_int_suffix(raw)).ok()?;
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i128::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = digits
+        .strip_prefix("0b")
+        .or_else(|| digits.strip_prefix("0B"))
+    {
+        i128::from_str_radix(bin, 2).ok()
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i128::from_str_radix(&digits[1..], 8).ok()
+    } else {
+        digits.parse().ok()
+    }
+}
+
+fn parse_float(raw: &[u8]) -> Option<f64> {
+    let text = std::str::from_utf8(raw).ok()?;
+    let text = text.trim_end_matches(['f', 'F', 'l', 'L']);
+    if text.starts_with("0x") || text.starts_with("0X") {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// A C integer type, given by its width and signedness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IntType {
+    bits: u32,
+    signed: bool,
+}
+
+impl IntType {
+    const INT: IntType = IntType {
+        bits: 32,
+        signed: true,
+    };
+
+    /// Convert `value` to this type: modulo 2^bits, as C does for unsigned targets (and as every
+    /// compiler does for signed ones).
+    fn wrap(self, value: i128) -> i128 {
+        let modulus = 1i128 << self.bits;
+        let value = value.rem_euclid(modulus);
+        if self.signed && value >= modulus / 2 {
+            value - modulus
+        } else {
+            value
+        }
+    }
+
+    fn contains(self, value: i128) -> bool {
+        self.wrap(value) == value
+    }
+
+    /// The Rust type bindgen should use for a constant of this type.
+    fn int_kind(self) -> Option<IntKind> {
+        Some(match (self.bits, self.signed) {
+            (8, true) => IntKind::I8,
+            (8, false) => IntKind::U8,
+            (16, true) => IntKind::I16,
+            (16, false) => IntKind::U16,
+            (32, true) => IntKind::I32,
+            (32, false) => IntKind::U32,
+            (64, true) => IntKind::I64,
+            (64, false) => IntKind::U64,
+            _ => return None,
+        })
+    }
+
+    /// The integer promotion: everything narrower than `int` becomes `int`.
+    fn promote(self) -> Self {
+        if self.bits < 32 {
+            IntType::INT
+        } else {
+            self
+        }
+    }
+
+    /// The type both operands of a binary operator are converted to (the usual arithmetic
+    /// conversions).
+    fn common(self, other: Self) -> Self {
+        let (a, b) = (self.promote(), other.promote());
+        if a.signed == b.signed {
+            return if a.bits >= b.bits { a } else { b };
+        }
+        let (signed, unsigned) = if a.signed { (a, b) } else { (b, a) };
+        if unsigned.bits >= signed.bits {
+            unsigned
+        } else {
+            signed
+        }
+    }
+
+    /// The result of an arithmetic operation in this type. Signed overflow is undefined in C,
+    /// so such a macro is not evaluated at all.
+    fn result(self, value: i128) -> Option<Value> {
+        (!self.signed || self.contains(value)).then(|| Value::Int(self.wrap(value), self))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Int(i128, IntType),
+    Float(f64),
+}
+
+impl Value {
+    fn as_float(self) -> f64 {
+        match self {
+            Value::Int(i, _) => i as f64,
+            Value::Float(f) => f,
+        }
+    }
+
+    fn truthy(self) -> bool {
+        match self {
+            Value::Int(i, _) => i != 0,
+            Value::Float(f) => f != 0.0,
+        }
+    }
+
+    fn bool(b: bool) -> Value {
+        Value::Int(b as i128, IntType::INT)
+    }
+
+    /// Convert the value as a cast to `kind` does, or `None` if C leaves the result undefined.
+    fn cast(self, kind: CastKind) -> Option<Value> {
+        match (kind, self) {
+            (CastKind::Float, value) => Some(Value::Float(value.as_float())),
+            (CastKind::Bool, value) => Some(Value::Int(
+                value.truthy() as i128,
+                IntType {
+                    bits: 8,
+                    signed: false,
+                },
+            )),
+            (CastKind::Integer { bits, signed }, Value::Int(i, _)) => {
+                let ty = IntType { bits, signed };
+                Some(Value::Int(ty.wrap(i), ty))
+            }
+            (CastKind::Integer { bits, signed }, Value::Float(f)) => {
+                let ty = IntType { bits, signed };
+                let i = f.trunc();
+                (i.abs() < 2f64.powi(64) && ty.contains(i as i128))
+                    .then_some(Value::Int(i as i128, ty))
+            }
+        }
+    }
+
+    /// The tokens of a C literal with this value.
+    fn tokens(self) -> Vec<Token> {
+        let (negative, raw) = match self {
+            Value::Int(i, _) => (i < 0, i.unsigned_abs().to_string()),
+            Value::Float(f) => (f.is_sign_negative(), float_literal(f.abs())),
+        };
+        let mut tokens = Vec::new();
+        if negative {
+            tokens.push(punct(b"-"));
+        }
+        tokens.push(literal(raw.as_bytes()));
+        tokens
+    }
+}
+
+/// Format a float as a C literal that round-trips and that cexpr can parse. cexpr rejects a
+/// fraction combined with an exponent (`1.5e3`), so those are written as `15e2`.
+fn float_literal(f: f64) -> String {
+    let repr = format!("{:?}", f);
+    match repr.split_once('e') {
+        Some((mantissa, exponent)) => match mantissa.split_once('.') {
+            Some((int, frac)) => {
+                let exponent: i32 = exponent.parse().unwrap();
+                format!("{int}{frac}e{}", exponent - frac.len() as i32)
             }
+            None => repr,
+        },
+        None => repr,
+    }
+}
+
+/// A small precedence-climbing evaluator for the constant expressions found in the SCIP headers.
+/// Integers are kept in 128 bits together with their C type, so unsigned 64-bit arithmetic
+/// (`SIZE_MAX>>20`) is exact and every result is wrapped into the type C gives it.
+struct Evaluator<'a> {
+    callback: &'a DeriveCastedConstant,
+    tokens: &'a [Token],
+    pos: usize,
+    known: &'a HashMap<Vec<u8>, EvalResult>,
+    values: &'a HashMap<Vec<u8>, Value>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(
+        callback: &'a DeriveCastedConstant,
+        tokens: &'a [Token],
+        known: &'a HashMap<Vec<u8>, EvalResult>,
+        values: &'a HashMap<Vec<u8>, Value>,
+    ) -> Self {
+        Evaluator {
+            callback,
+            tokens,
+            pos: 0,
+            known,
+            values,
+        }
+    }
+
+    /// Evaluate the whole token list, or `None` if it is not a constant expression whose result
+    /// fits into the 64-bit constants bindgen emits.
+    fn evaluate(mut self) -> Option<Value> {
+        let value = self.binary(0)?;
+        if self.pos != self.tokens.len() {
+            return None;
+        }
+        match value {
+            // cexpr reads literals above `i64::MAX` bit-cast to `i64`, and `int_macro` makes
+            // bindgen cast them back to `u64`
+            Value::Int(i, _) if i < i64::MIN as i128 || i > u64::MAX as i128 => None,
+            Value::Float(f) if !f.is_finite() => None,
+            value => Some(value),
+        }
+    }
+
+    fn peek_punct(&self) -> Option<&'a [u8]> {
+        self.tokens
+            .get(self.pos)
+            .filter(|t| t.kind == TokenKind::Punctuation)
+            .map(|t| &*t.raw)
+    }
+
+    fn binary(&mut self, min_precedence: u8) -> Option<Value> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.peek_punct() {
+            let precedence = match op {
+                b"*" | b"/" | b"%" => 10,
+                b"+" | b"-" => 9,
+                b"<<" | b">>" => 8,
+                b"<" | b"<=" | b">" | b">=" => 7,
+                b"==" | b"!=" => 6,
+                b"&" => 5,
+                b"^" => 4,
+                b"|" => 3,
+                b"&&" => 2,
+                b"||" => 1,
+                _ => break,
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.binary(precedence + 1)?;
+            lhs = apply(op, lhs, rhs)?;
         }
+        Some(lhs)
+    }
+
+    /// The cast starting at the current `(`, if it is one, and the position after its `)`.
+    fn cast(&self) -> Option<(CastKind, usize)> {
+        let types = self.tokens[self.pos..]
+            .iter()
+            .take_while(|t| self.callback.is_type_token(t))
+            .count();
+        let close = self.pos + types;
+        if types == 0 || !self.tokens.get(close).is_some_and(|t| is_punct(t, b")")) {
+            return None;
+        }
+        let kind = self.callback.cast_kind(&self.tokens[self.pos..close])?;
+        Some((kind, close + 1))
+    }
+
+    fn unary(&mut self) -> Option<Value> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        match token.kind {
+            TokenKind::Punctuation => match &*token.raw {
+                b"(" => {
+                    if let Some((kind, next)) = self.cast() {
+                        self.pos = next;
+                        return self.unary()?.cast(kind);
+                    }
+                    let value = self.binary(0)?;
+                    (self.peek_punct()? == b")").then_some(())?;
+                    self.pos += 1;
+                    Some(value)
+                }
+                b"-" => match self.unary()? {
+                    Value::Int(i, ty) => ty.promote().result(-i),
+                    Value::Float(f) => Some(Value::Float(-f)),
+                },
+                b"+" => match self.unary()? {
+                    Value::Int(i, ty) => Some(Value::Int(i, ty.promote())),
+                    value => Some(value),
+                },
+                b"~" => match self.unary()? {
+                    Value::Int(i, ty) => ty.promote().result(!i),
+                    Value::Float(_) => None,
+                },
+                b"!" => Some(Value::bool(!self.unary()?.truthy())),
+                _ => None,
+            },
+            TokenKind::Literal => match parse_int(&token.raw) {
+                Some(i) => Some(Value::Int(i, self.callback.literal_type(&token.raw, i)?)),
+                None => parse_float(&token.raw).map(Value::Float),
+            },
+            TokenKind::Identifier => {
+                if let Some(value) = self.values.get(&*token.raw) {
+                    return Some(*value);
+                }
+                // Macros bindgen evaluated on its own are typed like a literal of their value
+                match self.known.get(&*token.raw)? {
+                    EvalResult::Int(Wrapping(i)) => {
+                        let i = *i as i128;
+                        Some(Value::Int(i, self.callback.literal_type(b"0", i)?))
+                    }
+                    EvalResult::Float(f) => Some(Value::Float(*f)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+fn apply(op: &[u8], lhs: Value, rhs: Value) -> Option<Value> {
+    use Value::{Float, Int};
+    match (lhs, rhs) {
+        (Int(a, a_ty), Int(b, _)) if matches!(op, b"<<" | b">>") => {
+            // The result has the promoted type of the left operand, shifting by its width or more
+            // is undefined
+            let ty = a_ty.promote();
+            let shift = u32::try_from(b).ok().filter(|s| *s < ty.bits)?;
+            let a = ty.wrap(a);
+            if op == b">>" {
+                return Some(Int(a >> shift, ty));
+            }
+            if a < 0 {
+                return None;
+            }
+            ty.result(((a as u128) << shift) as i128)
+        }
+        (Int(a, a_ty), Int(b, b_ty)) => {
+            let ty = a_ty.common(b_ty);
+            let (a, b) = (ty.wrap(a), ty.wrap(b));
+            let value = match op {
+                b"*" => a.checked_mul(b)?,
+                b"/" => a.checked_div(b)?,
+                b"%" => a.checked_rem(b)?,
+                b"+" => a + b,
+                b"-" => a - b,
+                b"&" => a & b,
+                b"^" => a ^ b,
+                b"|" => a | b,
+                _ => return compare(op, Int(a, ty), Int(b, ty)),
+            };
+            ty.result(value)
+        }
+        _ => {
+            let (a, b) = (lhs.as_float(), rhs.as_float());
+            Some(Float(match op {
+                b"*" => a * b,
+                b"/" => a / b,
+                b"+" => a + b,
+                b"-" => a - b,
+                b"%" | b"<<" | b">>" | b"&" | b"^" | b"|" => return None,
+                _ => return compare(op, lhs, rhs),
+            }))
+        }
+    }
+}
+
+fn compare(op: &[u8], lhs: Value, rhs: Value) -> Option<Value> {
+    let result = match op {
+        b"&&" => lhs.truthy() && rhs.truthy(),
+        b"||" => lhs.truthy() || rhs.truthy(),
+        _ => {
+            let ordering = match (lhs, rhs) {
+                (Value::Int(a, _), Value::Int(b, _)) => a.partial_cmp(&b),
+                _ => lhs.as_float().partial_cmp(&rhs.as_float()),
+            }?;
+            match op {
+                b"<" => ordering.is_lt(),
+                b"<=" => ordering.is_le(),
+                b">" => ordering.is_gt(),
+                b">=" => ordering.is_ge(),
+                b"==" => ordering.is_eq(),
+                b"!=" => ordering.is_ne(),
+                _ => return None,
+            }
+        }
+    };
+    Some(Value::bool(result))
+}
+
+/// Translates SCIP's Doxygen comments into rustdoc markdown (see `doxygen.rs`).
+#[derive(Debug)]
+pub struct DoxygenComments;
+
+impl bindgen::callbacks::ParseCallbacks for DoxygenComments {
+    fn process_comment(&self, comment: &str) -> Option<String> {
+        // bindgen leaves out empty docs, while `None` would keep the raw comment (e.g. `@{`)
+        let doc = crate::doxygen::to_rustdoc(comment);
+        Some(if doc.trim().is_empty() {
+            String::new()
+        } else {
+            doc
+        })
     }
 }
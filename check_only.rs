@@ -0,0 +1,84 @@
+//! Check-only builds, for `cargo check`, clippy and rust-analyzer without a SCIP installation.
+//!
+//! Like the docs.rs build, a check-only build takes the committed bindings instead of looking for
+//! SCIP: the prebuilt ones for the target, or the pregenerated ones where there are none (see
+//! `prebuilt.rs`). Nothing is downloaded and libscip is not linked. Instead the crate links a library
+//! that does not exist and whose name says why, so that building a binary, test or example in
+//! this mode fails at link time rather than producing an artifact without SCIP.
+//!
+//! The mode is selected with `SCIP_SYS_CHECK_ONLY=1`, and taken automatically when the build runs
+//! for clippy or rust-analyzer, unless `SCIP_SYS_CHECK_ONLY=0`. Cargo does not tell build scripts
+//! about a plain `cargo check`, so there it has to be asked for. Neither are other tools that wrap
+//! rustc, e.g. a `RUSTC_WRAPPER` set up for linting.
+
+use crate::error::Result;
+use crate::{
+    apply_header_map, build_info, bundled, committed_bindings, pregenerated_bindings, report,
+    write_bindings,
+};
+use std::env;
+use std::path::Path;
+
+/// The library linked instead of libscip, which fails the link with this name.
+const MISSING_LIBRARY: &str = "scip_sys_check_only__unset_SCIP_SYS_CHECK_ONLY_to_link_scip";
+
+/// Whether this is a check-only build, and why.
+pub fn reason() -> Option<&'static str> {
+    for var in [
+        "SCIP_SYS_CHECK_ONLY",
+        "CLIPPY_ARGS",
+        "RUSTC_WORKSPACE_WRAPPER",
+        "RA_RUSTC_WRAPPER",
+    ] {
+        println!("cargo:rerun-if-env-changed={var}");
+    }
+    match env::var("SCIP_SYS_CHECK_ONLY").as_deref() {
+        Ok("0") | Ok("false") => return None,
+        Ok("") | Err(_) => {}
+        Ok(_) => return Some("SCIP_SYS_CHECK_ONLY is set"),
+    }
+
+    // `cargo clippy` passes its arguments in `CLIPPY_ARGS`, which every build script sees. It
+    // also runs clippy-driver as the wrapper of rustc, but only for workspace members, so
+    // `RUSTC_WORKSPACE_WRAPPER` is only a fallback for crates checked in their own workspace.
+    // rust-analyzer marks its wrapper.
+    let clippy = env::var_os("CLIPPY_ARGS").is_some()
+        || env::var_os("RUSTC_WORKSPACE_WRAPPER").is_some_and(|wrapper| {
+            Path::new(&wrapper)
+                .file_stem()
+                .is_some_and(|name| name.to_string_lossy().starts_with("clippy-driver"))
+        });
+    if clippy {
+        Some("the build runs for clippy")
+    } else if env::var_os("RA_RUSTC_WRAPPER").is_some() {
+        Some("the build runs for rust-analyzer")
+    } else {
+        None
+    }
+}
+
+/// Set up the committed bindings for `<out_path>/bindings.rs` without SCIP.
+pub fn emit(reason: &str, out_path: &Path) -> Result<()> {
+    report::strategy("check-only");
+    report::note(format!("Check-only build because {reason}"));
+
+    let prebuilt = match bundled::try_target_string() {
+        Some(target) => committed_bindings(&target)?,
+        None => None,
+    };
+    let (kind, (bindings, file)) = match prebuilt {
+        Some(prebuilt) => ("prebuilt", prebuilt),
+        None => ("pregenerated", pregenerated_bindings()?),
+    };
+    write_bindings(out_path, &bindings)?;
+    report::bindings(kind, Some(&file));
+    apply_header_map(None, out_path)?;
+    build_info::emit(None, out_path)?;
+
+    // with `runtime-load` nothing is linked anyway, so the artifacts work
+    if !cfg!(feature = "runtime-load") {
+        report::link(format!("rustc-link-lib=dylib={MISSING_LIBRARY}"));
+    }
+    println!("cargo:check_only=true");
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! Named type aliases for SCIP's plugin callback signatures.
+//!
+//! SCIP declares every callback kind with a function-like macro, e.g.
+//! ```c
+//! #define SCIP_DECL_HEUREXEC(x) SCIP_RETCODE x (SCIP* scip, SCIP_HEUR* heur, ...)
+//! SCIP_RETCODE SCIPincludeHeurBasic(..., SCIP_DECL_HEUREXEC((*heurexec)), ...);
+//! ```
+//! bindgen never emits function-like macros, so the callbacks end up as anonymous
+//! `Option<unsafe extern "C" fn(...)>` parameters. Before the SCIP headers are parsed, the
+//! wrapper header written here declares a function typedef named after each macro and redefines
+//! the macro to use it:
+//! ```c
+//! typedef SCIP_DECL_HEUREXEC(SCIP_DECL_HEUREXEC);
+//! #undef SCIP_DECL_HEUREXEC
+//! #define SCIP_DECL_HEUREXEC(x) SCIP_DECL_HEUREXEC x
+//! ```
+//! A function-like macro is not expanded inside its own expansion, so the typedef can share the
+//! macro's name. Every later `SCIP_DECL_HEUREXEC((*heurexec))` then declares a pointer to the
+//! typedef, which bindgen emits as `heurexec: SCIP_DECL_HEUREXEC`.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A `SCIP_DECL_*` macro and the header defining it, relative to the include directory.
+#[derive(Debug)]
+pub struct DeclMacro {
+    pub name: String,
+    pub header: String,
+}
+
+/// Collect all single-argument `SCIP_DECL_*` macros in the headers below `<include_dir>/scip`.
+pub fn scan_decl_macros(include_dir: &Path) -> Result<Vec<DeclMacro>, Box<dyn Error>> {
+    let mut macros = Vec::new();
+    let mut dirs = vec![include_dir.join("scip")];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "h") {
+                let header = header_name(include_dir, &path);
+                let contents = String::from_utf8_lossy(&fs::read(&path)?).into_owned();
+                macros.extend(
+                    contents
+                        .lines()
+                        .filter_map(decl_macro_name)
+                        .map(|name| DeclMacro {
+                            name,
+                            header: header.clone(),
+                        }),
+                );
+            }
+        }
+    }
+    // The `type_*.h` headers only declare types, so pull them in before the plugin headers
+    macros.sort_by_key(|m| {
+        (
+            !m.header.starts_with("scip/type_"),
+            m.header.clone(),
+            m.name.clone(),
+        )
+    });
+    let mut seen = std::collections::HashSet::new();
+    macros.retain(|m| seen.insert(m.name.clone()));
+    Ok(macros)
+}
+
+/// `path` relative to `include_dir`, with forward slashes as used in `#include`.
+fn header_name(include_dir: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(include_dir).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The name of the macro defined on `line`, if it is a `#define SCIP_DECL_NAME(x)`.
+fn decl_macro_name(line: &str) -> Option<String> {
+    let rest = line.trim_start().strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix("define")?.trim_start();
+    let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))?;
+    let (name, params) = rest.split_at(end);
+    let param = params.strip_prefix('(')?.split_once(')')?.0.trim();
+    let single_param =
+        !param.is_empty() && param.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    (name.starts_with("SCIP_DECL_") && single_param).then(|| name.to_string())
+}
+
+/// Write `<out_path>/scip_wrapper.h`: it turns every callback macro into a typedef and then
+/// includes `headers`.
+pub fn write_wrapper_header(
+    macros: &[DeclMacro],
+    headers: &[&str],
+    out_path: &Path,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let mut wrapper = String::from("/* generated by scip-sys */\n\n");
+
+    let mut included: Vec<&str> = macros.iter().map(|m| m.header.as_str()).collect();
+    included.dedup();
+    for header in included {
+        wrapper.push_str(&format!("#include <{header}>\n"));
+    }
+    wrapper.push('\n');
+
+    for DeclMacro { name, .. } in macros {
+        wrapper.push_str(&format!(
+            "#ifdef {name}\n\
+             typedef {name}({name});\n\
+             #undef {name}\n\
+             #define {name}(x) {name} x\n\
+             #endif\n"
+        ));
+    }
+    wrapper.push('\n');
+
+    for header in headers {
+        wrapper.push_str(&format!("#include <{header}>\n"));
+    }
+
+    let path = out_path.join("scip_wrapper.h");
+    fs::write(&path, wrapper)?;
+    Ok(path)
+}
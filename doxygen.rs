@@ -0,0 +1,627 @@
+//! Translation of SCIP's Doxygen comments into rustdoc markdown.
+//!
+//! bindgen copies the C comments verbatim, so `@param`, `@return`, `@pre` and `\ref` show up raw
+//! in the generated docs and the indented stage lists are rendered as code blocks. [`to_rustdoc`]
+//! rewrites a single comment: block tags become `# Arguments`, `# Returns`, `# Stages`, `# Note`,
+//! ... sections, inline commands become markdown and references to other items become intra-doc
+//! links.
+//!
+//! SCIP documents most parameters next to the declaration (`SCIP* scip, /**< ... */`), which
+//! bindgen does not keep. [`scan_param_docs`] collects those from the headers, and
+//! [`add_argument_docs`] adds them to the generated functions as an `# Arguments` section.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// The documented parameters of each function, in declaration order.
+pub type ParamDocs = HashMap<String, Vec<(String, String)>>;
+
+/// The sections a comment is split into, in the order they are emitted.
+const SECTIONS: [&str; 13] = [
+    "Arguments",
+    "Returns",
+    "Stages",
+    "Preconditions",
+    "Postconditions",
+    "Note",
+    "Attention",
+    "Warning",
+    "Deprecated",
+    "See also",
+    "Remarks",
+    "Todo",
+    "Bug",
+];
+
+const RETCODES: [&str; 20] = [
+    "SCIP_OKAY",
+    "SCIP_ERROR",
+    "SCIP_NOMEMORY",
+    "SCIP_READERROR",
+    "SCIP_WRITEERROR",
+    "SCIP_NOFILE",
+    "SCIP_FILECREATEERROR",
+    "SCIP_LPERROR",
+    "SCIP_NOPROBLEM",
+    "SCIP_INVALIDCALL",
+    "SCIP_INVALIDDATA",
+    "SCIP_INVALIDRESULT",
+    "SCIP_PLUGINNOTFOUND",
+    "SCIP_PARAMETERUNKNOWN",
+    "SCIP_PARAMETERWRONGTYPE",
+    "SCIP_PARAMETERWRONGVAL",
+    "SCIP_KEYALREADYEXISTING",
+    "SCIP_MAXDEPTHLEVEL",
+    "SCIP_BRANCHERROR",
+    "SCIP_NOTIMPLEMENTED",
+];
+
+/// Where the lines of a comment currently go.
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Description,
+    Section(usize),
+}
+
+/// Translate one comment, as bindgen hands it to `ParseCallbacks::process_comment`.
+pub fn to_rustdoc(comment: &str) -> String {
+    let mut description: Vec<String> = Vec::new();
+    let mut sections: Vec<Vec<String>> = vec![Vec::new(); SECTIONS.len()];
+    let mut target = Target::Description;
+    // closing tag of the code block we are in, if any
+    let mut verbatim: Option<&str> = None;
+
+    // `/**< ... */` documents the preceding member
+    let comment = comment.trim_start().strip_prefix('<').unwrap_or(comment);
+    let comment = join_split_commands(comment);
+
+    for line in comment.lines() {
+        let trimmed = line.trim();
+        let lines = match target {
+            Target::Description => &mut description,
+            Target::Section(i) => &mut sections[i],
+        };
+
+        if let Some(end) = verbatim {
+            if trimmed.starts_with(end) {
+                lines.push("```".to_string());
+                verbatim = None;
+            } else {
+                lines.push(line.trim_end().to_string());
+            }
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            // An empty line ends a block tag, except for the trailing pointer to the list of
+            // stages, which belongs to the stages section.
+            if target != Target::Section(section("Stages")) {
+                target = Target::Description;
+            }
+            match target {
+                Target::Description => description.push(String::new()),
+                Target::Section(i) => sections[i].push(String::new()),
+            }
+            continue;
+        }
+
+        // markdown code blocks without a language would be compiled as Rust doctests
+        if let Some(language) = trimmed.strip_prefix("```") {
+            let language = if language.is_empty() {
+                "text"
+            } else {
+                language
+            };
+            lines.push(format!("```{language}"));
+            verbatim = Some("```");
+            continue;
+        }
+
+        let Some((tag, rest)) = block_tag(trimmed) else {
+            if trimmed.starts_with("See \\ref SCIP_Stage") {
+                // the pointer to the list of stages closes the `@pre` list, wherever it is
+                let i = section("Stages");
+                sections[i].push(String::new());
+                sections[i].push(inline(trimmed));
+                target = Target::Section(i);
+            } else if target == Target::Section(section("Stages"))
+                && !trimmed.starts_with('-')
+                && lines.last().is_some_and(|l| l.is_empty())
+            {
+                target = Target::Description;
+                description.push(inline(trimmed));
+            } else if let Some(item) = trimmed.strip_prefix("- ") {
+                lines.push(format!("- {}", inline(item)));
+            } else if lines
+                .last()
+                .is_some_and(|l| l.starts_with("- ") || l.starts_with("* "))
+            {
+                // continuation of a list item
+                lines.push(format!("  {}", inline(trimmed)));
+            } else {
+                lines.push(inline(trimmed));
+            }
+            continue;
+        };
+
+        match tag {
+            "code" | "verbatim" | "f[" => {
+                lines.push(if tag == "code" { "```c" } else { "```text" }.to_string());
+                verbatim = Some(match tag {
+                    "code" => "@endcode",
+                    "verbatim" => "@endverbatim",
+                    _ => "@f]",
+                });
+                // the closing tag may also be written with a backslash
+                if trimmed.starts_with('\\') {
+                    verbatim = verbatim.map(|end| match end {
+                        "@endcode" => "\\endcode",
+                        "@endverbatim" => "\\endverbatim",
+                        _ => "\\f]",
+                    });
+                }
+            }
+            "param" | "tparam" => {
+                let (name, desc) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let i = section("Arguments");
+                sections[i].push(format!("* `{}` - {}", name, inline(desc.trim())));
+                target = Target::Section(i);
+            }
+            "retval" => {
+                let (code, desc) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let i = section("Returns");
+                sections[i].push(format!(
+                    "* {} - {}",
+                    reference(code, None),
+                    inline(desc.trim())
+                ));
+                target = Target::Section(i);
+            }
+            "brief" | "short" | "details" => {
+                description.push(inline(rest));
+                target = Target::Description;
+            }
+            "ingroup" | "addtogroup" | "defgroup" | "name" | "anchor" | "file" | "{" | "}" => {}
+            _ => {
+                let heading = match tag {
+                    "return" | "returns" | "result" => "Returns",
+                    "pre" if rest.contains("stage") => "Stages",
+                    "pre" => "Preconditions",
+                    "post" => "Postconditions",
+                    "note" => "Note",
+                    "attention" => "Attention",
+                    "warning" => "Warning",
+                    "deprecated" => "Deprecated",
+                    "see" | "sa" => "See also",
+                    "remark" | "remarks" => "Remarks",
+                    "todo" => "Todo",
+                    "bug" => "Bug",
+                    _ => {
+                        lines.push(inline(trimmed));
+                        continue;
+                    }
+                };
+                let i = section(heading);
+                let text = if heading == "See also" {
+                    see_also(rest)
+                } else {
+                    inline(rest)
+                };
+                if !sections[i].is_empty() {
+                    sections[i].push(String::new());
+                }
+                sections[i].push(text);
+                target = Target::Section(i);
+            }
+        }
+    }
+    if verbatim.is_some() {
+        match target {
+            Target::Description => description.push("```".to_string()),
+            Target::Section(i) => sections[i].push("```".to_string()),
+        }
+    }
+
+    let mut out = tidy(description);
+    for (heading, lines) in SECTIONS.iter().zip(sections) {
+        let lines = tidy(lines);
+        if lines.is_empty() {
+            continue;
+        }
+        if !out.is_empty() {
+            out.push(String::new());
+        }
+        out.push(format!("# {heading}"));
+        out.push(String::new());
+        out.extend(lines);
+    }
+
+    out.iter()
+        .map(|l| {
+            if l.is_empty() {
+                String::new()
+            } else {
+                format!(" {l}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Move the argument of a command that ends a line (`See \ref` followed by `SCIP_Retcode`) up
+/// to that line.
+fn join_split_commands(comment: &str) -> String {
+    let mut out = String::with_capacity(comment.len());
+    let mut lines = comment.lines();
+    while let Some(line) = lines.next() {
+        out.push_str(line);
+        let mut current = line;
+        while ["@ref", "\\ref", "@p", "\\p", "@a", "@c", "@b", "@e"]
+            .iter()
+            .any(|command| {
+                current
+                    .trim_end()
+                    .strip_suffix(command)
+                    .is_some_and(|before| before.is_empty() || before.ends_with(' '))
+            })
+        {
+            match lines.next() {
+                Some(next) => {
+                    out.truncate(out.trim_end().len());
+                    out.push(' ');
+                    out.push_str(next.trim_start());
+                    current = next;
+                }
+                None => break,
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn section(heading: &str) -> usize {
+    SECTIONS.iter().position(|s| *s == heading).unwrap()
+}
+
+/// Split `@tag rest` (or `\tag rest`, `@param[in] rest`) at the start of a line.
+fn block_tag(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix('@').or_else(|| line.strip_prefix('\\'))?;
+    if let Some(rest) = rest.strip_prefix("f[") {
+        return Some(("f[", rest.trim()));
+    }
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphabetic() && c != '{' && c != '}')
+        .unwrap_or(rest.len());
+    if end == 0 {
+        return None;
+    }
+    let (tag, rest) = rest.split_at(end);
+    // direction of a parameter, e.g. `@param[out]`
+    let rest = match rest.strip_prefix('[') {
+        Some(rest) => rest.split_once(']').map_or(rest, |(_, rest)| rest),
+        None => rest,
+    };
+    // `@note: ...`
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    Some((tag, rest.trim()))
+}
+
+/// Drop leading, trailing and repeated empty lines.
+fn tidy(lines: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for line in lines {
+        if line.is_empty() && out.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        out.push(line);
+    }
+    while out.last().is_some_and(|l| l.is_empty()) {
+        out.pop();
+    }
+    out
+}
+
+/// `@see SCIPevalExpr to evaluate ...` links the referenced item.
+fn see_also(rest: &str) -> String {
+    let (first, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let name = first.trim_end_matches([',', '.']);
+    if is_identifier(name.trim_end_matches("()")) {
+        let link = reference(name, None);
+        let tail = inline(tail.trim());
+        if tail.is_empty() {
+            link
+        } else {
+            format!("{link} {tail}")
+        }
+    } else {
+        inline(rest)
+    }
+}
+
+fn is_identifier(word: &str) -> bool {
+    !word.is_empty()
+        && !word.starts_with(|c: char| c.is_ascii_digit())
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Render a reference to `name` (optionally labelled `text`) as an intra-doc link if it names
+/// an item of the bindings, and as code otherwise.
+fn reference(name: &str, text: Option<&str>) -> String {
+    let label = text.unwrap_or(name);
+    let function = name.strip_suffix("()").unwrap_or(name);
+    if !is_identifier(function) || function.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return format!("`{label}`");
+    }
+    // bindgen prefixes the enumerators with the name of their enum
+    let target = if name.starts_with("SCIP_STAGE_") {
+        Some(format!("SCIP_Stage_{name}"))
+    } else if RETCODES.contains(&name) {
+        Some(format!("SCIP_Retcode_{name}"))
+    } else {
+        None
+    };
+    match (target, text) {
+        (Some(target), _) => format!("[`{label}`]({target})"),
+        (None, Some(text)) => format!("[`{text}`]({function})"),
+        (None, None) => format!("[`{label}`]"),
+    }
+}
+
+/// Translate the inline commands of a line of text.
+fn inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        // leave existing code spans alone
+        if c == '`' {
+            let end = rest[1..].find('`').map_or(rest.len(), |e| e + 2);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        if c == '@' || c == '\\' {
+            if let Some(math) = rest[1..].strip_prefix("f$") {
+                let end = math.find("@f$").or_else(|| math.find("\\f$"));
+                let (formula, tail) = match end {
+                    Some(end) => (&math[..end], &math[end + 3..]),
+                    None => (math, ""),
+                };
+                out.push_str(&format!("`{}`", formula.trim()));
+                rest = tail;
+                continue;
+            }
+
+            let command_len = rest[1..]
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len() - 1);
+            let command = &rest[1..1 + command_len];
+            let after = &rest[1 + command_len..];
+            let is_word_command = matches!(command, "p" | "a" | "c" | "e" | "em" | "b" | "ref");
+            if is_word_command && after.starts_with(' ') {
+                let after = after.trim_start();
+                let word_len = after.find(char::is_whitespace).unwrap_or(after.len());
+                let word = &after[..word_len];
+                // keep trailing punctuation outside of the markup
+                let word_end = word
+                    .trim_end_matches(|c: char| {
+                        matches!(c, '.' | ',' | ';' | ':' | ')' | '!' | '?')
+                    })
+                    .len();
+                let (word, punctuation) = word.split_at(word_end.max(1).min(word.len()));
+                let mut tail = &after[word_len..];
+
+                let rendered = match command {
+                    "ref" => {
+                        // `\ref SCIP_Stage "SCIP_STAGE"` labels the reference
+                        let label = tail
+                            .strip_prefix(" \"")
+                            .and_then(|t| t.split_once('"'))
+                            .filter(|_| punctuation.is_empty());
+                        match label {
+                            Some((label, after_label)) => {
+                                tail = after_label;
+                                reference(word, Some(label))
+                            }
+                            None => reference(word, None),
+                        }
+                    }
+                    "b" => format!("**{word}**"),
+                    "e" | "em" => format!("*{word}*"),
+                    _ => format!("`{word}`"),
+                };
+                out.push_str(&rendered);
+                out.push_str(punctuation);
+                rest = tail;
+                continue;
+            }
+        }
+
+        // `SCIPcreateVar()` refers to another function of the API
+        if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let preceded_by_word = out.ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+            if !preceded_by_word && word.starts_with("SCIP") && rest[len..].starts_with("()") {
+                out.push_str(&format!("[`{word}()`]"));
+                rest = &rest[len + 2..];
+            } else {
+                out.push_str(word);
+                rest = &rest[len..];
+            }
+            continue;
+        }
+
+        // a plain `[i]` would be taken for an intra-doc link
+        if c == '[' || c == ']' {
+            out.push('\\');
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// Collect the `/**< ... */` documentation of the parameters of every function declared in the
+/// headers below `<include_dir>/scip`, keyed by function name.
+pub fn scan_param_docs(include_dir: &Path) -> Result<ParamDocs, Box<dyn Error>> {
+    let mut docs = HashMap::new();
+    let mut dirs = vec![include_dir.join("scip")];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "h") {
+                let contents = String::from_utf8_lossy(&fs::read(&path)?).into_owned();
+                scan_header(&contents, &mut docs);
+            }
+        }
+    }
+    Ok(docs)
+}
+
+/// Find declarations of the form
+/// ```c
+/// SCIP_RETCODE SCIPcreateVar(
+///    SCIP*                 scip,               /**< SCIP data structure */
+///    ...
+///    );
+/// ```
+fn scan_header(contents: &str, docs: &mut ParamDocs) {
+    let mut lines = contents.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(open) = line.trim_end().strip_suffix('(') else {
+            continue;
+        };
+        let name_start = open
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |p| p + 1);
+        let name = &open[name_start..];
+        if !is_identifier(name) || name_start == 0 || line.starts_with('#') {
+            continue;
+        }
+
+        let mut params = Vec::new();
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim();
+            if trimmed.starts_with(')') {
+                break;
+            }
+            let Some((decl, doc)) = trimmed.split_once("/**<") else {
+                continue;
+            };
+            let mut doc = doc.to_string();
+            while !doc.contains("*/") {
+                match lines.next() {
+                    Some(next) => {
+                        let next = next.trim();
+                        // continuation lines are usually aligned with a leading `*`
+                        let next = match next.strip_prefix('*') {
+                            Some(rest) if !rest.starts_with('/') => rest,
+                            _ => next,
+                        };
+                        doc.push(' ');
+                        doc.push_str(next);
+                    }
+                    None => break,
+                }
+            }
+            let doc = doc.split("*/").next().unwrap_or_default().trim();
+            let decl = decl.trim().trim_end_matches(',').trim_end();
+            // the parameter name is the last identifier, also in `SCIP_DECL_X((*name))` and
+            // `name[]`
+            let decl = decl.trim_end_matches([')', ']', '[']);
+            let param_start = decl
+                .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .map_or(0, |p| p + 1);
+            let param = &decl[param_start..];
+            if is_identifier(param) && param_start > 0 {
+                params.push((
+                    param.to_string(),
+                    doc.split_whitespace().collect::<Vec<_>>().join(" "),
+                ));
+            }
+        }
+        if !params.is_empty() {
+            docs.entry(name.to_string()).or_insert(params);
+        }
+    }
+}
+
+/// Add an `# Arguments` section to the documentation of every function in the generated
+/// `bindings` for which `docs` has parameter documentation.
+pub fn add_argument_docs(bindings: &str, docs: &ParamDocs) -> String {
+    let mut out: Vec<String> = Vec::new();
+    for line in bindings.lines() {
+        let trimmed = line.trim_start();
+        let name = trimmed
+            .strip_prefix("pub fn ")
+            .and_then(|rest| rest.split('(').next());
+        let params = name.and_then(|name| docs.get(name)).filter(|p| {
+            // a function already documenting its parameters keeps its own list
+            !out.last().is_some_and(|l| l.contains("# Arguments")) && !p.is_empty()
+        });
+        if let Some(params) = params {
+            let mut arguments = String::from(" # Arguments\n\n");
+            let list: Vec<String> = params
+                .iter()
+                .map(|(param, doc)| format!(" * `{}` - {}", rust_param_name(param), inline(doc)))
+                .collect();
+            arguments.push_str(&list.join("\n"));
+
+            let indent = &line[..line.len() - trimmed.len()];
+            let previous = out.last().map(|l| l.trim_start());
+            match previous
+                .and_then(|l| l.strip_prefix("#[doc = \""))
+                .and_then(|l| l.strip_suffix("\"]"))
+            {
+                Some(doc) => {
+                    // keep the arguments in front of the other sections
+                    let doc = match doc.find("\\n\\n # ") {
+                        Some(pos) => {
+                            format!("{}\\n\\n{}{}", &doc[..pos], escape(&arguments), &doc[pos..])
+                        }
+                        None => format!("{}\\n\\n{}", doc, escape(&arguments)),
+                    };
+                    *out.last_mut().unwrap() = format!("{indent}#[doc = \"{doc}\"]");
+                }
+                None => out.push(format!("{indent}#[doc = \"{}\"]", escape(&arguments))),
+            }
+        }
+        out.push(line.to_string());
+    }
+    let mut result = out.join("\n");
+    if bindings.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// bindgen appends an underscore to parameter names that are Rust keywords.
+fn rust_param_name(name: &str) -> String {
+    const KEYWORDS: [&str; 17] = [
+        "as", "box", "crate", "dyn", "fn", "impl", "in", "loop", "match", "mod", "move", "ref",
+        "self", "super", "type", "use", "where",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Escape `text` the way a string literal in the generated bindings is escaped.
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\'' => c.to_string(),
+            _ => c.escape_debug().to_string(),
+        })
+        .collect()
+}
@@ -1,8 +1,15 @@
-use std::ffi::CString;
-use scip_sys::*;
-use std::mem::MaybeUninit;
+// With `runtime-load`, SCIP's functions are methods of `ScipLibrary`, see the README
+#[cfg(feature = "runtime-load")]
+fn main() {
+    eprintln!("this example calls the linked libscip, run it without the `runtime-load` feature");
+}
 
+#[cfg(not(feature = "runtime-load"))]
 fn main() {
+    use scip_sys::*;
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+
     let mut scip_ptr = MaybeUninit::uninit();
     unsafe { SCIPcreate(scip_ptr.as_mut_ptr()) };
     let mut scip_ptr = unsafe { scip_ptr.assume_init() };
@@ -0,0 +1,290 @@
+//! Cargo features selecting which parts of the SCIP API end up in the bindings.
+//!
+//! Every header belongs to a group: the constraint handlers (`cons-<name>`, all of them with
+//! `cons`), the LP interface (`lpi`), expressions and NLP (`nlp`), Benders' decomposition
+//! (`benders`), exact solving (`exact`), reoptimization (`reopt`) and the dynamic arrays and graph
+//! algorithms (`datastructures`). Everything else, including the `pub_misc*.h` helpers, is core
+//! and always available. `full`, part of the default features, enables all groups.
+//!
+//! The functions and constants of a disabled group are removed from `bindings.rs` before it is
+//! compiled. Types are always kept, since the signatures of core functions refer to them.
+
+use crate::header_modules::HeaderMap;
+use std::env;
+
+/// The constraint handlers that have a `cons-<name>` feature.
+pub const CONSTRAINT_HANDLERS: [&str; 31] = [
+    "and",
+    "benders",
+    "benderslp",
+    "bounddisjunction",
+    "cardinality",
+    "components",
+    "conjunction",
+    "countsols",
+    "cumulative",
+    "disjunction",
+    "exactlinear",
+    "exactsol",
+    "fixedvar",
+    "indicator",
+    "integral",
+    "knapsack",
+    "linear",
+    "linking",
+    "logicor",
+    "nonlinear",
+    "or",
+    "orbisack",
+    "orbitope",
+    "pseudoboolean",
+    "setppc",
+    "sos1",
+    "sos2",
+    "superindicator",
+    "symresack",
+    "varbound",
+    "xor",
+];
+
+/// The feature a header belongs to, `None` for core headers.
+pub fn feature(header: &str) -> Option<String> {
+    let (directory, file) = header.rsplit_once('/').unwrap_or(("", header));
+    let stem = file.strip_suffix(".h").unwrap_or(file);
+
+    match directory {
+        "lpi" if stem.contains("exact") => return Some("exact".to_string()),
+        "lpi" => return Some("lpi".to_string()),
+        "nlpi" => return Some("nlp".to_string()),
+        "tclique" | "dijkstra" => return Some("datastructures".to_string()),
+        "scip" => {}
+        _ => return None,
+    }
+
+    if let Some(handler) = stem.strip_prefix("cons_") {
+        let handler = handler.split('_').next().unwrap_or(handler);
+        if !CONSTRAINT_HANDLERS.contains(&handler) {
+            // handlers added after this list only come with `cons`
+            return Some("cons".to_string());
+        }
+        return Some(format!("cons-{handler}"));
+    }
+    // `pub_misc_sort.h`, `scip_benders.h`, `expr_sum.h`, ... are grouped by their first word
+    let mut words = stem.split('_');
+    let mut kind = words.next().unwrap_or(stem);
+    if matches!(kind, "pub" | "scip" | "type" | "struct") {
+        kind = words.next().unwrap_or(kind);
+    }
+    let feature = match kind {
+        "expr" | "exprinterpret" | "nlhdlr" | "nlp" | "nlpi" | "nlpioracle" => "nlp",
+        "benders" | "benderscut" => "benders",
+        "reopt" => "reopt",
+        // `pub_misc*.h` (numerics, sorting, hash maps, ...) is used everywhere and stays core
+        "datastructures" | "dbldblarith" => "datastructures",
+        "exact" | "lpexact" | "rational" | "certificate" => "exact",
+        _ => return None,
+    };
+    Some(feature.to_string())
+}
+
+/// Whether the cargo `feature` is enabled for this build.
+pub fn is_enabled(feature: &str) -> bool {
+    let enabled = |feature: &str| {
+        env::var_os(format!(
+            "CARGO_FEATURE_{}",
+            feature.to_uppercase().replace('-', "_")
+        ))
+        .is_some()
+    };
+    enabled(feature) || (feature.starts_with("cons-") && enabled("cons")) || enabled("full")
+}
+
+/// Remove the functions and constants of the disabled groups from `bindings`. Returns the new
+/// bindings and the number of removed items.
+pub fn strip_disabled(bindings: &str, map: &HeaderMap) -> (String, usize) {
+    let disabled = |name: &str| {
+        map.get(name)
+            .and_then(|header| feature(header))
+            .is_some_and(|feature| !is_enabled(&feature))
+    };
+
+    let lines: Vec<&str> = bindings.lines().collect();
+    let mut out: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut removed = 0;
+    for item in split_items(&lines) {
+        let code = item.iter().find(|l| !l.trim_start().starts_with("#["));
+        match code.map(|l| l.trim_start()) {
+            Some(l)
+                if l.starts_with("unsafe extern \"C\" {") || l.starts_with("extern \"C\" {") =>
+            {
+                // keep the opening and closing lines around the remaining declarations
+                let open = item.iter().position(|l| Some(l) == code).unwrap();
+                let inner = &item[open + 1..item.len() - 1];
+                let mut kept = Vec::new();
+                for declaration in split_items(inner) {
+                    match item_name(&declaration) {
+                        Some(name) if disabled(name) => removed += 1,
+                        _ => kept.extend(declaration),
+                    }
+                }
+                if !kept.is_empty() {
+                    out.extend(&item[..=open]);
+                    out.extend(kept);
+                    out.push(item[item.len() - 1]);
+                }
+            }
+            Some(l) if l.starts_with("pub const ") => match item_name(&item) {
+                Some(name) if disabled(name) => removed += 1,
+                _ => out.extend(item),
+            },
+            _ => out.extend(item),
+        }
+    }
+
+    let mut stripped = out.join("\n");
+    if bindings.ends_with('\n') {
+        stripped.push('\n');
+    }
+    (stripped, removed)
+}
+
+/// Split lines of Rust code into items, each with its attributes. Blank lines and comments are
+/// items of their own. An item ends with the line that closes all its brackets and ends in `;` or
+/// `}`; brackets in string and character literals and in comments do not count.
+pub fn split_items<'a>(lines: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut items = Vec::new();
+    let mut current = Vec::new();
+    let mut scanner = Scanner::default();
+    let mut in_item = false;
+    for &line in lines {
+        current.push(line);
+        let last = scanner.line(line);
+        if !in_item && last.is_none() {
+            // a comment spanning several lines is a single item
+            if scanner.state == State::Code {
+                items.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        in_item = true;
+        if scanner.depth <= 0 && scanner.state == State::Code && matches!(last, Some(';' | '}')) {
+            items.push(std::mem::take(&mut current));
+            scanner.depth = 0;
+            in_item = false;
+        }
+    }
+    if !current.is_empty() {
+        items.push(current);
+    }
+    items
+}
+
+/// What the [`Scanner`] is in at the end of a line.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum State {
+    #[default]
+    Code,
+    /// A string literal, raw with the given number of `#` if it is `Some`
+    Str(Option<usize>),
+    /// Block comments, which nest in Rust
+    Comment(usize),
+}
+
+/// Tracks the bracket depth of Rust code line by line.
+#[derive(Debug, Default)]
+struct Scanner {
+    state: State,
+    depth: i32,
+}
+
+impl Scanner {
+    /// Scan `line` and return its last character of code, if it has any.
+    fn line(&mut self, line: &str) -> Option<char> {
+        let mut last = None;
+        let mut chars = line.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let in_comment = matches!(self.state, State::Comment(_));
+            match self.state {
+                State::Str(None) => match c {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => self.state = State::Code,
+                    _ => {}
+                },
+                State::Str(Some(hashes)) => {
+                    if c == '"'
+                        && line[i + 1..].bytes().take_while(|&b| b == b'#').count() >= hashes
+                    {
+                        for _ in 0..hashes {
+                            chars.next();
+                        }
+                        self.state = State::Code;
+                    }
+                }
+                State::Comment(depth) => match (c, chars.peek()) {
+                    ('*', Some((_, '/'))) => {
+                        chars.next();
+                        self.state = match depth {
+                            1 => State::Code,
+                            _ => State::Comment(depth - 1),
+                        };
+                    }
+                    ('/', Some((_, '*'))) => {
+                        chars.next();
+                        self.state = State::Comment(depth + 1);
+                    }
+                    _ => {}
+                },
+                State::Code => match (c, chars.peek()) {
+                    ('/', Some((_, '/'))) => break,
+                    ('/', Some((_, '*'))) => {
+                        chars.next();
+                        self.state = State::Comment(1);
+                    }
+                    ('"', _) => {
+                        // `r"`, `br#"`, ...: the `r` and the `#` before the quote
+                        let before = line[..i].trim_end_matches('#');
+                        let raw = before.strip_suffix('r').is_some_and(|prefix| {
+                            let prefix = prefix.strip_suffix('b').unwrap_or(prefix);
+                            !prefix.ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                        });
+                        self.state = State::Str(raw.then_some(i - before.len()));
+                    }
+                    // a character literal, unless it is a lifetime like `'a`
+                    ('\'', Some((_, '\\'))) => {
+                        chars.next();
+                        chars.next();
+                        while chars.next().is_some_and(|(_, c)| c != '\'') {}
+                    }
+                    ('\'', _) if line[i + 1..].chars().nth(1) == Some('\'') => {
+                        chars.nth(1);
+                    }
+                    ('{' | '(' | '[', _) => self.depth += 1,
+                    ('}' | ')' | ']', _) => self.depth -= 1,
+                    _ => {}
+                },
+            }
+            if !c.is_whitespace() && !in_comment && !matches!(self.state, State::Comment(_)) {
+                last = Some(c);
+            }
+        }
+        last
+    }
+}
+
+/// The name of a `pub fn`, `pub static` or `pub const` item.
+pub fn item_name<'a>(item: &[&'a str]) -> Option<&'a str> {
+    let code = item
+        .iter()
+        .find(|l| !l.trim_start().starts_with("#["))?
+        .trim_start();
+    let rest = code.strip_prefix("pub ")?;
+    let rest = ["fn ", "static mut ", "static ", "const "]
+        .iter()
+        .find_map(|kind| rest.strip_prefix(kind))?;
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    Some(&rest[..end])
+}
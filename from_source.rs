@@ -1,5 +1,7 @@
 #[cfg(feature = "from-source")]
-use crate::download::download_and_extract_tar_gz;
+use crate::error::BuildError;
+use crate::error::Result;
+use scip_sys_build::Installation;
 #[cfg(feature = "from-source")]
 use std::env;
 use std::path::PathBuf;
@@ -15,60 +17,33 @@ pub fn is_from_source_feature_enabled() -> bool {
 }
 
 #[cfg(not(feature = "from-source"))]
-pub fn download_scip_source() -> PathBuf {
+pub fn download_scip_source() -> Result<PathBuf> {
     unimplemented!("Cannot download SCIP source code without the `from-source` feature")
 }
 
+/// Download the SCIP sources pinned in `versions.toml` into `OUT_DIR`.
 #[cfg(feature = "from-source")]
-pub fn download_scip_source() -> PathBuf {
-    let scip_version = "10.0.2";
-    let url = format!(
-        "https://github.com/scipopt/scip/releases/download/v{scip_version}/scipoptsuite-{scip_version}.tgz"
-    );
-    let target = env::var("OUT_DIR").unwrap();
-    let target = std::path::Path::new(&target);
-    if target.join(format!("scipoptsuite-{scip_version}")).exists() {
-        println!("cargo:warning=SCIP was previously downloaded, skipping download");
-    } else {
-        download_and_extract_tar_gz(&url, target).expect("Failed to download SCIP");
-    }
-    target.join(format!("scipoptsuite-{scip_version}"))
+pub fn download_scip_source() -> Result<PathBuf> {
+    let manifest = crate::manifest::read()?;
+    let url = manifest.expand("source.url", "")?;
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let directory = manifest.expand("source.directory", "")?;
+    let sha256 = manifest.required_sha256("source")?;
+    scip_sys_build::from_source::download(&url, Some(sha256), &out_dir, &directory)
+        .map_err(disable_from_source)
 }
 
 #[cfg(feature = "from-source")]
-pub fn compile_scip(source_path: PathBuf) -> PathBuf {
-    let out_dir = env::var("OUT_DIR").unwrap();
-    let out_dir = std::path::Path::new(&out_dir);
-    let lib_path = out_dir.join("lib");
-    if lib_path.exists() {
-        println!("cargo:warning=SCIP was previously compiled, skipping compilation");
-        return out_dir.to_path_buf();
-    }
-
-    use cmake::Config;
-    let dst = Config::new(source_path)
-        .define("IPOPT", "OFF")
-        .define("ZIMPL", "OFF")
-        .define("GMP", "OFF")
-        .define("READLINE", "OFF")
-        .define("BOOST", "OFF")
-        .define("AUTOBUILD", "OFF")
-        .define("PAPILO", "OFF")
-        .define("SYM", "snauty")
-        .define("ZLIB", "OFF")
-        .define("SHARED", "OFF")
-        .define("GCG", "OFF")
-        .define("UG", "OFF")
-        .define("SANITIZE_ADDRESS", "OFF")
-        .define("SANITIZE_MEMORY", "OFF")
-        .define("SANITIZE_UNDEFINED", "OFF")
-        .define("SANITIZE_THREAD", "OFF")
-        .build();
-
-    dst
+pub fn compile_scip(source_path: PathBuf) -> Result<Installation> {
+    scip_sys_build::from_source::build(&source_path, |_| {}).map_err(disable_from_source)
 }
 
 #[cfg(not(feature = "from-source"))]
-pub fn compile_scip(_source_path: PathBuf) -> PathBuf {
+pub fn compile_scip(_source_path: PathBuf) -> Result<Installation> {
     unimplemented!("Cannot compile SCIP without the `from-source` feature")
 }
+
+#[cfg(feature = "from-source")]
+fn disable_from_source(error: BuildError) -> BuildError {
+    error.remedy("Disable the `from-source` feature and point SCIPOPTDIR to a SCIP installation")
+}
@@ -0,0 +1,690 @@
+//! Per-header modules on top of the flat bindings.
+//!
+//! bindgen puts every item into one namespace. To show where an item comes from, the headers
+//! reachable from `scip/scip.h` and `scip/scipdefplugins.h` are scanned for the names they
+//! declare, and `modules.rs` re-exports each item of the bindings in a module named after its
+//! header:
+//!
+//! - `scip/scip_var.h` becomes `scip_sys::scip_var`,
+//! - plugin headers are grouped by kind, so `scip/cons_linear.h` becomes `scip_sys::cons::linear`,
+//! - headers outside of `scip/` keep their directory, e.g. `scip_sys::blockmemshell::memory`.
+//!
+//! The scan is plain text processing and does not need bindgen or libclang. The resulting map is
+//! also written to `src/bindings/headers.txt` when the prebuilt bindings are regenerated, so builds
+//! without headers (docs.rs) can use it.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The headers bindgen is pointed at, relative to the include directory.
+pub const ROOT_HEADERS: [&str; 3] = ["scip/scip.h", "scip/scipdefplugins.h", "scip/def.h"];
+
+/// The include directories of SCIP's own headers.
+#[cfg(any(feature = "bindgen", feature = "runtime-load"))]
+pub const SCIP_DIRECTORIES: [&str; 10] = [
+    "scip",
+    "lpi",
+    "lpiexact",
+    "nlpi",
+    "blockmemshell",
+    "tclique",
+    "dijkstra",
+    "symmetry",
+    "tinycthread",
+    "xml",
+];
+
+/// Plugin kinds whose headers (`<kind>_<name>.h`) are grouped into a `<kind>` module.
+const PLUGIN_KINDS: [&str; 21] = [
+    "benders",
+    "benderscut",
+    "branch",
+    "compr",
+    "concsolver",
+    "cons",
+    "cutsel",
+    "dialog",
+    "disp",
+    "event",
+    "expr",
+    "heur",
+    "iisfinder",
+    "nlhdlr",
+    "nlpi",
+    "nodesel",
+    "presol",
+    "pricer",
+    "prop",
+    "reader",
+    "sepa",
+];
+
+/// Item name to the header declaring it (relative to the include directory, with `/`).
+pub type HeaderMap = BTreeMap<String, String>;
+
+/// Scan all headers reachable from [`ROOT_HEADERS`] below `include_dir`.
+pub fn scan_headers(include_dir: &Path) -> Result<HeaderMap, Box<dyn Error>> {
+    let mut map = HeaderMap::new();
+    // names defined with a body take precedence over forward declarations
+    let mut defined = HashSet::new();
+
+    let mut queue: Vec<String> = ROOT_HEADERS.iter().map(|h| h.to_string()).collect();
+    let mut seen: HashSet<String> = queue.iter().cloned().collect();
+    let mut next = 0;
+    while next < queue.len() {
+        let header = queue[next].clone();
+        next += 1;
+        let Ok(contents) = fs::read(include_dir.join(&header)) else {
+            continue;
+        };
+        let contents = String::from_utf8_lossy(&contents);
+        let directory = header.rsplit_once('/').map_or("", |(dir, _)| dir);
+
+        for included in includes(&contents) {
+            // quoted includes may be relative to the including header
+            let candidates = [format!("{directory}/{included}"), included.clone()];
+            if let Some(found) = candidates
+                .into_iter()
+                .find(|c| include_dir.join(c).is_file())
+            {
+                let found = normalize(&found);
+                if seen.insert(found.clone()) {
+                    queue.push(found);
+                }
+            }
+        }
+
+        for (name, is_definition) in declarations(&strip_comments(&contents)) {
+            if is_definition && defined.insert(name.clone()) {
+                map.insert(name, header.clone());
+            } else if !defined.contains(&name) {
+                map.entry(name).or_insert_with(|| header.clone());
+            }
+        }
+    }
+    Ok(map)
+}
+
+/// Resolve `.` and `..` in a relative header path.
+fn normalize(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// The targets of the `#include` directives in `contents`.
+fn includes(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix('#')?.trim_start();
+            let rest = rest.strip_prefix("include")?.trim_start();
+            let (open, close) = match rest.chars().next()? {
+                '"' => ('"', '"'),
+                '<' => ('<', '>'),
+                _ => return None,
+            };
+            let rest = rest.strip_prefix(open)?;
+            Some(rest.split(close).next()?.to_string())
+        })
+        .collect()
+}
+
+/// Replace comments by spaces, keeping the line structure.
+fn strip_comments(contents: &str) -> String {
+    let mut out = String::with_capacity(contents.len());
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('/', Some('*')) => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                out.push(' ');
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('"', _) => {
+                // string literals can contain comment markers
+                out.push(c);
+                let mut escaped = false;
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if c == '"' && !escaped {
+                        break;
+                    }
+                    escaped = c == '\\' && !escaped;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// The names declared in a comment-free header, each with whether it is a definition.
+///
+/// This is not a C parser; it picks up macros, function declarations, typedefs, struct, union and
+/// enum tags and enumerators (also in the `<Enum>_<ENUMERATOR>` form bindgen uses for constants).
+/// Names that are not items of the bindings are dropped later.
+fn declarations(contents: &str) -> Vec<(String, bool)> {
+    let mut names = Vec::new();
+    let mut code = String::new();
+
+    let mut lines = contents.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let Some(directive) = trimmed.strip_prefix('#') else {
+            code.push_str(line);
+            code.push('\n');
+            continue;
+        };
+        // skip continuation lines of the directive
+        let mut last = line;
+        while last.trim_end().ends_with('\\') {
+            match lines.next() {
+                Some(next) => last = next,
+                None => break,
+            }
+        }
+        if let Some(rest) = directive.trim_start().strip_prefix("define") {
+            let rest = rest.trim_start();
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            if end > 0 {
+                names.push((rest[..end].to_string(), true));
+            }
+        }
+    }
+
+    let tokens = tokenize(&code);
+    let mut depth = 0usize;
+    let mut statement_start = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        match token {
+            "{" if depth == 0 && i > 0 && tokens[i - 1] == ")" => {
+                // skip the body of a static inline function
+                let mut nested = 1;
+                while nested > 0 && i + 1 < tokens.len() {
+                    i += 1;
+                    match tokens[i] {
+                        "{" => nested += 1,
+                        "}" => nested -= 1,
+                        _ => {}
+                    }
+                }
+                statement_start = i + 1;
+            }
+            // `extern "C" {` of the C++ guards does not open a scope
+            "{" if i > 0 && tokens[i - 1] == "\"C\"" => {}
+            "{" => depth += 1,
+            "}" => depth = depth.saturating_sub(1),
+            ";" if depth == 0 => {
+                typedef_name(&tokens[statement_start..i], &mut names);
+                statement_start = i + 1;
+            }
+            "struct" | "union" | "enum" if depth == 0 => {
+                if let Some(&name) = tokens.get(i + 1).filter(|t| is_identifier(t)) {
+                    let is_definition = tokens.get(i + 2) == Some(&"{");
+                    names.push((name.to_string(), is_definition));
+                }
+                if token == "enum" {
+                    enumerators(&tokens[i..], &mut names);
+                }
+            }
+            "(" if depth == 0 && i > 0 => {
+                let name = tokens[i - 1];
+                let before = i.checked_sub(2).map(|p| tokens[p]);
+                let declares = before.is_some_and(|t| is_identifier(t) || t == "*")
+                    && !matches!(before, Some("return" | "sizeof" | "typedef"));
+                if is_identifier(name) && declares && !is_keyword(name) {
+                    names.push((name.to_string(), false));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    names
+}
+
+/// `typedef ... NAME;` and `typedef RET (*NAME)(...);`
+fn typedef_name(statement: &[&str], names: &mut Vec<(String, bool)>) {
+    if statement.first() != Some(&"typedef") {
+        return;
+    }
+    let function_pointer = statement
+        .windows(3)
+        .find(|w| w[0] == "(" && w[1] == "*" && is_identifier(w[2]))
+        .map(|w| w[2]);
+    let name = function_pointer.or_else(|| {
+        // the name follows the closing brace of an inline definition, or is the last identifier
+        statement
+            .iter()
+            .rev()
+            .find(|t| is_identifier(t) && !is_keyword(t))
+            .copied()
+    });
+    if let Some(name) = name {
+        names.push((name.to_string(), true));
+        // anonymous `typedef enum { ... } NAME;` prefixes its enumerators with NAME
+        if statement.get(1) == Some(&"enum") && statement.get(2) == Some(&"{") {
+            let mut anonymous = Vec::new();
+            enumerators(&statement[1..], &mut anonymous);
+            names.extend(
+                anonymous
+                    .into_iter()
+                    .filter(|(n, _)| !n.contains("_enum_"))
+                    .map(|(n, d)| (format!("{name}_{n}"), d)),
+            );
+        }
+    }
+}
+
+/// The enumerators of the `enum [tag] { ... }` at the start of `tokens`, bare and prefixed with
+/// the tag.
+fn enumerators(tokens: &[&str], names: &mut Vec<(String, bool)>) {
+    let tag = tokens.get(1).filter(|t| is_identifier(t)).copied();
+    let Some(open) = tokens.iter().take(3).position(|t| *t == "{") else {
+        return;
+    };
+    let mut expect_name = true;
+    let mut depth = 0;
+    for token in &tokens[open + 1..] {
+        match *token {
+            "}" if depth == 0 => break,
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            "," if depth == 0 => expect_name = true,
+            name if expect_name && is_identifier(name) => {
+                names.push((name.to_string(), true));
+                if let Some(tag) = tag {
+                    names.push((format!("{tag}_{name}"), true));
+                }
+                expect_name = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Split C code into identifiers, numbers and single punctuation characters.
+fn tokenize(code: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut rest = code;
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_alphanumeric() || c == '_' {
+            rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len())
+        } else if c == '"' || c == '\'' {
+            rest[1..].find(c).map_or(rest.len(), |end| end + 2)
+        } else {
+            c.len_utf8()
+        };
+        if !c.is_whitespace() {
+            tokens.push(&rest[..len]);
+        }
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+fn is_identifier(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+}
+
+fn is_keyword(token: &str) -> bool {
+    matches!(
+        token,
+        "if" | "while"
+            | "for"
+            | "switch"
+            | "return"
+            | "sizeof"
+            | "typedef"
+            | "struct"
+            | "union"
+            | "enum"
+            | "const"
+            | "volatile"
+            | "static"
+            | "inline"
+            | "extern"
+            | "void"
+            | "char"
+            | "short"
+            | "int"
+            | "long"
+            | "float"
+            | "double"
+            | "signed"
+            | "unsigned"
+    )
+}
+
+/// Read a map written by [`write_header_map`].
+pub fn read_header_map(path: &Path) -> Result<HeaderMap, Box<dyn Error>> {
+    let mut map = HeaderMap::new();
+    for line in fs::read_to_string(path)?.lines() {
+        let Some((header, items)) = line.split_once(':') else {
+            continue;
+        };
+        for item in items.split_whitespace() {
+            map.insert(item.to_string(), header.trim().to_string());
+        }
+    }
+    Ok(map)
+}
+
+/// Write `map` as one `header: item item ...` line per header, keeping only the items of
+/// `bindings`.
+#[cfg(all(feature = "bundled", feature = "bindgen"))]
+pub fn write_header_map(
+    map: &HeaderMap,
+    bindings: &str,
+    path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let mut contents =
+        String::from("# Header of every item of the prebuilt bindings, generated by build.rs\n");
+    for (header, items) in by_header(map, &binding_items(bindings)) {
+        contents.push_str(&format!("{header}: {}\n", items.join(" ")));
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Group the items present in the bindings by header.
+fn by_header<'a>(map: &'a HeaderMap, items: &BTreeSet<String>) -> BTreeMap<&'a str, Vec<&'a str>> {
+    let mut headers: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (item, header) in map {
+        if items.contains(item) {
+            headers.entry(header).or_default().push(item);
+        }
+    }
+    headers
+}
+
+/// The names of the top-level items of the generated bindings.
+pub fn binding_items(bindings: &str) -> BTreeSet<String> {
+    const KINDS: [&str; 8] = [
+        "fn ",
+        "const ",
+        "static mut ",
+        "static ",
+        "type ",
+        "struct ",
+        "union ",
+        "enum ",
+    ];
+    let mut items = BTreeSet::new();
+    // brace depth inside an `impl` block, `Some(0)` while its header spans several lines
+    let mut in_impl: Option<usize> = None;
+    for line in bindings.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("#[") || trimmed.starts_with("//") {
+            continue;
+        }
+        if trimmed.starts_with("impl") {
+            in_impl = Some(0);
+        }
+        if let Some(depth) = in_impl {
+            // methods of bindgen's bitfield and `Default` impls are not items of the crate
+            let opened = line.matches('{').count();
+            let depth = (depth + opened).saturating_sub(line.matches('}').count());
+            in_impl = (depth > 0 || (opened == 0 && !trimmed.ends_with('}'))).then_some(depth);
+            continue;
+        }
+        let Some(rest) = trimmed.strip_prefix("pub ") else {
+            continue;
+        };
+        let rest = rest.strip_prefix("unsafe ").unwrap_or(rest);
+        // typedefs of enums: `pub use self::SCIP_Stage as SCIP_STAGE;`
+        if let Some((_, alias)) = rest.strip_prefix("use ").and_then(|r| r.split_once(" as ")) {
+            items.insert(alias.trim_end_matches(';').to_string());
+            continue;
+        }
+        if let Some(rest) = KINDS.iter().find_map(|kind| rest.strip_prefix(kind)) {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            if end > 0 {
+                items.insert(rest[..end].to_string());
+            }
+        }
+    }
+    items
+}
+
+/// Turn the intra-doc links of the translated comments (``[`X`]``, ``[`X()`]`` and
+/// ``[`label`](X)``, see `doxygen.rs`) whose target is not an item of `bindings` into plain code.
+/// These are references to macros, to items of disabled features and, with `runtime-load`, to
+/// the functions that became methods of `ScipLibrary`. Returns the bindings and the number of
+/// links removed.
+pub fn unlink_missing(bindings: &str) -> (String, usize) {
+    let items = binding_items(bindings);
+    let mut unlinked = 0;
+    let mut out = String::with_capacity(bindings.len());
+    for line in bindings.split_inclusive('\n') {
+        if !line.trim_start().starts_with("#[doc = \"") {
+            out.push_str(line);
+            continue;
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find("[`") {
+            let (before, link) = rest.split_at(start);
+            out.push_str(before);
+            let Some(end) = link[2..].find("`]").map(|end| end + 4) else {
+                rest = link;
+                break;
+            };
+            let label = &link[2..end - 2];
+            let target = link[end..]
+                .strip_prefix('(')
+                .and_then(|after| after.split_once(')'))
+                .map(|(target, _)| target);
+            let len = target.map_or(end, |target| end + target.len() + 2);
+            let name = target.unwrap_or(label.trim_end_matches("()"));
+            // an escaped `\[` is no link (the backslash is escaped in the string literal)
+            if before.ends_with('\\') || items.contains(name) {
+                out.push_str(&link[..len]);
+            } else {
+                out.push_str(&format!("`{label}`"));
+                unlinked += 1;
+            }
+            rest = &link[len..];
+        }
+        out.push_str(rest);
+    }
+    (out, unlinked)
+}
+
+/// Escape the brackets in the docs of `bindings` that rustdoc would take for intra-doc links,
+/// e.g. `coefs[0]` or `[lb,ub]`, as `doxygen.rs` does for the comments it translates. Bindings
+/// committed before it did so still have them bare. The links (``[`X`]``, ``[`label`](X)``),
+/// code spans and brackets that are already escaped are left alone.
+pub fn escape_brackets(bindings: &str) -> String {
+    let mut out = String::with_capacity(bindings.len());
+    for line in bindings.split_inclusive('\n') {
+        if !line.trim_start().starts_with("#[doc = \"") {
+            out.push_str(line);
+            continue;
+        }
+        let start = line.find("#[doc = \"").unwrap() + "#[doc = \"".len();
+        let end = line
+            .rfind("\"]")
+            .filter(|&end| end >= start)
+            .unwrap_or(line.len());
+        out.push_str(&line[..start]);
+        let mut rest = &line[start..end];
+        while let Some(c) = rest.chars().next() {
+            // the backslash of `\[` is escaped in the string literal
+            let escaped = out.ends_with("\\\\");
+            let len = if escaped {
+                None
+            } else if let Some(link) = rest.strip_prefix("[`") {
+                // a link, with its target if it has one
+                link.find("`]").map(|end| {
+                    let end = end + 4;
+                    let target = rest[end..]
+                        .strip_prefix('(')
+                        .and_then(|after| after.find(')'));
+                    target.map_or(end, |target| end + target + 2)
+                })
+            } else if c == '`' {
+                rest[1..].find('`').map(|end| end + 2)
+            } else {
+                None
+            };
+            if let Some(len) = len {
+                out.push_str(&rest[..len]);
+                rest = &rest[len..];
+                continue;
+            }
+            if (c == '[' || c == ']') && !escaped {
+                out.push_str("\\\\");
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        out.push_str(&line[end..]);
+    }
+    out
+}
+
+/// The module path of a header, e.g. `["cons", "linear"]` for `scip/cons_linear.h`.
+fn module_path(header: &str) -> Vec<String> {
+    let stem = header.strip_suffix(".h").unwrap_or(header);
+    let mut parts: Vec<&str> = stem.split('/').collect();
+    if parts.len() > 1 && parts[0] == "scip" {
+        parts.remove(0);
+    }
+    // `lpi/lpi.h`
+    if parts.len() == 2 && parts[0] == parts[1] {
+        parts.pop();
+    }
+    let mut path: Vec<String> = Vec::new();
+    for part in &parts {
+        let kind = PLUGIN_KINDS
+            .iter()
+            .find(|kind| part.strip_prefix(*kind).is_some_and(|r| r.starts_with('_')));
+        match kind {
+            // only the plugins in `scip/` follow the naming scheme
+            Some(kind) if parts.len() == 1 => {
+                path.push(kind.to_string());
+                path.push(part[kind.len() + 1..].to_string());
+            }
+            _ => path.push(part.to_string()),
+        }
+    }
+    path.into_iter().map(|p| module_name(&p)).collect()
+}
+
+fn module_name(part: &str) -> String {
+    let mut name: String = part
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    const KEYWORDS: [&str; 12] = [
+        "crate", "fn", "impl", "in", "mod", "ref", "self", "super", "type", "use", "where",
+        "struct",
+    ];
+    if KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+#[derive(Default)]
+struct Module {
+    header: Option<String>,
+    items: Vec<String>,
+    children: BTreeMap<String, Module>,
+}
+
+/// Write `<out_path>/modules.rs` with a module per header re-exporting the items of `bindings`.
+pub fn write_modules(
+    map: &HeaderMap,
+    bindings: &str,
+    out_path: &Path,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let items = binding_items(bindings);
+    let mut root = Module::default();
+    for (header, header_items) in by_header(map, &items) {
+        let mut module = &mut root;
+        for part in module_path(header) {
+            // a module must not shadow an item of the bindings
+            let part = if items.contains(&part) {
+                format!("{part}_h")
+            } else {
+                part
+            };
+            module = module.children.entry(part).or_default();
+        }
+        module.header = Some(header.to_string());
+        module
+            .items
+            .extend(header_items.iter().map(|i| i.to_string()));
+    }
+
+    let mut contents = String::from("// generated by scip-sys\n");
+    emit(&root, "", &mut contents);
+    let path = out_path.join("modules.rs");
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+fn emit(module: &Module, indent: &str, out: &mut String) {
+    if !module.items.is_empty() {
+        out.push_str(&format!(
+            "{indent}pub use crate::{{{}}};\n",
+            module.items.join(", ")
+        ));
+    }
+    for (name, child) in &module.children {
+        let doc = match &child.header {
+            Some(header) => format!("Items declared in `{header}`."),
+            None => format!("`{name}` headers."),
+        };
+        out.push_str(&format!(
+            "{indent}#[doc = \"{doc}\"]\n{indent}pub mod {name} {{\n"
+        ));
+        emit(child, &format!("{indent}    "), out);
+        out.push_str(&format!("{indent}}}\n"));
+    }
+}
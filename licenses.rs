@@ -0,0 +1,334 @@
+//! The licenses and the SBOM of the SCIP the crate links, for whoever redistributes it.
+//!
+//! The license files of a downloaded archive (`bundled`) or of the source release (`from-source`)
+//! are copied into `<OUT_DIR>/licenses/`, published as `DEP_SCIP_LICENSES` and embedded for
+//! `build_info::licenses()`. Where they have none, a notice naming the SPDX license recorded in
+//! `versions.toml` stands in. `<OUT_DIR>/sbom.cdx.json`, published as `DEP_SCIP_SBOM` and
+//! `build_info::SBOM`, is a CycloneDX document listing the archive with its download URL and
+//! checksum, and the libraries in it with their versions and licenses.
+//!
+//! An installed SCIP (SCIPOPTDIR, conda, system) brings its own licenses; only its version goes
+//! into the SBOM.
+
+use crate::error::{BuildError, Result};
+use crate::paths;
+use scip_sys_build::manifest::Manifest;
+use scip_sys_build::report::json_string;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The libraries in the `bundled` archive, named as in the `[license]` section of
+/// `versions.toml`, with the key of their version there.
+#[cfg(feature = "bundled")]
+const BUNDLED: [(&str, Option<&str>); 6] = [
+    ("scip", Some("scip.version")),
+    ("soplex", Some("soplex.version")),
+    ("gcg", Some("gcg.version")),
+    ("ipopt", Some("ipopt.version")),
+    ("mumps", None),
+    ("libgfortran", None),
+];
+
+/// The libraries a `from-source` build compiles.
+#[cfg(not(feature = "bundled"))]
+const FROM_SOURCE: [(&str, Option<&str>); 2] = [
+    ("scip", Some("scip.version")),
+    ("soplex", Some("soplex.version")),
+];
+
+/// A library in the SBOM.
+struct Component {
+    name: String,
+    version: Option<String>,
+    license: Option<String>,
+}
+
+/// Where the SCIP of a build came from: a downloaded archive or an installation.
+pub struct Origin {
+    name: String,
+    version: Option<String>,
+    url: Option<String>,
+    sha256: Option<String>,
+    components: Vec<Component>,
+    /// The directory to copy license files from, and how deep to look in it
+    licenses: Option<(PathBuf, usize)>,
+}
+
+fn components(manifest: &Manifest, libraries: &[(&str, Option<&str>)]) -> Vec<Component> {
+    libraries
+        .iter()
+        .map(|&(name, version)| Component {
+            name: name.to_string(),
+            version: version.and_then(|key| manifest.get(key).ok().map(str::to_string)),
+            license: manifest.license(name).map(str::to_string),
+        })
+        .collect()
+}
+
+/// The SHA-256 of the archive extracted to `dir`: the one in `versions.toml`, or else the one
+/// recorded when it was downloaded.
+fn archive_sha256(recorded: Option<&str>, dir: &Path) -> Option<String> {
+    #[cfg(any(feature = "bundled", feature = "from-source"))]
+    let downloaded = scip_sys_build::download::archive_sha256(dir);
+    #[cfg(not(any(feature = "bundled", feature = "from-source")))]
+    let downloaded = {
+        let _ = dir;
+        None
+    };
+    recorded.map(str::to_string).or(downloaded)
+}
+
+/// The precompiled release for the platform `target`, extracted to `root`.
+#[cfg(feature = "bundled")]
+pub fn bundled(manifest: &Manifest, target: &str, root: &Path) -> Result<Origin> {
+    Ok(Origin {
+        name: "scipoptsuite-deploy".to_string(),
+        version: Some(manifest.get("deploy.version")?.to_string()),
+        url: Some(manifest.expand("deploy.url", target)?),
+        sha256: archive_sha256(manifest.sha256(target), root),
+        components: components(manifest, &BUNDLED),
+        // the archive is redistributed as a whole, so every license in it counts
+        licenses: Some((root.to_path_buf(), usize::MAX)),
+    })
+}
+
+/// The source release extracted to `source`, which a `from-source` build compiles.
+#[cfg(not(feature = "bundled"))]
+pub fn from_source(manifest: &Manifest, source: &Path) -> Result<Origin> {
+    Ok(Origin {
+        name: "scipoptsuite".to_string(),
+        version: Some(manifest.get("scip.version")?.to_string()),
+        url: Some(manifest.expand("source.url", "")?),
+        sha256: archive_sha256(manifest.sha256("source"), source),
+        components: components(manifest, &FROM_SOURCE),
+        // the suite's own license and those of its projects, e.g. `scip/LICENSE`, not the ones of
+        // test instances and examples deeper down
+        licenses: Some((source.to_path_buf(), 2)),
+    })
+}
+
+/// A SCIP installed with the headers in `include_dir`.
+#[cfg(not(feature = "bundled"))]
+pub fn installed(include_dir: &Path) -> Origin {
+    let version = scip_sys_build::ScipVersion::from_headers(include_dir)
+        .map(|v| format!("{}.{}.{}", v.major, v.minor, v.patch));
+    Origin {
+        name: "scip".to_string(),
+        version,
+        url: None,
+        sha256: None,
+        components: Vec::new(),
+        licenses: None,
+    }
+}
+
+/// Whether `name` is the name of a license file.
+fn is_license(name: &str) -> bool {
+    let name = name.to_uppercase();
+    ["LICENSE", "LICENCE", "COPYING", "NOTICE", "COPYRIGHT"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+/// The license files below `dir`, at most `depth` directories down, in a stable order. Symbolic
+/// links are not followed. Every file in a `licenses` directory counts.
+fn find_licenses(dir: &Path, depth: usize, in_licenses_dir: bool, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<_> = entries.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if file_type.is_dir() && depth > 0 {
+            let licenses_dir = in_licenses_dir || name.eq_ignore_ascii_case("licenses");
+            find_licenses(&entry.path(), depth - 1, licenses_dir, found);
+        } else if file_type.is_file() && (in_licenses_dir || is_license(&name)) {
+            found.push(entry.path());
+        }
+    }
+}
+
+/// Copy the license files of `origin` into `<out_path>/licenses/`. Returns their paths relative
+/// to that directory.
+fn copy_licenses(origin: Option<&Origin>, licenses_dir: &Path) -> Result<Vec<String>> {
+    let Some((dir, depth)) = origin.and_then(|origin| origin.licenses.as_ref()) else {
+        return Ok(Vec::new());
+    };
+    let mut files = Vec::new();
+    find_licenses(dir, *depth, false, &mut files);
+
+    let mut copied = Vec::new();
+    for file in &files {
+        let Ok(relative) = file.strip_prefix(dir) else {
+            continue;
+        };
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let text = fs::read(file).map_err(|e| {
+            BuildError::new(format!("failed to read {}", file.display())).caused_by(e)
+        })?;
+        // embedded with `include_str!`, so it must be UTF-8
+        write(
+            &licenses_dir.join(&relative),
+            &String::from_utf8_lossy(&text),
+        )?;
+        copied.push(relative);
+    }
+    if !copied.is_empty() {
+        return Ok(copied);
+    }
+
+    let origin = origin.unwrap();
+    crate::report::note(format!(
+        "{} contains no license files, writing notices of the licenses in versions.toml",
+        dir.display()
+    ));
+    for component in &origin.components {
+        let Some(license) = &component.license else {
+            continue;
+        };
+        let id = license.split_whitespace().next().unwrap_or(license);
+        let relative = format!("{}.txt", component.name);
+        write(
+            &licenses_dir.join(&relative),
+            &format!(
+                "{} {}is licensed under {license}, see https://spdx.org/licenses/{id}.html\n",
+                component.name,
+                component
+                    .version
+                    .as_ref()
+                    .map_or(String::new(), |version| format!("{version} "))
+            ),
+        )?;
+        copied.push(relative);
+    }
+    Ok(copied)
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(path, text))
+        .map_err(|e| BuildError::new(format!("failed to write {}", path.display())).caused_by(e))
+}
+
+/// The CycloneDX JSON document of `origin`.
+fn sbom(origin: Option<&Origin>) -> String {
+    let component = |name: &str, version: Option<&str>, license: Option<&str>| {
+        let mut out = format!(
+            "{{\"type\": \"library\", \"bom-ref\": {}, \"name\": {}",
+            json_string(Some(name)),
+            json_string(Some(name))
+        );
+        if let Some(version) = version {
+            write!(out, ", \"version\": {}", json_string(Some(version))).unwrap();
+        }
+        if let Some(license) = license {
+            write!(
+                out,
+                ", \"licenses\": [{{\"expression\": {}}}]",
+                json_string(Some(license))
+            )
+            .unwrap();
+        }
+        out
+    };
+
+    let package = env!("CARGO_PKG_NAME");
+    let version = env!("CARGO_PKG_VERSION");
+    let mut out = String::from("{\n");
+    out.push_str("  \"bomFormat\": \"CycloneDX\",\n");
+    out.push_str("  \"specVersion\": \"1.5\",\n");
+    out.push_str("  \"version\": 1,\n");
+    writeln!(
+        out,
+        "  \"metadata\": {{\"component\": {}, \"purl\": {}}}}},",
+        component(package, Some(version), None),
+        json_string(Some(&format!("pkg:cargo/{package}@{version}")))
+    )
+    .unwrap();
+
+    let Some(origin) = origin else {
+        out.push_str("  \"components\": []\n}\n");
+        return out;
+    };
+    let mut top = component(&origin.name, origin.version.as_deref(), None);
+    if let Some(url) = &origin.url {
+        write!(
+            top,
+            ", \"externalReferences\": [{{\"type\": \"distribution\", \"url\": {}}}]",
+            json_string(Some(url))
+        )
+        .unwrap();
+    }
+    if let Some(sha256) = &origin.sha256 {
+        write!(
+            top,
+            ", \"hashes\": [{{\"alg\": \"SHA-256\", \"content\": {}}}]",
+            json_string(Some(sha256))
+        )
+        .unwrap();
+    }
+    if !origin.components.is_empty() {
+        let nested: Vec<String> = origin
+            .components
+            .iter()
+            .map(|c| {
+                format!(
+                    "      {}}}",
+                    component(&c.name, c.version.as_deref(), c.license.as_deref())
+                )
+            })
+            .collect();
+        write!(top, ", \"components\": [\n{}\n    ]", nested.join(",\n")).unwrap();
+    }
+    writeln!(out, "  \"components\": [\n    {top}}}\n  ]\n}}").unwrap();
+    out
+}
+
+/// Copy the licenses of `origin` (`None` if no SCIP is linked), write the SBOM and the Rust source
+/// embedding both, and publish their paths to dependent crates.
+pub fn emit(origin: Option<&Origin>, out_path: &Path) -> Result<()> {
+    let licenses_dir = out_path.join("licenses");
+    // left over from an earlier build with another SCIP
+    let _ = fs::remove_dir_all(&licenses_dir);
+    fs::create_dir_all(&licenses_dir).map_err(|e| {
+        BuildError::new(format!("failed to create {}", licenses_dir.display())).caused_by(e)
+    })?;
+    let licenses = copy_licenses(origin, &licenses_dir)?;
+    let sbom_path = out_path.join("sbom.cdx.json");
+    write(&sbom_path, &sbom(origin))?;
+
+    let mut source = String::from(
+        "/// The license files of the SCIP the crate links (the files in `DEP_SCIP_LICENSES`), as\n\
+         /// their path in that directory and their text. Empty for an installed SCIP, which brings\n\
+         /// its own.\n\
+         pub fn licenses() -> &'static [(&'static str, &'static str)] {\n    &[\n",
+    );
+    for relative in &licenses {
+        let path = paths::utf8(&licenses_dir.join(relative))?;
+        writeln!(source, "        ({relative:?}, include_str!({path:?})),").unwrap();
+    }
+    source.push_str("    ]\n}\n\n");
+    writeln!(
+        source,
+        "/// The CycloneDX SBOM of the SCIP the crate links (`DEP_SCIP_SBOM`): the archive it was\n\
+         /// downloaded as, with its URL and checksum, and the libraries in it with their versions and\n\
+         /// licenses.\n\
+         pub const SBOM: &str = include_str!({:?});",
+        paths::utf8(&sbom_path)?
+    )
+    .unwrap();
+    write(&out_path.join("licenses.rs"), &source)?;
+
+    println!("cargo:licenses={}", paths::utf8(&licenses_dir)?);
+    println!("cargo:sbom={}", paths::utf8(&sbom_path)?);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Reading `versions.toml`, the single place the pinned SCIP release is recorded (see
+//! `scip_sys_build::manifest`), and checking the committed bindings of a build against it.
+
+#[cfg(feature = "bundled")]
+use crate::error::BuildError;
+use crate::error::Result;
+use scip_sys_build::manifest::Manifest;
+#[cfg(feature = "bundled")]
+use scip_sys_build::ScipVersion;
+use std::env;
+use std::path::PathBuf;
+
+/// Read `versions.toml` from the root of the crate.
+pub fn read() -> Result<Manifest> {
+    let path = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("versions.toml");
+    println!("cargo:rerun-if-changed={}", crate::paths::utf8(&path)?);
+    Manifest::read(&path)
+}
+
+/// Fail if the committed bindings `name` were generated for another SCIP than the pinned one,
+/// which the bundled build downloads. `tests/prebuilt_bindings.rs` checks all of them.
+#[cfg(feature = "bundled")]
+pub fn check_bindings(manifest: &Manifest, name: &str, bindings: &str) -> Result<()> {
+    let expected = manifest.scip_version()?;
+    let mismatch = match ScipVersion::from_bindings(bindings) {
+        Some(version) if version == expected => return Ok(()),
+        Some(version) => format!("they are for SCIP {version}"),
+        None => "they have no SCIP version constants".to_string(),
+    };
+    Err(BuildError::new(format!(
+        "the committed {name} bindings do not match SCIP {expected} pinned in versions.toml: \
+         {mismatch}"
+    ))
+    .remedy("Regenerate the bindings for the pinned release with the generate-bindings workflow")
+    .remedy("Or update versions.toml to the release the bindings were generated for"))
+}
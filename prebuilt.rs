@@ -0,0 +1,302 @@
+//! The committed bindings in `src/bindings/`: one set per target with a bundled SCIP and the
+//! pregenerated ones for builds without SCIP (docs.rs). They are stored as the items all of them
+//! share in `common.rs` plus one small file per set with the rest.
+//!
+//! The file of a set is its bindings with every run of shared items replaced by a line
+//! `//@ common <n>`, which stands for the next `n` lines of `common.rs`. Assembling a set gives
+//! back its bindings byte for byte. The shared items are found with a patience diff over the
+//! items of the sets.
+//!
+//! This file only uses `std`, so that `tests/prebuilt_bindings.rs` can include it.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The sets of committed bindings: the targets of `bundled::try_target_string`, and
+/// `pregenerated`.
+pub const NAMES: [&str; 6] = [
+    "linux",
+    "linux-arm",
+    "macos-arm",
+    "macos-intel",
+    "windows",
+    "pregenerated",
+];
+
+/// Starts the lines of the file of a set that stand for lines of `common.rs`.
+const MARKER: &str = "//@ common ";
+
+/// `common.rs` and the file of the bindings `name` in `dir`.
+pub fn files(dir: &Path, name: &str) -> [PathBuf; 2] {
+    [dir.join("common.rs"), dir.join(format!("{name}.rs"))]
+}
+
+/// The bindings `name` from the files in `dir`, or `None` if there are none.
+pub fn read(dir: &Path, name: &str) -> io::Result<Option<String>> {
+    let [common, delta] = files(dir, name);
+    if !delta.exists() {
+        return Ok(None);
+    }
+    assemble(&fs::read_to_string(common)?, &fs::read_to_string(&delta)?)
+        .map(Some)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", delta.display())))
+}
+
+/// Replace the bindings `name` in `dir` by `bindings`, splitting them anew with the others.
+#[cfg(all(feature = "bundled", feature = "bindgen"))]
+pub fn replace(dir: &Path, name: &str, bindings: String) -> io::Result<()> {
+    let mut replacement = Some(bindings);
+    let mut all = Vec::new();
+    for other in NAMES {
+        let bindings = match other == name {
+            true => replacement.take(),
+            false => read(dir, other)?,
+        };
+        all.extend(bindings.map(|bindings| (other, bindings)));
+    }
+    write(dir, &all)
+}
+
+/// Take the bindings from the copies of `src/bindings/` in `<source>/bindings-<name>/` over into
+/// `dir`, e.g. those regenerated on every platform by the `generate-bindings` workflow. The
+/// bindings without such a copy stay as they are, except for the pregenerated ones, which follow
+/// the imported Linux bindings. Returns the names of those taken over.
+pub fn import(source: &Path, dir: &Path) -> io::Result<Vec<&'static str>> {
+    let mut imported = Vec::new();
+    let mut all: Vec<(&str, String)> = Vec::new();
+    for name in NAMES {
+        let linux = all
+            .iter()
+            .find(|(other, _)| *other == PREGENERATED_FROM && imported.contains(other));
+        let bindings = match read(&source.join(format!("bindings-{name}")), name)? {
+            Some(bindings) => {
+                imported.push(name);
+                Some(bindings)
+            }
+            None if name == "pregenerated" && linux.is_some() => {
+                imported.push(name);
+                linux.map(|(_, bindings)| pregenerated(bindings))
+            }
+            None => read(dir, name)?,
+        };
+        all.extend(bindings.map(|bindings| (name, bindings)));
+    }
+    write(dir, &all)?;
+    Ok(imported)
+}
+
+/// The set the pregenerated bindings are made from: docs.rs builds on x86_64 Linux.
+pub const PREGENERATED_FROM: &str = "linux";
+
+/// The pregenerated bindings made from the bindings of [`PREGENERATED_FROM`]: the same, with a
+/// comment after bindgen's header saying what they are for.
+pub fn pregenerated(bindings: &str) -> String {
+    let (header, rest) = bindings.split_once('\n').unwrap_or((bindings, ""));
+    format!(
+        "{header}\n\
+         /* Pre-generated bindings for docs.rs builds (no network access).\n   \
+         These bindings were generated from SCIP headers and are used\n   \
+         when building documentation on docs.rs. */\n\
+         {rest}"
+    )
+}
+
+/// Write the bindings `(name, bindings)` to `dir`.
+pub fn write(dir: &Path, all: &[(&str, String)]) -> io::Result<()> {
+    let bindings: Vec<&str> = all.iter().map(|(_, bindings)| bindings.as_str()).collect();
+    let (common, deltas) = split(&bindings);
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join("common.rs"), common)?;
+    for ((name, _), delta) in all.iter().zip(deltas) {
+        fs::write(dir.join(format!("{name}.rs")), delta)?;
+    }
+    Ok(())
+}
+
+/// The bindings of a set from `common.rs` and the file of the set.
+pub fn assemble(common: &str, delta: &str) -> io::Result<String> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut common_lines = common.split_inclusive('\n');
+    let mut bindings = String::with_capacity(common.len() + delta.len());
+    for line in delta.split_inclusive('\n') {
+        let Some(count) = line.strip_prefix(MARKER) else {
+            bindings.push_str(line);
+            continue;
+        };
+        let count: usize = count
+            .trim_end()
+            .parse()
+            .map_err(|_| invalid(format!("invalid line `{}`", line.trim_end())))?;
+        for _ in 0..count {
+            let line = common_lines
+                .next()
+                .ok_or_else(|| invalid("refers to more lines than common.rs has".to_string()))?;
+            bindings.push_str(line);
+        }
+    }
+    if common_lines.next().is_some() {
+        return Err(invalid(
+            "does not use all of common.rs, which belongs to other bindings".to_string(),
+        ));
+    }
+    Ok(bindings)
+}
+
+/// Split several sets of bindings into the items they all share and a file per set.
+pub fn split(all: &[&str]) -> (String, Vec<String>) {
+    let chunks: Vec<Vec<&str>> = all.iter().map(|bindings| chunks(bindings)).collect();
+    let Some(first) = chunks.first() else {
+        return (String::new(), Vec::new());
+    };
+
+    // the shared items, with their position in every set
+    let mut common: Vec<&str> = first.clone();
+    let mut positions: Vec<Vec<usize>> = vec![(0..common.len()).collect()];
+    for bindings in &chunks[1..] {
+        let pairs = matching(&common, bindings);
+        common = pairs.iter().map(|&(i, _)| common[i]).collect();
+        for known in &mut positions {
+            *known = pairs.iter().map(|&(i, _)| known[i]).collect();
+        }
+        positions.push(pairs.iter().map(|&(_, j)| j).collect());
+    }
+
+    let deltas = chunks
+        .iter()
+        .zip(&positions)
+        .map(|(bindings, positions)| {
+            let mut delta = String::new();
+            let mut shared = positions.iter().peekable();
+            let mut run = 0;
+            for (index, chunk) in bindings.iter().enumerate() {
+                if shared.next_if(|&&position| position == index).is_some() {
+                    run += chunk.split_inclusive('\n').count();
+                    continue;
+                }
+                if run > 0 {
+                    delta.push_str(&format!("{MARKER}{run}\n"));
+                    run = 0;
+                }
+                delta.push_str(chunk);
+            }
+            if run > 0 {
+                delta.push_str(&format!("{MARKER}{run}\n"));
+            }
+            delta
+        })
+        .collect();
+    (common.concat(), deltas)
+}
+
+/// Split bindings into their top-level items, each with its attributes, and blank lines.
+fn chunks(bindings: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    let mut previous = "\n";
+    for line in bindings.split_inclusive('\n') {
+        let code = line.trim_end();
+        let starts_chunk = code.is_empty()
+            || previous.trim_end().is_empty()
+            || !(line.starts_with(char::is_whitespace)
+                || code.starts_with(['}', ')', ']', '{'])
+                || code.starts_with("where")
+                || previous.starts_with("#["));
+        if starts_chunk && offset > start {
+            chunks.push(&bindings[start..offset]);
+            start = offset;
+        }
+        offset += line.len();
+        previous = line;
+    }
+    if offset > start {
+        chunks.push(&bindings[start..offset]);
+    }
+    chunks
+}
+
+/// The pairs of indices of equal chunks of `a` and `b` that a patience diff matches, in
+/// increasing order.
+fn matching(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    match_ranges(a, b, (0, a.len()), (0, b.len()), &mut pairs);
+    pairs
+}
+
+fn match_ranges(
+    a: &[&str],
+    b: &[&str],
+    (mut a_start, mut a_end): (usize, usize),
+    (mut b_start, mut b_end): (usize, usize),
+    pairs: &mut Vec<(usize, usize)>,
+) {
+    while a_start < a_end && b_start < b_end && a[a_start] == b[b_start] {
+        pairs.push((a_start, b_start));
+        a_start += 1;
+        b_start += 1;
+    }
+    let mut suffix = Vec::new();
+    while a_start < a_end && b_start < b_end && a[a_end - 1] == b[b_end - 1] {
+        a_end -= 1;
+        b_end -= 1;
+        suffix.push((a_end, b_end));
+    }
+
+    // the chunks that occur once on both sides anchor the rest
+    let mut occurrences: HashMap<&str, [(usize, usize); 2]> = HashMap::new();
+    for (i, chunk) in (a_start..a_end).zip(&a[a_start..a_end]) {
+        let entry = occurrences.entry(chunk).or_default();
+        entry[0] = (entry[0].0 + 1, i);
+    }
+    for (j, chunk) in (b_start..b_end).zip(&b[b_start..b_end]) {
+        if let Some(entry) = occurrences.get_mut(chunk) {
+            entry[1] = (entry[1].0 + 1, j);
+        }
+    }
+    let mut unique: Vec<(usize, usize)> = occurrences
+        .into_values()
+        .filter(|[(in_a, _), (in_b, _)]| *in_a == 1 && *in_b == 1)
+        .map(|[(_, i), (_, j)]| (i, j))
+        .collect();
+    unique.sort_unstable();
+
+    let mut previous = (a_start, b_start);
+    for (i, j) in longest_increasing(&unique) {
+        match_ranges(a, b, (previous.0, i), (previous.1, j), pairs);
+        pairs.push((i, j));
+        previous = (i + 1, j + 1);
+    }
+    if previous != (a_start, b_start) {
+        match_ranges(a, b, (previous.0, a_end), (previous.1, b_end), pairs);
+    }
+    pairs.extend(suffix.into_iter().rev());
+}
+
+/// The longest subsequence of `pairs` (sorted by the first index) that also increases in the
+/// second index.
+fn longest_increasing(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // `tails[k]` is the index in `pairs` of the smallest end of an increasing run of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![None; pairs.len()];
+    for (index, &(_, j)) in pairs.iter().enumerate() {
+        let length = tails.partition_point(|&tail| pairs[tail].1 < j);
+        if length > 0 {
+            predecessors[index] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+    let mut run = Vec::new();
+    let mut next = tails.last().copied();
+    while let Some(index) = next {
+        run.push(pairs[index]);
+        next = predecessors[index];
+    }
+    run.reverse();
+    run
+}
@@ -0,0 +1,245 @@
+//! The `runtime-load` mode: instead of declaring SCIP's functions in `extern "C"` blocks, which
+//! requires linking against libscip, they become fields of a `ScipLibrary` struct that is filled
+//! from a library opened at runtime, like the bindings bindgen generates with
+//! `dynamic_library_name`.
+//!
+//! This is done on the finished bindings, so it works the same for prebuilt and generated ones.
+//! Functions of the C library (`printf`, ...) stay in their `extern` blocks.
+
+use crate::feature_groups::{item_name, split_items};
+use crate::header_modules::{HeaderMap, SCIP_DIRECTORIES};
+use std::fmt::Write as _;
+
+/// A function of libscip.
+struct Function<'a> {
+    name: &'a str,
+    /// The `#[doc]` and other attributes
+    attributes: Vec<&'a str>,
+    /// The parameters with their types, as written in the bindings
+    parameters: Vec<String>,
+    /// `-> T`, or empty
+    output: String,
+}
+
+/// Move the functions of libscip out of the `extern` blocks of `bindings` into a `ScipLibrary`
+/// struct. Returns the new bindings and the number of moved functions.
+pub fn into_library_struct(bindings: &str, map: &HeaderMap) -> (String, usize) {
+    let from_scip = |name: &str| match map.get(name) {
+        Some(header) => header
+            .split_once('/')
+            .is_some_and(|(directory, _)| SCIP_DIRECTORIES.contains(&directory)),
+        // without a map, go by SCIP's naming conventions
+        None => ["SCIP", "BMS", "tclique"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix)),
+    };
+
+    let lines: Vec<&str> = bindings.lines().collect();
+    let mut out: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut functions = Vec::new();
+    for item in split_items(&lines) {
+        let code = item.iter().find(|l| !l.trim_start().starts_with("#["));
+        match code.map(|l| l.trim_start()) {
+            Some(l)
+                if l.starts_with("unsafe extern \"C\" {") || l.starts_with("extern \"C\" {") =>
+            {
+                let open = item.iter().position(|l| Some(l) == code).unwrap();
+                let inner = &item[open + 1..item.len() - 1];
+                let mut kept = Vec::new();
+                for declaration in split_items(inner) {
+                    let function = item_name(&declaration)
+                        .filter(|name| from_scip(name))
+                        .and_then(|name| parse_function(name, &declaration));
+                    match function {
+                        Some(function) => functions.push(function),
+                        None => kept.extend(declaration),
+                    }
+                }
+                if kept.iter().any(|l| !l.trim().is_empty()) {
+                    out.extend(&item[..=open]);
+                    out.extend(kept);
+                    out.push(item[item.len() - 1]);
+                }
+            }
+            _ => out.extend(item),
+        }
+    }
+
+    let mut converted = out.join("\n");
+    converted.push('\n');
+    converted.push_str(&library_struct(&functions));
+    (converted, functions.len())
+}
+
+/// Parse the declaration of a `pub fn` in an `extern` block. Returns `None` for anything this
+/// does not understand, e.g. a `#[link_name]`, so it is kept as it is.
+fn parse_function<'a>(name: &'a str, declaration: &[&'a str]) -> Option<Function<'a>> {
+    let (attributes, code): (Vec<&str>, Vec<&str>) = declaration
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .partition(|l| l.trim_start().starts_with("#["));
+    if attributes
+        .iter()
+        .any(|a| !a.trim_start().starts_with("#[doc"))
+    {
+        return None;
+    }
+    let code = code.iter().map(|l| l.trim()).collect::<Vec<_>>().join(" ");
+    let rest = code.strip_prefix(&format!("pub fn {name}("))?;
+
+    // the parameters end at the parenthesis matching the opening one
+    let mut depth = 1;
+    let end = rest.find(|c| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        depth == 0
+    })?;
+    let parameters = split_top_level(&rest[..end]);
+    let output = rest[end + 1..].trim().strip_suffix(';')?.trim().to_string();
+
+    Some(Function {
+        name,
+        attributes,
+        parameters,
+        output,
+    })
+}
+
+/// Split a parameter list at the commas that are not nested in brackets.
+fn split_top_level(parameters: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in parameters.chars() {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                out.push(std::mem::take(&mut current).trim().to_string());
+                continue;
+            }
+            _ => {}
+        }
+        // `->` is not a closing bracket
+        if c == '>' && current.ends_with('-') {
+            depth += 1;
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        out.push(current.trim().to_string());
+    }
+    out
+}
+
+/// The `ScipLibrary` struct with a field and, unless it is variadic, a method per function.
+fn library_struct(functions: &[Function]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "/// The functions of a libscip opened at runtime, see [`ScipLibrary::load`].\n\
+         ///\n\
+         /// Every function is a field holding the function pointer and, unless it is variadic, a\n\
+         /// method calling it.\n\
+         pub struct ScipLibrary {\n    __library: ::libloading::Library,\n",
+    );
+    for function in functions {
+        writeln!(
+            out,
+            "    pub {}: unsafe extern \"C\" fn({}){},",
+            function.name,
+            function.parameters.join(", "),
+            output_suffix(&function.output)
+        )
+        .unwrap();
+    }
+    // The symbols are first resolved into `ScipSymbols`, so that the missing ones can be
+    // collected. Both structs are filled in a single expression: thousands of `let` bindings
+    // would nest as deeply in the debug info, which LLVM does not survive.
+    out.push_str("}\n\nstruct ScipSymbols {\n");
+    for function in functions {
+        writeln!(
+            out,
+            "    {}: Option<unsafe extern \"C\" fn({}){}>,",
+            function.name,
+            function.parameters.join(", "),
+            output_suffix(&function.output)
+        )
+        .unwrap();
+    }
+    out.push_str("}\n\nimpl ScipLibrary {\n");
+    out.push_str(
+        "    /// Resolve all functions in `library`, failing with the names of the missing ones.\n\
+         ///\n\
+         /// # Safety\n\
+         /// `library` must be a libscip with the API these bindings were made for.\n\
+         pub unsafe fn from_library(\n\
+                 library: ::libloading::Library,\n\
+             ) -> Result<Self, crate::runtime_load::LoadError> {\n\
+                 let mut missing = Vec::new();\n\
+                 let symbols = ScipSymbols {\n",
+    );
+    for function in functions {
+        writeln!(
+            out,
+            "            {0}: crate::runtime_load::symbol(&library, \"{0}\\0\", &mut missing),",
+            function.name
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "        };\n\
+                 if !missing.is_empty() {\n\
+                     return Err(crate::runtime_load::LoadError::MissingSymbols(missing));\n\
+                 }\n\
+                 Ok(ScipLibrary {\n\
+                     __library: library,\n",
+    );
+    for function in functions {
+        writeln!(out, "            {0}: symbols.{0}.unwrap(),", function.name).unwrap();
+    }
+    out.push_str("        })\n    }\n");
+
+    for function in functions {
+        if function.parameters.last().map(String::as_str) == Some("...") {
+            continue;
+        }
+        let arguments: Vec<&str> = function
+            .parameters
+            .iter()
+            .map(|p| {
+                p.split_once(':')
+                    .map_or(p.as_str(), |(name, _)| name.trim())
+            })
+            .collect();
+        for attribute in &function.attributes {
+            writeln!(out, "    {}", attribute.trim()).unwrap();
+        }
+        writeln!(
+            out,
+            "    pub unsafe fn {0}(&self{1}{2}){3} {{\n        (self.{0})({4})\n    }}",
+            function.name,
+            if function.parameters.is_empty() {
+                ""
+            } else {
+                ", "
+            },
+            function.parameters.join(", "),
+            output_suffix(&function.output),
+            arguments.join(", ")
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn output_suffix(output: &str) -> String {
+    if output.is_empty() {
+        String::new()
+    } else {
+        format!(" {output}")
+    }
+}
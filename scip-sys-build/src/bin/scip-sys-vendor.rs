@@ -0,0 +1,202 @@
+//! Download the archives a `bundled` or `from-source` build of scip-sys needs into a vendor
+//! directory, so that builds without network access can take them from there with
+//! `SCIP_SYS_VENDOR_DIR` (see `scip_sys_build::vendor`).
+//!
+//! The URLs and checksums come from the `versions.toml` of scip-sys, the same ones its build
+//! script uses. Several `--versions` files vendor the releases of several scip-sys versions into
+//! one directory. Each archive is checked against its checksum in `versions.toml`. An archive
+//! without one is downloaded and its checksum printed, to be filled into `versions.toml`, but not
+//! vendored, as a build could not verify it.
+
+use scip_sys_build::bundled::PLATFORMS;
+use scip_sys_build::download::{get, sha256_hex, verify_sha256};
+use scip_sys_build::manifest::Manifest;
+use scip_sys_build::{vendor, BuildError, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: scip-sys-vendor [OPTIONS] <DIR>
+
+Download the SCIP archives of scip-sys builds into DIR, for building offline with
+SCIP_SYS_VENDOR_DIR=DIR.
+
+Options:
+  --versions <FILE>   The versions.toml of the scip-sys release to vendor for, may be repeated
+                      [default: versions.toml]
+  --target <TAG>      The precompiled SCIP for the `bundled` feature on the platform TAG, may be
+                      repeated; `all` for every platform
+                      [platforms: linux, linux-arm, macos-intel, macos-arm, windows]
+  --source            The SCIP sources for the `from-source` feature
+  -h, --help          Print this help";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    manifests: Vec<PathBuf>,
+    targets: Vec<String>,
+    source: bool,
+    dir: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let options = match parse(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The options in `args`, or `None` if help was asked for.
+fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut args = args.into_iter();
+    let mut manifests = Vec::new();
+    let mut targets = Vec::new();
+    let mut source = false;
+    let mut dir = None;
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| args.next().ok_or(format!("{option} needs a value"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--versions" => manifests.push(PathBuf::from(value("--versions")?)),
+            "--target" => match value("--target")?.as_str() {
+                "all" => targets.extend(PLATFORMS.map(String::from)),
+                tag if PLATFORMS.contains(&tag) => targets.push(tag.to_string()),
+                tag => {
+                    return Err(format!(
+                        "there is no precompiled SCIP for `{tag}`, the platforms are {}",
+                        PLATFORMS.join(", ")
+                    ))
+                }
+            },
+            "--source" => source = true,
+            option if option.starts_with('-') => return Err(format!("unknown option {option}")),
+            _ if dir.is_some() => return Err(format!("unexpected argument {arg}")),
+            _ => dir = Some(PathBuf::from(arg)),
+        }
+    }
+    let dir = dir.ok_or("no vendor directory given")?;
+    if targets.is_empty() && !source {
+        return Err("nothing to vendor, give --target or --source".to_string());
+    }
+    if manifests.is_empty() {
+        manifests.push(PathBuf::from("versions.toml"));
+    }
+    targets.sort();
+    targets.dedup();
+    Ok(Some(Options {
+        manifests,
+        targets,
+        source,
+        dir,
+    }))
+}
+
+fn run(options: &Options) -> Result<()> {
+    for path in &options.manifests {
+        let manifest = Manifest::read(path)?;
+        println!(
+            "SCIP {} from {}",
+            manifest.get("scip.version")?,
+            path.display()
+        );
+        for target in &options.targets {
+            let url = manifest.expand("deploy.url", target)?;
+            vendor_archive(&options.dir, &url, &manifest, target)?;
+        }
+        if options.source {
+            let url = manifest.expand("source.url", "")?;
+            vendor_archive(&options.dir, &url, &manifest, "source")?;
+        }
+    }
+    println!(
+        "Build with {}={} to use them",
+        vendor::ENV_VAR,
+        options.dir.display()
+    );
+    Ok(())
+}
+
+/// Download the archive `name` (a platform tag or `source`) at `url` into the vendor directory
+/// `dir`, unless it is there already. An error if `manifest` records no checksum for it.
+fn vendor_archive(dir: &Path, url: &str, manifest: &Manifest, name: &str) -> Result<()> {
+    let path = vendor::path_of(url)?;
+    let sha256 = manifest.sha256(name);
+    if let (Some(sha256), Some(recorded)) = (sha256, vendor::checksums(dir)?.get(&path)) {
+        let expected = sha256.eq_ignore_ascii_case(recorded);
+        let intact = fs::read(dir.join(&path)).is_ok_and(|data| sha256_hex(&data) == *recorded);
+        if expected && intact {
+            println!("  {path}: already vendored, sha256 {recorded}");
+            return Ok(());
+        }
+    }
+
+    println!("  {path}: downloading {url}");
+    let data = get(url).map_err(|e| {
+        BuildError::new(format!("failed to download {url}"))
+            .caused_by(e)
+            .remedy("Check the network connection and any HTTP(S) proxy settings")
+    })?;
+    println!("  {path}: sha256 {}", sha256_hex(&data));
+    verify_sha256(url, &data, manifest.required_sha256(name)?)?;
+    vendor::add(dir, url, &data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        super::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_the_targets_to_vendor() {
+        let options = parse(&["--target", "linux", "--source", "vendor"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            options,
+            Options {
+                manifests: vec![PathBuf::from("versions.toml")],
+                targets: vec!["linux".to_string()],
+                source: true,
+                dir: PathBuf::from("vendor"),
+            }
+        );
+
+        let options = parse(&["--versions", "a.toml", "--target", "all", "vendor"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.manifests, [PathBuf::from("a.toml")]);
+        let mut platforms = PLATFORMS.to_vec();
+        platforms.sort();
+        assert_eq!(options.targets, platforms);
+
+        let options = parse(&["--target", "linux", "--target", "all", "vendor"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.targets, platforms);
+        assert!(!options.source);
+
+        assert_eq!(parse(&["--help"]), Ok(None));
+        assert!(parse(&["--target", "solaris", "vendor"]).is_err());
+        assert!(parse(&["--source"]).is_err());
+        assert!(parse(&["vendor"]).is_err());
+    }
+}
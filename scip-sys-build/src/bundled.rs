@@ -0,0 +1,236 @@
+//! The precompiled SCIP releases (scipoptsuite-deploy): the platform tag of the target in their
+//! names, downloading one, and checking that it fits the target.
+
+#[cfg(feature = "download")]
+use crate::download::{download_and_extract_zip, record_sha256};
+use crate::elf::{Elf, Reader};
+use crate::error::{BuildError, Result};
+#[cfg(feature = "download")]
+use crate::{report, Installation};
+use std::env;
+use std::path::Path;
+
+/// The platform tags of the precompiled releases.
+pub const PLATFORMS: [&str; 5] = ["linux", "linux-arm", "macos-intel", "macos-arm", "windows"];
+
+/// The platform tag of the target in the names of the precompiled releases, or `None` if there
+/// is none for it.
+pub fn platform() -> Option<&'static str> {
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    platform_of(&os, &arch)
+}
+
+fn platform_of(os: &str, arch: &str) -> Option<&'static str> {
+    Some(match (os, arch) {
+        ("linux", "x86_64") => "linux",
+        ("linux", "aarch64") => "linux-arm",
+        ("macos", "x86_64") => "macos-intel",
+        ("macos", "aarch64") => "macos-arm",
+        ("windows", "x86_64") => "windows",
+        _ => return None,
+    })
+}
+
+/// Download the release at `url`, a zip archive checked against `sha256` when that is given, and
+/// extract it into `<out_dir>/scip_install`, unless an earlier build did. The checksum of the
+/// archive is kept for `download::archive_sha256`.
+#[cfg(feature = "download")]
+pub fn download(url: &str, sha256: Option<&str>, out_dir: &Path) -> Result<Installation> {
+    let root = out_dir.join("scip_install");
+    if root.exists() {
+        report::probe(root.display().to_string(), "previously downloaded");
+        return Ok(Installation::in_root(root));
+    }
+
+    let archive_sha256 = download_and_extract_zip(url, out_dir, sha256).map_err(|e| {
+        // a partial extraction would be taken for a finished download by the next build
+        let _ = std::fs::remove_dir_all(&root);
+        match e.downcast::<BuildError>() {
+            // a checksum mismatch
+            Ok(e) => *e,
+            Err(e) => BuildError::new(format!("failed to download and extract {url}"))
+                .caused_by(e)
+                .remedy("Check the network connection and any HTTP(S) proxy settings"),
+        }
+    })?;
+    record_sha256(&root, &archive_sha256)?;
+    Ok(Installation::in_root(root))
+}
+
+/// Check that libscip in the downloaded release in `root` is a library of the target's format
+/// and architecture, so that a wrong archive fails the build instead of the link.
+pub fn check_library(root: &Path) -> Result<()> {
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    check_library_for(root, &os, &arch)
+}
+
+fn check_library_for(root: &Path, os: &str, arch: &str) -> Result<()> {
+    let library = [
+        "lib/libscip*.so*",
+        "lib/libscip*.dylib",
+        "bin/*scip*.dll",
+        "lib/*scip*.dll",
+    ]
+    .iter()
+    .find_map(|pattern| crate::paths::find(root, pattern).into_iter().next())
+    .ok_or_else(|| {
+        BuildError::new(format!(
+            "the downloaded SCIP in {} contains no libscip",
+            root.display()
+        ))
+        .remedy(format!("Delete {} to download it again", root.display()))
+    })?;
+    let data = std::fs::read(&library).map_err(|e| {
+        BuildError::new(format!("failed to read {}", library.display())).caused_by(e)
+    })?;
+    let expected_format = match os {
+        "macos" => "Mach-O",
+        "windows" => "PE",
+        _ => "ELF",
+    };
+    match binary_format(&data) {
+        Some((format, archs)) if format == expected_format && archs.contains(&arch) => Ok(()),
+        found => {
+            let found = match found {
+                Some((format, archs)) => format!("a {format} library for {}", archs.join(", ")),
+                None => "not a library this build can read".to_string(),
+            };
+            Err(BuildError::new(format!(
+                "the downloaded {} is {found}, but the target is {os}-{arch}, which needs a \
+                 {expected_format} library for {arch}; the archive for '{}' is wrong",
+                library.display(),
+                platform_of(os, arch).unwrap_or("this target")
+            ))
+            .remedy(format!("Delete {} to download it again", root.display())))
+        }
+    }
+}
+
+/// The format of a shared library (`ELF`, `Mach-O` or `PE`) and the architectures it contains,
+/// named like `CARGO_CFG_TARGET_ARCH`.
+fn binary_format(data: &[u8]) -> Option<(&'static str, Vec<&'static str>)> {
+    let le = Reader::little_endian(data);
+    let be = Reader::big_endian(data);
+
+    let elf_arch = |machine| match machine {
+        0x3e => "x86_64",
+        0xb7 => "aarch64",
+        0x03 => "x86",
+        0x28 => "arm",
+        _ => "unknown",
+    };
+    let mach_arch = |cputype| match cputype {
+        0x0100_0007 => "x86_64",
+        0x0100_000c => "aarch64",
+        _ => "unknown",
+    };
+    let pe_arch = |machine| match machine {
+        0x8664 => "x86_64",
+        0xaa64 => "aarch64",
+        0x014c => "x86",
+        _ => "unknown",
+    };
+
+    if let Some(elf) = Elf::parse(data) {
+        return Some(("ELF", vec![elf_arch(elf.machine()?)]));
+    }
+    if data.starts_with(&[0xcf, 0xfa, 0xed, 0xfe]) {
+        return Some(("Mach-O", vec![mach_arch(le.u32_at(4)?)]));
+    }
+    if data.starts_with(&[0xca, 0xfe, 0xba, 0xbe]) {
+        // a universal binary: `fat_arch` entries of 20 bytes after the 8 byte header
+        let count = be.u32_at(4)? as usize;
+        let archs = (0..count)
+            .map(|i| be.u32_at(8 + i * 20).map(mach_arch))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(("Mach-O", archs));
+    }
+    if data.starts_with(b"MZ") {
+        let pe = le.u32_at(0x3c)? as usize;
+        if data.get(pe..pe + 4)? == b"PE\0\0" {
+            return Some(("PE", vec![pe_arch(le.u16_at(pe + 4)?)]));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn elf(machine: u16) -> Vec<u8> {
+        // 64-bit, little-endian
+        let mut data = b"\x7fELF\x02\x01".to_vec();
+        data.resize(0x12, 0);
+        data.extend(machine.to_le_bytes());
+        data.resize(64, 0);
+        data
+    }
+
+    fn pe(machine: u16) -> Vec<u8> {
+        let mut data = b"MZ".to_vec();
+        data.resize(0x3c, 0);
+        data.extend(0x40u32.to_le_bytes());
+        data.extend(b"PE\0\0");
+        data.extend(machine.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn reads_the_format_of_libraries() {
+        assert_eq!(binary_format(&elf(0x3e)), Some(("ELF", vec!["x86_64"])));
+        assert_eq!(binary_format(&elf(0xb7)), Some(("ELF", vec!["aarch64"])));
+        assert_eq!(binary_format(&pe(0x8664)), Some(("PE", vec!["x86_64"])));
+
+        let mut thin = vec![0xcf, 0xfa, 0xed, 0xfe];
+        thin.extend(0x0100_000cu32.to_le_bytes());
+        assert_eq!(binary_format(&thin), Some(("Mach-O", vec!["aarch64"])));
+
+        let mut universal = vec![0xca, 0xfe, 0xba, 0xbe];
+        universal.extend(2u32.to_be_bytes());
+        for cputype in [0x0100_0007u32, 0x0100_000c] {
+            universal.extend(cputype.to_be_bytes());
+            universal.extend([0; 16]);
+        }
+        assert_eq!(
+            binary_format(&universal),
+            Some(("Mach-O", vec!["x86_64", "aarch64"]))
+        );
+
+        assert_eq!(binary_format(b"!<arch>\n"), None);
+    }
+
+    #[test]
+    fn checks_the_downloaded_library_against_the_target() {
+        let root = tempfile::tempdir().unwrap();
+        let lib = root.path().join("lib");
+        fs::create_dir_all(&lib).unwrap();
+        let error = check_library_for(root.path(), "linux", "x86_64").unwrap_err();
+        assert!(error.to_string().contains("contains no libscip"), "{error}");
+
+        fs::write(lib.join("libscip.so.10.0"), elf(0x3e)).unwrap();
+        check_library_for(root.path(), "linux", "x86_64").unwrap();
+        let error = check_library_for(root.path(), "linux", "aarch64")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("is a ELF library for x86_64") && error.contains("'linux-arm'"),
+            "{error}"
+        );
+        let error = check_library_for(root.path(), "windows", "x86_64")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("needs a PE library"), "{error}");
+    }
+
+    #[test]
+    fn names_the_platforms_with_releases() {
+        assert_eq!(platform_of("linux", "x86_64"), Some("linux"));
+        assert_eq!(platform_of("macos", "aarch64"), Some("macos-arm"));
+        assert_eq!(platform_of("windows", "aarch64"), None);
+        assert_eq!(platform_of("freebsd", "x86_64"), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Looking for an installed SCIP: in the directories named by environment variables (SCIPOPTDIR,
+//! a conda environment) and in the system's include directories. Every location looked at is
+//! recorded with `report::probe`, so that a failed search can list them.
+
+use crate::{paths, report, Installation};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The variables `scip-sys` looks in, in this order.
+pub const ENV_VARS: [&str; 2] = ["SCIPOPTDIR", "CONDA_PREFIX"];
+
+/// The include directories of system installations `scip-sys` looks in, in this order.
+pub const SYSTEM_INCLUDE_DIRS: [&str; 5] = [
+    "/usr/include",
+    "/usr/local/include",
+    "/opt/local/include",          // MacPorts
+    "/opt/homebrew/include",       // Homebrew ARM Mac
+    "/usr/local/opt/scip/include", // Homebrew Intel Mac
+];
+
+/// Whether `dir` contains a `lib/libscip*`.
+pub fn has_libscip(dir: &Path) -> bool {
+    !paths::find(dir, "lib/libscip*").is_empty()
+}
+
+/// The installation in the directory named by the first of `vars` that contains libscip. The
+/// variable becomes the strategy of the report.
+pub fn from_env(vars: &[&str]) -> Option<Installation> {
+    for &var in vars {
+        report::directive(format_args!("rerun-if-env-changed={var}"));
+        let Some(dir) = env::var_os(var) else {
+            report::probe(var, "not set");
+            continue;
+        };
+        let dir = PathBuf::from(dir);
+        let location = format!("{var}={}", dir.display());
+        if has_libscip(&dir) {
+            report::probe(location, "found libscip");
+            report::strategy(var);
+            return Some(Installation::in_root(dir));
+        }
+        report::probe(location, "no lib/libscip*");
+    }
+    None
+}
+
+/// The first of `include_dirs` with the SCIP headers, as a system installation.
+pub fn system(include_dirs: &[&str]) -> Option<Installation> {
+    for &include_dir in include_dirs {
+        let base = PathBuf::from(include_dir);
+        let missing = ["scip.h", "scipdefplugins.h", "def.h"]
+            .into_iter()
+            .map(|header| Path::new("scip").join(header))
+            .find(|header| !base.join(header).exists());
+        match missing {
+            None => {
+                report::probe(include_dir, "found the SCIP headers");
+                report::strategy("system");
+                return Some(Installation {
+                    root: base.parent().unwrap_or(&base).to_path_buf(),
+                    include_dir: base,
+                    system: true,
+                });
+            }
+            Some(header) => report::probe(include_dir, format!("no {}", header.display())),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn finds_the_first_variable_with_libscip() {
+        let empty = tempfile::tempdir().unwrap();
+        let scip = tempfile::tempdir().unwrap();
+        touch(&scip.path().join("lib").join("libscip.so.10.0"));
+        touch(&scip.path().join("include").join("scip").join("scip.h"));
+        env::set_var("SCIP_SYS_BUILD_TEST_EMPTY", empty.path());
+        env::set_var("SCIP_SYS_BUILD_TEST_SCIP", scip.path());
+
+        let found = from_env(&[
+            "SCIP_SYS_BUILD_TEST_UNSET",
+            "SCIP_SYS_BUILD_TEST_EMPTY",
+            "SCIP_SYS_BUILD_TEST_SCIP",
+        ]);
+        assert_eq!(found, Some(Installation::in_root(scip.path())));
+        assert_eq!(found.unwrap().include_dir, scip.path().join("include"));
+
+        let attempts = report::attempts();
+        let result = |location: &str| {
+            attempts
+                .iter()
+                .find(|(probed, _)| probed.starts_with(location))
+                .map(|(_, result)| result.as_str())
+        };
+        assert_eq!(result("SCIP_SYS_BUILD_TEST_UNSET"), Some("not set"));
+        assert_eq!(
+            result("SCIP_SYS_BUILD_TEST_EMPTY="),
+            Some("no lib/libscip*")
+        );
+        assert_eq!(result("SCIP_SYS_BUILD_TEST_SCIP="), Some("found libscip"));
+    }
+
+    #[test]
+    fn finds_nothing_without_libscip() {
+        let headers_only = tempfile::tempdir().unwrap();
+        touch(
+            &headers_only
+                .path()
+                .join("include")
+                .join("scip")
+                .join("scip.h"),
+        );
+        env::set_var("SCIP_SYS_BUILD_TEST_HEADERS_ONLY", headers_only.path());
+        assert_eq!(from_env(&["SCIP_SYS_BUILD_TEST_HEADERS_ONLY"]), None);
+    }
+
+    #[test]
+    fn finds_system_headers() {
+        let partial = tempfile::tempdir().unwrap();
+        let usr = tempfile::tempdir().unwrap();
+        for header in ["scip.h", "scipdefplugins.h"] {
+            touch(&partial.path().join("include").join("scip").join(header));
+        }
+        for header in ["scip.h", "scipdefplugins.h", "def.h"] {
+            touch(&usr.path().join("include").join("scip").join(header));
+        }
+        let partial_include = partial.path().join("include");
+        let usr_include = usr.path().join("include");
+
+        let found = system(&[
+            partial_include.to_str().unwrap(),
+            usr_include.to_str().unwrap(),
+        ])
+        .unwrap();
+        assert_eq!(
+            found,
+            Installation {
+                root: usr.path().to_path_buf(),
+                include_dir: usr_include,
+                system: true,
+            }
+        );
+        assert!(report::attempts().contains(&(
+            partial_include.to_str().unwrap().to_string(),
+            format!("no {}", Path::new("scip").join("def.h").display())
+        )));
+        assert_eq!(system(&[partial_include.to_str().unwrap()]), None);
+    }
+}
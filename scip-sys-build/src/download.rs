@@ -0,0 +1,181 @@
+//! Downloading and extracting archives, checked against their SHA-256.
+
+use crate::error::BuildError;
+use std::error::Error;
+use std::fs::File;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use tempfile::tempdir;
+use zip_extract::extract;
+
+/// Downloads a `.zip` archive (or takes it from the vendor directory, see `vendor`) and extracts
+/// it into `extract_path`, after checking it against `sha256` when that is given. Returns the
+/// SHA-256 of the archive.
+pub fn download_and_extract_zip(
+    url: &str,
+    extract_path: &Path,
+    sha256: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let content = fetch(url)?;
+    verify(url, &content, sha256)?;
+
+    // Create a temporary file to store the ZIP
+    let dir = tempdir()?;
+    let zip_path = dir.path().join("libscip.zip");
+
+    let mut temp_file = File::create(&zip_path)?;
+    temp_file.write_all(&content)?;
+    let target_dir = PathBuf::from(extract_path);
+
+    crate::report::note(format!("Extracting to {}", target_dir.display()));
+    extract(Cursor::new(std::fs::read(zip_path)?), &target_dir, false)?;
+
+    extract_nested_zip(&target_dir)?;
+    Ok(sha256_hex(&content))
+}
+
+/// Downloads a `.tar.gz`/`.tgz` archive (or takes it from the vendor directory) and extracts it
+/// into `extract_path`, after checking it against `sha256` when that is given. Returns the
+/// SHA-256 of the archive.
+#[cfg(feature = "from-source")]
+pub fn download_and_extract_tar_gz(
+    url: &str,
+    extract_path: &Path,
+    sha256: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let content = fetch(url)?;
+    verify(url, &content, sha256)?;
+
+    crate::report::note(format!("Extracting to {}", extract_path.display()));
+    let sha256 = sha256_hex(&content);
+    let decoder = flate2::read::GzDecoder::new(Cursor::new(content));
+    tar::Archive::new(decoder).unpack(extract_path)?;
+    Ok(sha256)
+}
+
+/// The archive at `url`, from the vendor directory if `SCIP_SYS_VENDOR_DIR` is set.
+fn fetch(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    if let Some(dir) = crate::vendor::dir() {
+        return Ok(crate::vendor::read(&dir, url)?);
+    }
+    crate::report::probe(url, "downloaded");
+    get(url)
+}
+
+/// Download `url`.
+pub fn get(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let resp = ureq::get(url).timeout(Duration::from_secs(300)).call()?;
+    let mut content: Vec<u8> = Vec::new();
+    resp.into_reader().read_to_end(&mut content)?;
+    Ok(content)
+}
+
+/// If extracting produced a single nested zip file, extract that too (some
+/// release archives wrap the install in another zip).
+fn extract_nested_zip(target_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let extracted_files: Vec<_> = std::fs::read_dir(target_dir)?.collect();
+    if extracted_files.len() == 1 {
+        let first_file = extracted_files[0].as_ref().map_err(|e| e.to_string())?;
+        if first_file
+            .path()
+            .extension()
+            .is_some_and(|ext| ext == "zip")
+        {
+            crate::report::note("Found nested zip file, extracting again");
+            let nested_zip_path = first_file.path();
+            extract(
+                Cursor::new(std::fs::read(&nested_zip_path)?),
+                &(target_dir.join("scip_install")),
+                true,
+            )?;
+            std::fs::remove_file(nested_zip_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check a downloaded archive against its checksum, if one is known.
+fn verify(url: &str, content: &[u8], sha256: Option<&str>) -> Result<(), Box<dyn Error>> {
+    match sha256 {
+        Some(sha256) => verify_sha256(url, content, sha256)?,
+        None => crate::report::note(format!("No SHA-256 known for {url}, not verified")),
+    }
+    Ok(())
+}
+
+/// Record `sha256`, the SHA-256 of the archive that was extracted to `dir`, in `<dir>.sha256`,
+/// for `archive_sha256`.
+pub fn record_sha256(dir: &Path, sha256: &str) -> Result<(), BuildError> {
+    let path = sidecar(dir);
+    std::fs::write(&path, format!("{sha256}\n"))
+        .map_err(|e| BuildError::new(format!("failed to write {}", path.display())).caused_by(e))
+}
+
+/// The SHA-256 of the archive that was extracted to `dir`, as recorded by `record_sha256`.
+pub fn archive_sha256(dir: &Path) -> Option<String> {
+    let sha256 = std::fs::read_to_string(sidecar(dir)).ok()?;
+    Some(sha256.trim().to_string()).filter(|sha256| !sha256.is_empty())
+}
+
+fn sidecar(dir: &Path) -> PathBuf {
+    let mut name = dir.file_name().unwrap_or_default().to_os_string();
+    name.push(".sha256");
+    dir.with_file_name(name)
+}
+
+/// The SHA-256 of `data`, in lowercase hex.
+pub fn sha256_hex(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    use std::fmt::Write as _;
+
+    Sha256::digest(data)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+/// Fail unless `data` has the SHA-256 `expected` (hex).
+pub fn verify_sha256(name: &str, data: &[u8], expected: &str) -> Result<(), BuildError> {
+    let actual = sha256_hex(data);
+    if actual.eq_ignore_ascii_case(expected) {
+        return Ok(());
+    }
+    Err(BuildError::new(format!(
+        "the SHA-256 of {name} is {actual}, but {expected} was expected"
+    ))
+    .remedy("Retry the download, it may have been corrupted or intercepted")
+    .remedy("If the release was re-published on purpose, update the recorded checksum"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_the_sha256() {
+        let abc = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        verify_sha256("abc", b"abc", abc).unwrap();
+        verify_sha256("abc", b"abc", &abc.to_uppercase()).unwrap();
+        let error = verify_sha256("abd", b"abd", abc).unwrap_err().to_string();
+        assert!(
+            error.contains(&format!("but {abc} was expected")),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn records_the_checksum_of_an_archive() {
+        let out_dir = tempfile::tempdir().unwrap();
+        let extracted = out_dir.path().join("scip_install");
+        assert_eq!(archive_sha256(&extracted), None);
+        record_sha256(&extracted, &sha256_hex(b"abc")).unwrap();
+        assert!(out_dir.path().join("scip_install.sha256").exists());
+        assert_eq!(archive_sha256(&extracted), Some(sha256_hex(b"abc")));
+    }
+}
@@ -0,0 +1,463 @@
+//! A reader for the parts of ELF files the build looks at: the header, the program and section
+//! headers, the dynamic section and the dynamic symbol table. Both byte orders and both classes
+//! (32- and 64-bit) are read; anything malformed reads as `None`.
+//!
+//! `Reader` is the byte reader underneath, also used for the other binary formats in `bundled`.
+
+use std::collections::BTreeSet;
+
+const PT_LOAD: u32 = 1;
+const PT_DYNAMIC: u32 = 2;
+const SHT_DYNSYM: u32 = 11;
+const SHN_UNDEF: u16 = 0;
+const DT_NULL: u64 = 0;
+
+/// Reads integers of one byte order at offsets into a byte slice.
+#[derive(Clone, Copy)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub fn little_endian(data: &'a [u8]) -> Self {
+        Reader {
+            data,
+            big_endian: false,
+        }
+    }
+
+    pub fn big_endian(data: &'a [u8]) -> Self {
+        Reader {
+            data,
+            big_endian: true,
+        }
+    }
+
+    fn bytes<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        let bytes: [u8; N] = self
+            .data
+            .get(offset..offset.checked_add(N)?)?
+            .try_into()
+            .ok()?;
+        Some(bytes)
+    }
+
+    pub fn u16_at(&self, offset: usize) -> Option<u16> {
+        let bytes = self.bytes(offset)?;
+        Some(match self.big_endian {
+            true => u16::from_be_bytes(bytes),
+            false => u16::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn u32_at(&self, offset: usize) -> Option<u32> {
+        let bytes = self.bytes(offset)?;
+        Some(match self.big_endian {
+            true => u32::from_be_bytes(bytes),
+            false => u32::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn u64_at(&self, offset: usize) -> Option<u64> {
+        let bytes = self.bytes(offset)?;
+        Some(match self.big_endian {
+            true => u64::from_be_bytes(bytes),
+            false => u64::from_le_bytes(bytes),
+        })
+    }
+
+    /// The NUL-terminated string at `offset`, lossily decoded.
+    pub fn string_at(&self, offset: usize) -> Option<String> {
+        let bytes = self.data.get(offset..)?;
+        let end = bytes.iter().position(|&b| b == 0)?;
+        Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    }
+}
+
+/// A program header: `p_type`, `p_offset`, `p_vaddr` and `p_filesz`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub kind: u32,
+    pub offset: u64,
+    pub address: u64,
+    pub size: u64,
+}
+
+/// A section header: `sh_type`, `sh_offset`, `sh_size` and `sh_link`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section {
+    pub kind: u32,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+}
+
+/// An ELF file of either class and byte order.
+#[derive(Clone, Copy)]
+pub struct Elf<'a> {
+    reader: Reader<'a>,
+    is_64: bool,
+}
+
+impl<'a> Elf<'a> {
+    /// `None` if `data` is no ELF file.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        if !data.starts_with(b"\x7fELF") {
+            return None;
+        }
+        let is_64 = match *data.get(4)? {
+            1 => false,
+            2 => true,
+            _ => return None,
+        };
+        let reader = match *data.get(5)? {
+            1 => Reader::little_endian(data),
+            2 => Reader::big_endian(data),
+            _ => return None,
+        };
+        Some(Elf { reader, is_64 })
+    }
+
+    /// The reader for the structures of the file that are the same in both classes, like the
+    /// symbol versions.
+    pub fn reader(&self) -> Reader<'a> {
+        self.reader
+    }
+
+    pub fn is_64(&self) -> bool {
+        self.is_64
+    }
+
+    /// `e_machine`, e.g. `0x3e` for x86_64
+    pub fn machine(&self) -> Option<u16> {
+        self.reader.u16_at(0x12)
+    }
+
+    /// A field that is 4 bytes wide in 32-bit files and 8 bytes in 64-bit ones.
+    fn word_at(&self, offset: usize) -> Option<u64> {
+        match self.is_64 {
+            true => self.reader.u64_at(offset),
+            false => self.reader.u32_at(offset).map(u64::from),
+        }
+    }
+
+    /// The `count` entries of `size` bytes from the file offset `start`.
+    fn table(start: u64, size: u16, count: u16) -> impl Iterator<Item = usize> {
+        (0..count as usize).map(move |i| start as usize + i * size as usize)
+    }
+
+    pub fn segments(&self) -> Option<Vec<Segment>> {
+        let (phoff, phentsize, phnum) = match self.is_64 {
+            true => (self.reader.u64_at(0x20)?, 0x36, 0x38),
+            false => (self.reader.u32_at(0x1c)?.into(), 0x2a, 0x2c),
+        };
+        let (phentsize, phnum) = (self.reader.u16_at(phentsize)?, self.reader.u16_at(phnum)?);
+        // Elf64_Phdr { p_type: u32, p_flags: u32, p_offset: u64, p_vaddr: u64, p_paddr: u64,
+        // p_filesz: u64, .. }, Elf32_Phdr { p_type: u32, p_offset: u32, p_vaddr: u32,
+        // p_paddr: u32, p_filesz: u32, .. }
+        let fields = match self.is_64 {
+            true => [8, 16, 32],
+            false => [4, 8, 16],
+        };
+        Self::table(phoff, phentsize, phnum)
+            .map(|header| {
+                Some(Segment {
+                    kind: self.reader.u32_at(header)?,
+                    offset: self.word_at(header + fields[0])?,
+                    address: self.word_at(header + fields[1])?,
+                    size: self.word_at(header + fields[2])?,
+                })
+            })
+            .collect()
+    }
+
+    pub fn sections(&self) -> Option<Vec<Section>> {
+        let (shoff, shentsize, shnum) = match self.is_64 {
+            true => (self.reader.u64_at(0x28)?, 0x3a, 0x3c),
+            false => (self.reader.u32_at(0x20)?.into(), 0x2e, 0x30),
+        };
+        let (shentsize, shnum) = (self.reader.u16_at(shentsize)?, self.reader.u16_at(shnum)?);
+        // Elf64_Shdr { sh_name: u32, sh_type: u32, sh_flags: u64, sh_addr: u64, sh_offset: u64,
+        // sh_size: u64, sh_link: u32, .. }, the 32-bit one with u32 for the u64
+        let fields = match self.is_64 {
+            true => [24, 32, 40],
+            false => [16, 20, 24],
+        };
+        Self::table(shoff, shentsize, shnum)
+            .map(|header| {
+                Some(Section {
+                    kind: self.reader.u32_at(header + 4)?,
+                    offset: self.word_at(header + fields[0])?,
+                    size: self.word_at(header + fields[1])?,
+                    link: self.reader.u32_at(header + fields[2])?,
+                })
+            })
+            .collect()
+    }
+
+    /// The file offset of a virtual address, through the loaded segments.
+    pub fn file_offset(&self, address: u64) -> Option<usize> {
+        self.segments()?
+            .iter()
+            .filter(|segment| segment.kind == PT_LOAD)
+            .find(|segment| (segment.address..segment.address + segment.size).contains(&address))
+            .map(|segment| (address - segment.address + segment.offset) as usize)
+    }
+
+    /// The `(d_tag, d_val)` entries of the dynamic section, up to `DT_NULL`.
+    pub fn dynamic(&self) -> Option<Vec<(u64, u64)>> {
+        let segment = self
+            .segments()?
+            .into_iter()
+            .find(|segment| segment.kind == PT_DYNAMIC)?;
+        let size = if self.is_64 { 16 } else { 8 };
+        let mut entries = Vec::new();
+        let mut entry = segment.offset as usize;
+        loop {
+            let tag = self.word_at(entry)?;
+            if tag == DT_NULL {
+                return Some(entries);
+            }
+            entries.push((tag, self.word_at(entry + size / 2)?));
+            entry += size;
+        }
+    }
+
+    /// The names of the symbols the file defines in its dynamic symbol table.
+    pub fn defined_symbols(&self) -> Option<BTreeSet<String>> {
+        let sections = self.sections()?;
+        let dynsym = sections.iter().find(|section| section.kind == SHT_DYNSYM)?;
+        let strtab = sections.get(dynsym.link as usize)?.offset as usize;
+
+        // Elf64_Sym { st_name: u32, st_info: u8, st_other: u8, st_shndx: u16, .. }, 24 bytes,
+        // Elf32_Sym { st_name: u32, st_value: u32, st_size: u32, st_info: u8, st_other: u8,
+        // st_shndx: u16 }, 16 bytes
+        let (size, shndx) = if self.is_64 { (24, 6) } else { (16, 14) };
+        let start = dynsym.offset as usize;
+        let mut symbols = BTreeSet::new();
+        for symbol in (start..start + dynsym.size as usize).step_by(size) {
+            if self.reader.u16_at(symbol + shndx)? == SHN_UNDEF {
+                continue;
+            }
+            let name = strtab + self.reader.u32_at(symbol)? as usize;
+            symbols.insert(self.reader.string_at(name)?);
+        }
+        Some(symbols)
+    }
+}
+
+/// A small shared library for the tests: it needs `libm.so.6` with the symbol version
+/// `GLIBC_2.29`, has the runpath `$ORIGIN/../lib`, defines `SCIPcreate` and imports `malloc`.
+#[cfg(test)]
+pub(crate) fn fixture(is_64: bool, big_endian: bool) -> Vec<u8> {
+    struct Writer {
+        data: Vec<u8>,
+        is_64: bool,
+        big_endian: bool,
+    }
+    impl Writer {
+        fn put(&mut self, offset: usize, bytes: &[u8]) {
+            if self.data.len() < offset + bytes.len() {
+                self.data.resize(offset + bytes.len(), 0);
+            }
+            self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        }
+        fn u16(&mut self, offset: usize, value: u16) {
+            match self.big_endian {
+                true => self.put(offset, &value.to_be_bytes()),
+                false => self.put(offset, &value.to_le_bytes()),
+            }
+        }
+        fn u32(&mut self, offset: usize, value: u32) {
+            match self.big_endian {
+                true => self.put(offset, &value.to_be_bytes()),
+                false => self.put(offset, &value.to_le_bytes()),
+            }
+        }
+        fn word(&mut self, offset: usize, value: u64) {
+            match (self.is_64, self.big_endian) {
+                (true, true) => self.put(offset, &value.to_be_bytes()),
+                (true, false) => self.put(offset, &value.to_le_bytes()),
+                (false, _) => self.u32(offset, value as u32),
+            }
+        }
+    }
+
+    // the whole file is loaded at this address
+    const BASE: u64 = 0x1000;
+    const PHDRS: usize = 0x40;
+    const STRTAB: usize = 0x100;
+    const DYNSYM: usize = 0x180;
+    const VERNEED: usize = 0x200;
+    const DYNAMIC: usize = 0x240;
+    const SHDRS: usize = 0x300;
+    const STRINGS: &[u8] = b"\0libm.so.6\0$ORIGIN/../lib\0SCIPcreate\0malloc\0GLIBC_2.29\0";
+    let string = |name: &str| {
+        let needle = [name.as_bytes(), b"\0"].concat();
+        STRINGS
+            .windows(needle.len())
+            .position(|w| w == needle)
+            .unwrap() as u64
+    };
+
+    let mut w = Writer {
+        data: Vec::new(),
+        is_64,
+        big_endian,
+    };
+    w.put(0, b"\x7fELF");
+    w.put(
+        4,
+        &[if is_64 { 2 } else { 1 }, if big_endian { 2 } else { 1 }, 1],
+    );
+    w.u16(0x10, 3); // ET_DYN
+    w.u16(0x12, if is_64 { 0x3e } else { 0x03 });
+    let (phoff, shoff, phentsize, shentsize) = match is_64 {
+        true => (0x20, 0x28, 56, 64),
+        false => (0x1c, 0x20, 32, 40),
+    };
+    w.word(phoff, PHDRS as u64);
+    w.word(shoff, SHDRS as u64);
+    let counts = if is_64 { 0x36 } else { 0x2a };
+    w.u16(counts, phentsize);
+    w.u16(counts + 2, 2);
+    w.u16(counts + 4, shentsize);
+    w.u16(counts + 6, 3);
+
+    // program headers: the file as one PT_LOAD segment, and PT_DYNAMIC
+    let phdr_fields = if is_64 { [8, 16, 32] } else { [4, 8, 16] };
+    let file_size = (SHDRS + 3 * shentsize as usize) as u64;
+    for (i, (kind, offset, size)) in [(PT_LOAD, 0, file_size), (PT_DYNAMIC, DYNAMIC as u64, 0)]
+        .into_iter()
+        .enumerate()
+    {
+        let header = PHDRS + i * phentsize as usize;
+        w.u32(header, kind);
+        w.word(header + phdr_fields[0], offset);
+        w.word(header + phdr_fields[1], BASE + offset);
+        w.word(header + phdr_fields[2], size);
+    }
+
+    w.put(STRTAB, STRINGS);
+
+    // symbols: the null symbol, `SCIPcreate` in section 1, the undefined `malloc`
+    let (sym_size, shndx) = if is_64 { (24, 6) } else { (16, 14) };
+    for (i, (name, section)) in [(0, 0), (string("SCIPcreate"), 1), (string("malloc"), 0)]
+        .into_iter()
+        .enumerate()
+    {
+        w.u32(DYNSYM + i * sym_size, name as u32);
+        w.u16(DYNSYM + i * sym_size + shndx, section);
+    }
+
+    // Verneed { vn_version, vn_cnt, vn_file, vn_aux, vn_next } + Vernaux { vna_hash, vna_flags,
+    // vna_other, vna_name, vna_next }
+    w.u16(VERNEED, 1);
+    w.u16(VERNEED + 2, 1);
+    w.u32(VERNEED + 4, string("libm.so.6") as u32);
+    w.u32(VERNEED + 8, 16);
+    w.u32(VERNEED + 16 + 8, string("GLIBC_2.29") as u32);
+
+    let entries = [
+        (1, string("libm.so.6")),             // DT_NEEDED
+        (29, string("$ORIGIN/../lib")),       // DT_RUNPATH
+        (5, BASE + STRTAB as u64),            // DT_STRTAB
+        (0x6fff_fffe, BASE + VERNEED as u64), // DT_VERNEED
+        (0x6fff_ffff, 1),                     // DT_VERNEEDNUM
+        (DT_NULL, 0),
+    ];
+    let dyn_size = if is_64 { 16 } else { 8 };
+    for (i, (tag, value)) in entries.into_iter().enumerate() {
+        w.word(DYNAMIC + i * dyn_size, tag);
+        w.word(DYNAMIC + i * dyn_size + dyn_size / 2, value);
+    }
+
+    // section headers: the null section, .dynsym linked to .dynstr
+    let shdr_fields = if is_64 { [24, 32, 40] } else { [16, 20, 24] };
+    for (i, (kind, offset, size, link)) in [
+        (SHT_DYNSYM, DYNSYM, 3 * sym_size, 2),
+        (3, STRTAB, STRINGS.len(), 0), // SHT_STRTAB
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let header = SHDRS + (i + 1) * shentsize as usize;
+        w.u32(header + 4, kind);
+        w.word(header + shdr_fields[0], offset as u64);
+        w.word(header + shdr_fields[1], size as u64);
+        w.u32(header + shdr_fields[2], link);
+    }
+    w.data.resize(file_size as usize, 0);
+    w.data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VARIANTS: [(bool, bool); 4] =
+        [(true, false), (true, true), (false, false), (false, true)];
+
+    #[test]
+    fn reads_both_byte_orders() {
+        let data = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, b'a', 0];
+        let le = Reader::little_endian(&data);
+        let be = Reader::big_endian(&data);
+        assert_eq!(le.u16_at(0), Some(0x3412));
+        assert_eq!(be.u16_at(0), Some(0x1234));
+        assert_eq!(le.u32_at(0), Some(0x7856_3412));
+        assert_eq!(be.u32_at(0), Some(0x1234_5678));
+        assert_eq!(be.u64_at(0), Some(0x1234_5678_9abc_def0));
+        assert_eq!(le.string_at(8).as_deref(), Some("a"));
+        // out of range
+        assert_eq!(le.u64_at(4), None);
+        assert_eq!(le.u32_at(usize::MAX), None);
+        assert_eq!(le.string_at(10), None);
+    }
+
+    #[test]
+    fn reads_every_class_and_byte_order() {
+        for (is_64, big_endian) in VARIANTS {
+            let data = fixture(is_64, big_endian);
+            let elf = Elf::parse(&data).unwrap();
+            let variant = format!("64-bit: {is_64}, big-endian: {big_endian}");
+            assert_eq!(elf.is_64(), is_64);
+            assert_eq!(
+                elf.machine(),
+                Some(if is_64 { 0x3e } else { 0x03 }),
+                "{variant}"
+            );
+
+            let segments = elf.segments().unwrap();
+            assert_eq!(segments.len(), 2, "{variant}");
+            assert_eq!(elf.file_offset(0x1100), Some(0x100), "{variant}");
+            assert_eq!(elf.file_offset(0x10_0000), None, "{variant}");
+
+            let dynamic = elf.dynamic().unwrap();
+            assert_eq!(dynamic.len(), 5, "{variant}");
+            assert_eq!(dynamic[0], (1, 1), "{variant}");
+            assert_eq!(dynamic[2], (5, 0x1100), "{variant}");
+
+            assert_eq!(
+                elf.defined_symbols().unwrap(),
+                BTreeSet::from(["SCIPcreate".to_string()]),
+                "{variant}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_and_broken_files() {
+        assert!(Elf::parse(b"MZ\x90\0").is_none());
+        assert!(Elf::parse(b"\x7fELF\x03\x01").is_none());
+        assert!(Elf::parse(b"\x7fELF").is_none());
+
+        let mut data = fixture(true, false);
+        data.truncate(0x2c0);
+        let elf = Elf::parse(&data).unwrap();
+        assert!(elf.dynamic().is_some());
+        assert!(elf.sections().is_none());
+        assert!(elf.defined_symbols().is_none());
+    }
+}
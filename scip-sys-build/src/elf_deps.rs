@@ -0,0 +1,274 @@
+//! Check on Linux that the shared libraries libscip needs are installed, so that a missing
+//! runtime (libgfortran for the bundled Ipopt/MUMPS, libgomp, libtbb, ...) or one that is too old
+//! (glibc, libstdc++) is reported when building instead of when the binary starts.
+//!
+//! The `NEEDED` entries and the required symbol versions are read from the ELF dynamic section of
+//! libscip, and each library is looked up like the loader would: in the rpath of the library
+//! needing it, in the install's lib dir, in `LD_LIBRARY_PATH` and in the system directories.
+//! The libraries found in the install's lib dir are checked the same way.
+
+use crate::elf::Elf;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DT_NEEDED: u64 = 1;
+const DT_STRTAB: u64 = 5;
+const DT_RPATH: u64 = 15;
+const DT_RUNPATH: u64 = 29;
+const DT_VERDEF: u64 = 0x6fff_fffc;
+const DT_VERDEFNUM: u64 = 0x6fff_fffd;
+const DT_VERNEED: u64 = 0x6fff_fffe;
+const DT_VERNEEDNUM: u64 = 0x6fff_ffff;
+
+/// The dynamic section of a shared library.
+#[derive(Default)]
+struct Dynamic {
+    needed: Vec<String>,
+    /// `DT_RPATH` and `DT_RUNPATH` entries, with `$ORIGIN` resolved
+    search_path: Vec<PathBuf>,
+    /// The symbol versions needed from each library, e.g. `libstdc++.so.6` -> `GLIBCXX_3.4.29`
+    version_needs: BTreeMap<String, BTreeSet<String>>,
+    /// The symbol versions the library defines
+    version_defs: BTreeSet<String>,
+}
+
+/// Check the dependencies of the libscip in `lib_dir` and warn about the missing ones. Only done
+/// when building natively for Linux.
+pub fn check(lib_dir: &Path) {
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os != "linux" || env::var("HOST").ok() != env::var("TARGET").ok() {
+        return;
+    }
+    let Some(libscip) = ["libscip.so", "libscip.so.*"]
+        .iter()
+        .find_map(|name| crate::paths::find(lib_dir, name).into_iter().next())
+    else {
+        return;
+    };
+
+    let system_dirs = system_library_dirs();
+    let mut queue = VecDeque::from([libscip]);
+    let mut checked = BTreeSet::new();
+    let mut missing: BTreeMap<String, String> = BTreeMap::new();
+    let mut outdated: Vec<String> = Vec::new();
+    while let Some(library) = queue.pop_front() {
+        if !checked.insert(library.clone()) {
+            continue;
+        }
+        let Some(dynamic) = read_dynamic(&library) else {
+            continue;
+        };
+        let name = library.file_name().unwrap().to_string_lossy().into_owned();
+
+        for needed in &dynamic.needed {
+            let found = dynamic
+                .search_path
+                .iter()
+                .chain([lib_dir.to_path_buf()].iter())
+                .chain(system_dirs.iter())
+                .map(|dir| dir.join(needed))
+                .find(|path| path.exists());
+            let Some(found) = found else {
+                missing.entry(needed.clone()).or_insert(name.clone());
+                continue;
+            };
+            // follow the libraries that came with SCIP, the system ones are consistent
+            if found.starts_with(lib_dir) {
+                queue.push_back(found.clone());
+            }
+
+            let Some(versions) = dynamic.version_needs.get(needed) else {
+                continue;
+            };
+            let defined = read_dynamic(&found).unwrap_or_default().version_defs;
+            if defined.is_empty() {
+                continue;
+            }
+            for version in versions.difference(&defined) {
+                outdated.push(format!(
+                    "{} lacks {version}, which {name} needs",
+                    found.display()
+                ));
+            }
+        }
+    }
+
+    for (library, needed_by) in &missing {
+        crate::report::warn(format!(
+            "SCIP needs {library} (through {needed_by}), which is not installed; \
+             binaries using SCIP will fail to start.{}",
+            install_hint(library)
+        ));
+    }
+    for message in &outdated {
+        crate::report::warn(format!(
+            "{message}; binaries using SCIP will fail to start. The library is \
+             older than the one SCIP was built against, update it or use a SCIP built for this system."
+        ));
+    }
+}
+
+/// A hint at the package providing a library that is commonly missing.
+fn install_hint(library: &str) -> &'static str {
+    let hints = [
+        (
+            "libgfortran",
+            " Install the GNU Fortran runtime, e.g. `apt install libgfortran5` or `dnf install libgfortran`.",
+        ),
+        (
+            "libgomp",
+            " Install the GNU OpenMP runtime, e.g. `apt install libgomp1` or `dnf install libgomp`.",
+        ),
+        (
+            "libquadmath",
+            " Install the GCC quad-precision library, e.g. `apt install libquadmath0`.",
+        ),
+        (
+            "libtbb",
+            " Install oneTBB, e.g. `apt install libtbb12` or `dnf install tbb`.",
+        ),
+        (
+            "libstdc++",
+            " Install the GNU C++ runtime, e.g. `apt install libstdc++6`.",
+        ),
+    ];
+    hints
+        .iter()
+        .find(|(prefix, _)| library.starts_with(prefix))
+        .map_or("", |(_, hint)| hint)
+}
+
+/// The directories the loader searches after the rpath: `LD_LIBRARY_PATH`, those listed in
+/// `/etc/ld.so.conf` and the default ones.
+fn system_library_dirs() -> Vec<PathBuf> {
+    println!("cargo:rerun-if-env-changed=LD_LIBRARY_PATH");
+    let mut dirs: Vec<PathBuf> = env::var_os("LD_LIBRARY_PATH")
+        .map(|paths| env::split_paths(&paths).collect())
+        .unwrap_or_default();
+    read_ld_so_conf(Path::new("/etc/ld.so.conf"), &mut dirs, 0);
+
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    for dir in ["/lib", "/usr/lib", "/lib64", "/usr/lib64"] {
+        dirs.push(PathBuf::from(dir));
+        dirs.push(PathBuf::from(format!("{dir}/{arch}-linux-gnu")));
+    }
+    dirs
+}
+
+/// Add the directories of an `ld.so.conf` file, following its `include` lines.
+fn read_ld_so_conf(path: &Path, dirs: &mut Vec<PathBuf>, depth: usize) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if let Some(pattern) = line.strip_prefix("include") {
+            let pattern = pattern.trim();
+            let pattern = if pattern.starts_with('/') {
+                PathBuf::from(pattern)
+            } else {
+                path.parent().unwrap_or(Path::new("/")).join(pattern)
+            };
+            let Some(pattern) = pattern.to_str() else {
+                continue;
+            };
+            for included in glob::glob(pattern).into_iter().flatten().flatten() {
+                if depth < 4 {
+                    read_ld_so_conf(&included, dirs, depth + 1);
+                }
+            }
+        } else if !line.is_empty() {
+            dirs.push(PathBuf::from(line));
+        }
+    }
+}
+
+/// Read the dynamic section of an ELF file. `None` for anything else.
+fn read_dynamic(path: &Path) -> Option<Dynamic> {
+    let data = fs::read(path).ok()?;
+    let elf = Elf::parse(&data)?;
+    let entries = elf.dynamic()?;
+    let value = |tag: u64| entries.iter().find(|(t, _)| *t == tag).map(|(_, v)| *v);
+    let strtab = elf.file_offset(value(DT_STRTAB)?)?;
+    let reader = elf.reader();
+    let string = |offset: u32| reader.string_at(strtab + offset as usize);
+
+    let origin = path.parent().unwrap_or(Path::new("."));
+    let mut dynamic = Dynamic::default();
+    for &(tag, value) in &entries {
+        match tag {
+            DT_NEEDED => dynamic.needed.extend(string(value as u32)),
+            DT_RPATH | DT_RUNPATH => {
+                for dir in string(value as u32).unwrap_or_default().split(':') {
+                    let dir = dir.replace("${ORIGIN}", "$ORIGIN");
+                    dynamic.search_path.push(match dir.strip_prefix("$ORIGIN") {
+                        Some(rest) => origin.join(rest.trim_start_matches('/')),
+                        None => PathBuf::from(dir),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Elf_Verneed { vn_version: u16, vn_cnt: u16, vn_file: u32, vn_aux: u32, vn_next: u32 }
+    // with Elf_Vernaux { vna_hash: u32, vna_flags: u16, vna_other: u16, vna_name: u32, vna_next: u32 },
+    // the same in both classes
+    if let (Some(address), Some(count)) = (value(DT_VERNEED), value(DT_VERNEEDNUM)) {
+        let mut need = elf.file_offset(address)?;
+        for _ in 0..count {
+            let file = string(reader.u32_at(need + 4)?)?;
+            let mut aux = need + reader.u32_at(need + 8)? as usize;
+            for _ in 0..reader.u16_at(need + 2)? {
+                let version = string(reader.u32_at(aux + 8)?)?;
+                dynamic
+                    .version_needs
+                    .entry(file.clone())
+                    .or_default()
+                    .insert(version);
+                aux += reader.u32_at(aux + 12)? as usize;
+            }
+            need += reader.u32_at(need + 12)? as usize;
+        }
+    }
+
+    // Elf_Verdef { vd_version: u16, vd_flags: u16, vd_ndx: u16, vd_cnt: u16, vd_hash: u32,
+    // vd_aux: u32, vd_next: u32 } with Elf_Verdaux { vda_name: u32, vda_next: u32 }
+    if let (Some(address), Some(count)) = (value(DT_VERDEF), value(DT_VERDEFNUM)) {
+        let mut def = elf.file_offset(address)?;
+        for _ in 0..count {
+            let aux = def + reader.u32_at(def + 12)? as usize;
+            dynamic.version_defs.extend(string(reader.u32_at(aux)?));
+            def += reader.u32_at(def + 16)? as usize;
+        }
+    }
+
+    Some(dynamic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_dependencies_of_a_library() {
+        let dir = tempfile::tempdir().unwrap();
+        for (is_64, big_endian) in [(true, false), (true, true), (false, false), (false, true)] {
+            let path = dir.path().join("lib").join("libscip.so");
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, crate::elf::fixture(is_64, big_endian)).unwrap();
+
+            let dynamic = read_dynamic(&path).unwrap();
+            assert_eq!(dynamic.needed, ["libm.so.6"]);
+            assert_eq!(dynamic.search_path, [dir.path().join("lib").join("../lib")]);
+            assert_eq!(
+                dynamic.version_needs["libm.so.6"],
+                BTreeSet::from(["GLIBC_2.29".to_string()])
+            );
+            assert!(dynamic.version_defs.is_empty());
+        }
+        assert!(read_dynamic(&dir.path().join("missing.so")).is_none());
+    }
+}
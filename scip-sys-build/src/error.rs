@@ -0,0 +1,94 @@
+//! The error of a build script.
+//!
+//! A `BuildError` says what failed, why, where SCIP was looked for and what the user can do about
+//! it. `main` returns it, and cargo prints it through its `Debug` implementation, which is the
+//! same formatted diagnostic as `Display`, headed by the name of the package being built.
+
+use std::error::Error;
+use std::fmt;
+
+pub struct BuildError {
+    message: String,
+    source: Option<Box<dyn Error>>,
+    /// The locations that were probed for SCIP, with the result, see `report::probe`
+    attempts: Vec<(String, String)>,
+    /// What the user can do to fix the build
+    remedies: Vec<String>,
+}
+
+pub type Result<T, E = BuildError> = std::result::Result<T, E>;
+
+impl BuildError {
+    pub fn new(message: impl Into<String>) -> Self {
+        BuildError {
+            message: message.into(),
+            source: None,
+            attempts: Vec::new(),
+            remedies: Vec::new(),
+        }
+    }
+
+    /// The error that caused this one.
+    pub fn caused_by(mut self, source: impl Into<Box<dyn Error>>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Include the locations probed so far.
+    pub fn with_attempts(mut self) -> Self {
+        self.attempts = crate::report::attempts();
+        self
+    }
+
+    /// Add a step the user can take to fix the build.
+    pub fn remedy(mut self, remedy: impl Into<String>) -> Self {
+        self.remedies.push(remedy.into());
+        self
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let package = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "scip-sys".to_string());
+        write!(f, "{package}: {}", self.message)?;
+        let mut source = self.source.as_deref();
+        while let Some(error) = source {
+            write!(f, "\n  caused by: {error}")?;
+            source = error.source();
+        }
+        if !self.attempts.is_empty() {
+            write!(f, "\n\nLooked for SCIP in:")?;
+            for (location, result) in &self.attempts {
+                write!(f, "\n  - {location}: {result}")?;
+            }
+        }
+        if !self.remedies.is_empty() {
+            write!(f, "\n\nTo fix this:")?;
+            for remedy in &self.remedies {
+                write!(f, "\n  - {remedy}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref()
+    }
+}
+
+impl From<Box<dyn Error>> for BuildError {
+    fn from(error: Box<dyn Error>) -> Self {
+        match error.downcast::<BuildError>() {
+            Ok(error) => *error,
+            Err(error) => BuildError::new(error.to_string()),
+        }
+    }
+}
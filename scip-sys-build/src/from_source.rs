@@ -0,0 +1,87 @@
+//! Building SCIP from its source release with CMake.
+
+use crate::download::{download_and_extract_tar_gz, record_sha256};
+use crate::error::{BuildError, Result};
+use crate::{report, Installation};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Download the source release at `url`, a `.tar.gz` checked against `sha256` when that is given,
+/// and extract it into `out_dir`, unless an earlier build did. `directory` is the directory the
+/// archive unpacks to, which is returned. The checksum of the archive is kept for
+/// `download::archive_sha256`.
+pub fn download(
+    url: &str,
+    sha256: Option<&str>,
+    out_dir: &Path,
+    directory: &str,
+) -> Result<PathBuf> {
+    let source = out_dir.join(directory);
+    if source.exists() {
+        report::note("SCIP was previously downloaded, skipping download");
+        return Ok(source);
+    }
+    let archive_sha256 = download_and_extract_tar_gz(url, out_dir, sha256).map_err(|e| {
+        // a partial extraction would be taken for a finished download by the next build
+        let _ = std::fs::remove_dir_all(&source);
+        match e.downcast::<BuildError>() {
+            // a checksum mismatch
+            Ok(e) => *e,
+            Err(e) => BuildError::new(format!("failed to download and extract {url}"))
+                .caused_by(e)
+                .remedy("Check the network connection and any HTTP(S) proxy settings"),
+        }
+    })?;
+    record_sha256(&source, &archive_sha256)?;
+    Ok(source)
+}
+
+/// Build the SCIP sources in `source` as a static library and install it into `OUT_DIR`, unless
+/// an earlier build did. The CMake configuration leaves out the optional dependencies (Ipopt,
+/// ZIMPL, GMP, PaPILO, ...); `configure` can change it further.
+pub fn build(source: &Path, configure: impl FnOnce(&mut cmake::Config)) -> Result<Installation> {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    if out_dir.join("lib").exists() {
+        report::note("SCIP was previously compiled, skipping compilation");
+        return Ok(Installation::in_root(out_dir));
+    }
+
+    let mut config = cmake::Config::new(source);
+    config
+        .define("IPOPT", "OFF")
+        .define("ZIMPL", "OFF")
+        .define("GMP", "OFF")
+        .define("READLINE", "OFF")
+        .define("BOOST", "OFF")
+        .define("AUTOBUILD", "OFF")
+        .define("PAPILO", "OFF")
+        .define("SYM", "snauty")
+        .define("ZLIB", "OFF")
+        .define("SHARED", "OFF")
+        .define("GCG", "OFF")
+        .define("UG", "OFF")
+        .define("SANITIZE_ADDRESS", "OFF")
+        .define("SANITIZE_MEMORY", "OFF")
+        .define("SANITIZE_UNDEFINED", "OFF")
+        .define("SANITIZE_THREAD", "OFF");
+    configure(&mut config);
+
+    // the cmake crate panics when CMake fails, after printing its output
+    let root = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || config.build()))
+        .map_err(|panic| {
+            let reason = panic
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_default();
+            BuildError::new(format!(
+                "failed to build SCIP from {} with CMake",
+                source.display()
+            ))
+            .caused_by(reason)
+            .remedy(
+                "Install CMake and a C/C++ compiler, see the CMake output above for what failed",
+            )
+        })?;
+    Ok(Installation::in_root(root))
+}
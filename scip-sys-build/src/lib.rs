@@ -0,0 +1,75 @@
+//! Build-script helpers to find, download, build and link SCIP, shared by `scip-sys` and the
+//! `-sys` crates of libraries built on SCIP (GCG, plugins) that link it themselves.
+//!
+//! ```no_run
+//! use scip_sys_build::{discover, link, report};
+//!
+//! fn main() -> scip_sys_build::Result<()> {
+//!     let scip = discover::from_env(&discover::ENV_VARS)
+//!         .or_else(|| discover::system(&discover::SYSTEM_INCLUDE_DIRS))
+//!         .ok_or_else(|| scip_sys_build::BuildError::new("SCIP not found").with_attempts())?;
+//!     if !scip.system {
+//!         link::search(&scip.root)?;
+//!     }
+//!     let libs = link::libs(&link::scip_libs(false));
+//!     link::metadata(&scip.include_dir, &libs)?;
+//!     report::finish(std::env::var_os("OUT_DIR").unwrap().as_ref())?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! What the helpers find and decide goes into the build report (`report`), and their errors are
+//! `BuildError`s that say what the user can do about them. They follow the same environment
+//! variables as `scip-sys` (`SCIPOPTDIR`, `SCIP_SYS_RPATH`, `SCIP_SYS_VERBOSE`, ...), so that one
+//! setting applies to every crate linking SCIP.
+//!
+//! Downloading the precompiled releases (`bundled::download`) needs the `download` feature,
+//! building SCIP with CMake (`from_source`) the `from-source` feature.
+
+pub mod bundled;
+pub mod discover;
+#[cfg(feature = "download")]
+pub mod download;
+pub mod elf;
+pub mod elf_deps;
+pub mod error;
+#[cfg(feature = "from-source")]
+pub mod from_source;
+pub mod link;
+pub mod manifest;
+pub mod paths;
+pub mod report;
+pub mod rpath;
+#[cfg(feature = "download")]
+pub mod vendor;
+pub mod version;
+
+pub use error::{BuildError, Result};
+pub use version::ScipVersion;
+
+use std::path::PathBuf;
+
+/// A SCIP installation that was found, downloaded or built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Installation {
+    /// The directory containing `include/` and `lib/`, e.g. `/usr` for the headers in
+    /// `/usr/include`
+    pub root: PathBuf,
+    /// The directory containing `scip/scip.h`
+    pub include_dir: PathBuf,
+    /// Whether it was found in the system's include directories, where the linker looks for
+    /// libscip by itself
+    pub system: bool,
+}
+
+impl Installation {
+    /// The installation in `root`, with the headers in `<root>/include`.
+    pub fn in_root(root: impl Into<PathBuf>) -> Installation {
+        let root = root.into();
+        Installation {
+            include_dir: root.join("include"),
+            root,
+            system: false,
+        }
+    }
+}
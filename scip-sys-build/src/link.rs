@@ -0,0 +1,191 @@
+//! The `cargo:` directives that link SCIP, and the metadata a crate with `links = "..."` publishes
+//! about it to the build scripts of dependent crates.
+
+use crate::error::{BuildError, Result};
+use crate::{paths, report, rpath};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The `lib/` directory of the installation in `root`.
+pub fn lib_dir(root: &Path) -> Result<PathBuf> {
+    let lib_dir = root.join("lib");
+    if !lib_dir.exists() {
+        return Err(BuildError::new(format!(
+            "{} has no lib directory, so it is not a SCIP installation",
+            root.display()
+        ))
+        .with_attempts()
+        .remedy("Point SCIPOPTDIR to the directory containing SCIP's include/ and lib/"));
+    }
+    Ok(lib_dir)
+}
+
+/// Emit the `cargo:` link-search / rpath directives for the installation in `root` (one
+/// containing `lib/` and `include/`). The rpath follows `SCIP_SYS_RPATH` (see `rpath`).
+pub fn search(root: &Path) -> Result<()> {
+    let lib_dir = lib_dir(root)?;
+    report::link(format!("rustc-link-search={}", paths::utf8(&lib_dir)?));
+    #[cfg(windows)]
+    let bin_dir = Some(root.join("bin"));
+    #[cfg(not(windows))]
+    let bin_dir: Option<PathBuf> = None;
+    if let Some(bin_dir) = &bin_dir {
+        report::link(format!("rustc-link-search={}", paths::utf8(bin_dir)?));
+    }
+
+    rpath::emit(&lib_dir, bin_dir.as_deref())
+}
+
+/// The libraries to link for SCIP: libscip itself, plus the C++ runtime and SoPlex for a static
+/// SCIP built from source.
+pub fn scip_libs(built_from_source: bool) -> Vec<&'static str> {
+    let mut libs = Vec::new();
+    #[cfg(windows)]
+    libs.push("libscip");
+    #[cfg(not(windows))]
+    libs.push("scip");
+
+    if built_from_source {
+        let target = env::var("TARGET").unwrap_or_default();
+        let apple = target.contains("apple");
+        let linux = target.contains("linux");
+        let mingw = target.contains("pc-windows-gnu");
+        if apple {
+            libs.push("dylib=c++");
+        } else if linux || mingw {
+            libs.push("dylib=stdc++");
+        }
+
+        #[cfg(windows)]
+        libs.push("libsoplex");
+        #[cfg(not(windows))]
+        libs.push("soplex");
+    }
+    libs
+}
+
+/// Emit a `cargo:rustc-link-lib` directive for each of `libs` (`name` or `kind=name`). Returns
+/// their names.
+pub fn libs<'a>(libs: &[&'a str]) -> Vec<&'a str> {
+    for lib in libs {
+        report::link(format!("rustc-link-lib={lib}"));
+    }
+    libs.iter()
+        .map(|lib| lib.split_once('=').map_or(*lib, |(_, name)| name))
+        .collect()
+}
+
+/// libscip (or its import library) in the installation in `root`: in `lib/`, `lib64/` or the
+/// multiarch directory of a system install (`/usr/lib/x86_64-linux-gnu`).
+pub fn find_library(root: &Path) -> Option<PathBuf> {
+    ["lib/*scip*", "lib64/*scip*", "lib/*/*scip*"]
+        .iter()
+        .flat_map(|pattern| paths::find(root, pattern))
+        .next()
+}
+
+/// Whether the libscip in `lib_dir` of the installation in `root` is a shared library.
+pub fn is_shared(root: &Path, lib_dir: &Path) -> bool {
+    // a DLL lives in `bin/`, next to `lib/` with its import library
+    !paths::find(lib_dir, "lib*scip*.so*").is_empty()
+        || !paths::find(lib_dir, "lib*scip*.dylib").is_empty()
+        || !paths::find(root, "bin/*scip*.dll").is_empty()
+}
+
+/// Publish the SCIP installation with the headers in `include_dir` to the build scripts of
+/// dependent crates, as `DEP_<LINKS>_INCLUDE`, `_ROOT`, `_LIBDIR`, `_STATIC` and `_LINK_LIBS`
+/// (comma separated). Returns the directory containing libscip, if it was found.
+pub fn metadata(include_dir: &Path, link_libs: &[&str]) -> Result<Option<PathBuf>> {
+    let root = include_dir.parent().unwrap_or(include_dir);
+    println!("cargo:include={}", paths::utf8(include_dir)?);
+    report::include_dir(include_dir);
+    println!("cargo:root={}", paths::utf8(root)?);
+
+    let library = find_library(root);
+    if let Some(library) = &library {
+        report::library(library);
+    }
+    let lib_dir = library.and_then(|lib| Some(lib.parent()?.to_path_buf()));
+    let mut shared = false;
+    if let Some(lib_dir) = &lib_dir {
+        println!("cargo:libdir={}", paths::utf8(lib_dir)?);
+        shared = is_shared(root, lib_dir);
+    }
+    println!("cargo:static={}", lib_dir.is_some() && !shared);
+    println!("cargo:link_libs={}", link_libs.join(","));
+    Ok(lib_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn install(files: &[&str]) -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        for file in files {
+            let path = root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn finds_the_library_of_an_installation() {
+        let conda = install(&[
+            "include/scip/scip.h",
+            "lib/libscip.so.10.0",
+            "lib/libscip.so",
+        ]);
+        let found = find_library(conda.path()).unwrap();
+        assert_eq!(found.parent().unwrap(), conda.path().join("lib"));
+        assert!(is_shared(conda.path(), found.parent().unwrap()));
+
+        let multiarch = install(&["include/scip/scip.h", "lib/x86_64-linux-gnu/libscip.a"]);
+        let found = find_library(multiarch.path()).unwrap();
+        assert_eq!(
+            found,
+            multiarch.path().join("lib/x86_64-linux-gnu/libscip.a")
+        );
+        assert!(!is_shared(multiarch.path(), found.parent().unwrap()));
+
+        let windows = install(&["lib/libscip.lib", "bin/libscip.dll"]);
+        let found = find_library(windows.path()).unwrap();
+        assert!(is_shared(windows.path(), found.parent().unwrap()));
+
+        let headers_only = install(&["include/scip/scip.h"]);
+        assert_eq!(find_library(headers_only.path()), None);
+    }
+
+    #[test]
+    fn publishes_the_library_directory() {
+        let root = install(&["include/scip/scip.h", "lib64/libscip.a"]);
+        let lib_dir = metadata(&root.path().join("include"), &["scip"]).unwrap();
+        assert_eq!(lib_dir, Some(root.path().join("lib64")));
+
+        let headers_only = install(&["include/scip/scip.h"]);
+        let lib_dir = metadata(&headers_only.path().join("include"), &["scip"]).unwrap();
+        assert_eq!(lib_dir, None);
+    }
+
+    #[test]
+    fn an_installation_needs_a_lib_directory() {
+        let root = install(&["lib/libscip.so"]);
+        assert_eq!(lib_dir(root.path()).unwrap(), root.path().join("lib"));
+        let headers_only = install(&["include/scip/scip.h"]);
+        let error = lib_dir(headers_only.path()).unwrap_err().to_string();
+        assert!(error.contains("has no lib directory"), "{error}");
+    }
+
+    #[test]
+    fn link_libs_are_named_without_their_kind() {
+        assert_eq!(
+            libs(&["scip", "dylib=stdc++", "static=soplex"]),
+            ["scip", "stdc++", "soplex"]
+        );
+        let libs = scip_libs(false);
+        assert_eq!(libs.len(), 1);
+        assert!(libs[0].ends_with("scip"));
+    }
+}
@@ -0,0 +1,144 @@
+//! `versions.toml`, the manifest in which `scip-sys` records the SCIP release it is pinned to: the
+//! SCIP, SoPlex and deploy versions, the download URLs, their checksums, the expected API version
+//! and the licenses of the bundled libraries.
+//!
+//! The file only uses `[section]` headers and `key = "string"` or `key = integer` lines, which
+//! are parsed here rather than with a TOML crate.
+
+use crate::error::{BuildError, Result};
+use crate::version::ScipVersion;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A parsed `versions.toml`.
+pub struct Manifest {
+    /// The values by `section.key`, e.g. `scip.version`
+    values: HashMap<String, String>,
+}
+
+impl Manifest {
+    /// Read the manifest at `path`.
+    pub fn read(path: &Path) -> Result<Manifest> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            BuildError::new(format!("failed to read {}", path.display())).caused_by(e)
+        })?;
+        Self::parse(&text)
+            .map_err(|e| BuildError::new(format!("{} is not valid", path.display())).caused_by(e))
+    }
+
+    fn parse(text: &str) -> Result<Manifest> {
+        let mut values = HashMap::new();
+        let mut section = String::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue;
+            }
+            let invalid = || BuildError::new(format!("line {}: `{line}`", number + 1));
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"').ok_or_else(invalid)?,
+                None if value.parse::<u32>().is_ok() => value,
+                None => return Err(invalid()),
+            };
+            values.insert(format!("{section}.{}", key.trim()), value.to_string());
+        }
+        Ok(Manifest { values })
+    }
+
+    /// The value of `key`, `section.name`.
+    pub fn get(&self, key: &str) -> Result<&str> {
+        self.values
+            .get(key)
+            .map(String::as_str)
+            .ok_or_else(|| BuildError::new(format!("versions.toml has no `{key}`")))
+    }
+
+    /// The pinned SCIP release.
+    pub fn scip_version(&self) -> Result<ScipVersion> {
+        let version = self.get("scip.version")?;
+        let numbers: Vec<u32> = version.split('.').filter_map(|n| n.parse().ok()).collect();
+        let [major, minor, patch] = numbers[..] else {
+            return Err(BuildError::new(format!(
+                "versions.toml: scip.version = \"{version}\" is not major.minor.patch"
+            )));
+        };
+        let api = self.get("scip.api_version")?;
+        let api = api.parse().map_err(|_| {
+            BuildError::new(format!(
+                "versions.toml: scip.api_version = {api} is not a number"
+            ))
+        })?;
+        Ok(ScipVersion {
+            major,
+            minor,
+            patch,
+            api,
+        })
+    }
+
+    /// `key` with `{scip}`, `{soplex}` and `{deploy}` replaced by their versions and `{target}` by
+    /// `target`.
+    pub fn expand(&self, key: &str, target: &str) -> Result<String> {
+        Ok(self
+            .get(key)?
+            .replace("{scip}", self.get("scip.version")?)
+            .replace("{soplex}", self.get("soplex.version")?)
+            .replace("{deploy}", self.get("deploy.version")?)
+            .replace("{target}", target))
+    }
+
+    /// The SHA-256 recorded for the archive `name` (a platform tag or `source`), if any.
+    pub fn sha256(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(&format!("sha256.{name}"))
+            .map(String::as_str)
+            .filter(|sha256| !sha256.is_empty())
+    }
+
+    /// The SHA-256 a download of the archive `name` must be checked against. An error if
+    /// `versions.toml` records none.
+    pub fn required_sha256(&self, name: &str) -> Result<&str> {
+        self.sha256(name).ok_or_else(|| {
+            BuildError::new(format!(
+                "versions.toml records no SHA-256 for the `{name}` archive, so its download \
+                 cannot be verified"
+            ))
+            .remedy(format!(
+                "Set `{name}` in the [sha256] section of versions.toml to the checksum of the \
+                 released archive, which `scip-sys-vendor` prints when it downloads it"
+            ))
+        })
+    }
+
+    /// The SPDX license expression recorded for the library `name`, e.g. `scip`, if any.
+    pub fn license(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(&format!("license.{name}"))
+            .map(String::as_str)
+            .filter(|license| !license.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downloads_need_a_checksum() {
+        let manifest = Manifest::parse("[sha256]\nlinux = \"ABC\"\nwindows = \"\"\n").unwrap();
+        assert_eq!(manifest.required_sha256("linux").unwrap(), "ABC");
+        for name in ["windows", "source"] {
+            let error = manifest.required_sha256(name).unwrap_err().to_string();
+            assert!(
+                error.contains(&format!("no SHA-256 for the `{name}` archive")),
+                "{error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Paths that need not be valid UTF-8.
+//!
+//! The SCIP installation may live in a directory whose name is not valid UTF-8. Finding files in
+//! it works on `OsStr` components, and where a path has to be printed for cargo or passed to
+//! clang, which only take UTF-8, a symbolic link with a UTF-8 name in `OUT_DIR` stands in for it.
+
+use crate::error::{BuildError, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The files below `dir` matching `pattern`, a relative path whose components may contain glob
+/// wildcards, e.g. `lib/*/libscip*.so*`. Unlike `glob::glob`, `dir` need not be UTF-8.
+pub fn find(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut found = vec![dir.to_path_buf()];
+    for component in pattern.split('/') {
+        let Ok(component) = glob::Pattern::new(component) else {
+            return Vec::new();
+        };
+        let mut next = Vec::new();
+        for dir in &found {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            let mut matching: Vec<PathBuf> = entries
+                .flatten()
+                .filter(|entry| component.matches(&entry.file_name().to_string_lossy()))
+                .map(|entry| entry.path())
+                .collect();
+            matching.sort();
+            next.extend(matching);
+        }
+        found = next;
+    }
+    found
+}
+
+/// `path` as UTF-8, for a `cargo:` directive or a clang argument. If it is not valid UTF-8, a
+/// symbolic link to it is created in `OUT_DIR` and returned instead.
+pub fn utf8(path: &Path) -> Result<String> {
+    if let Some(path) = path.to_str() {
+        return Ok(path.to_string());
+    }
+
+    let links = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("utf8-paths");
+    std::fs::create_dir_all(&links).map_err(|e| {
+        BuildError::new(format!("failed to create {}", links.display())).caused_by(e)
+    })?;
+    // name the link after a hash of the path, so that every path gets its own
+    let hash = path
+        .as_os_str()
+        .as_encoded_bytes()
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    let link = links.join(format!("{hash:016x}"));
+    let _ = std::fs::remove_file(&link);
+
+    #[cfg(unix)]
+    let linked = std::os::unix::fs::symlink(path, &link);
+    #[cfg(windows)]
+    let linked = std::os::windows::fs::symlink_dir(path, &link);
+    linked.map_err(|e| {
+        BuildError::new(format!(
+            "{} is not valid UTF-8, and a link to it could not be created",
+            path.display()
+        ))
+        .caused_by(e)
+        .remedy("Move SCIP to a directory whose path is valid UTF-8")
+    })?;
+    Ok(link
+        .to_str()
+        .expect("OUT_DIR is not valid UTF-8")
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_files_matching_a_pattern() {
+        let root = tempfile::tempdir().unwrap();
+        for file in [
+            "lib/libscip.so.10.0",
+            "lib/libsoplex.a",
+            "lib/x86_64-linux-gnu/libscip.so",
+        ] {
+            let path = root.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        assert_eq!(
+            find(root.path(), "lib/libscip*"),
+            [root.path().join("lib/libscip.so.10.0")]
+        );
+        assert_eq!(
+            find(root.path(), "lib/*/libscip*.so*"),
+            [root.path().join("lib/x86_64-linux-gnu/libscip.so")]
+        );
+        assert!(find(root.path(), "lib64/*").is_empty());
+        assert_eq!(utf8(root.path()).unwrap(), root.path().to_str().unwrap());
+    }
+}
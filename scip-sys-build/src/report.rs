@@ -0,0 +1,254 @@
+//! The build report: what the build script found and decided, written as JSON to
+//! `<OUT_DIR>/scip-report.json` and published to dependent crates as `DEP_SCIP_REPORT`.
+//!
+//! It records the strategy that found SCIP, every location that was probed and why it was not
+//! used, the headers and library used, the version and components, the link directives and where
+//! the bindings came from. Progress messages go into the report too, and are only printed as
+//! `cargo:warning`s with `SCIP_SYS_VERBOSE` set; otherwise a build prints a single summary line
+//! and the warnings about actual problems.
+
+use crate::version::ScipVersion;
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+struct Report {
+    strategy: Option<String>,
+    /// Locations looked at, with the result, e.g. `("/usr/include", "no scip/scip.h")`
+    probes: Vec<(String, String)>,
+    include_dir: Option<String>,
+    library: Option<String>,
+    version: Option<ScipVersion>,
+    tpi: Option<String>,
+    components: Vec<(String, bool)>,
+    /// The `cargo:rustc-link-*` directives, without the `cargo:` prefix
+    link: Vec<String>,
+    /// `prebuilt`, `generated`, `cached`, `pregenerated` or `user`, with the file they were
+    /// copied from
+    bindings: Option<(&'static str, Option<String>)>,
+    messages: Vec<String>,
+    warnings: Vec<String>,
+}
+
+static REPORT: Mutex<Report> = Mutex::new(Report {
+    strategy: None,
+    probes: Vec::new(),
+    include_dir: None,
+    library: None,
+    version: None,
+    tpi: None,
+    components: Vec::new(),
+    link: Vec::new(),
+    bindings: None,
+    messages: Vec::new(),
+    warnings: Vec::new(),
+});
+
+fn with<R>(f: impl FnOnce(&mut Report) -> R) -> R {
+    f(&mut REPORT.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Cleared by `report_only`.
+static DIRECTIVES: AtomicBool = AtomicBool::new(true);
+
+/// Only record what is found from now on, without printing `cargo:` directives: for running the
+/// discovery outside a build script, e.g. to diagnose an environment, where nobody reads them.
+pub fn report_only() {
+    DIRECTIVES.store(false, Ordering::Relaxed);
+}
+
+/// Print `cargo:<directive>`, unless in report-only mode.
+pub fn directive(directive: impl fmt::Display) {
+    if DIRECTIVES.load(Ordering::Relaxed) {
+        println!("cargo:{directive}");
+    }
+}
+
+/// Whether `SCIP_SYS_VERBOSE` asks for every message to be printed. Read once, so its
+/// `rerun-if-env-changed` is printed once however many messages there are.
+fn verbose() -> bool {
+    static VERBOSE: OnceLock<bool> = OnceLock::new();
+    *VERBOSE.get_or_init(|| {
+        directive("rerun-if-env-changed=SCIP_SYS_VERBOSE");
+        env::var("SCIP_SYS_VERBOSE").is_ok_and(|v| !v.is_empty() && v != "0" && v != "false")
+    })
+}
+
+/// Record a progress message, printed only in verbose mode.
+pub fn note(message: impl Into<String>) {
+    let message = message.into();
+    if verbose() {
+        directive(format_args!("warning={message}"));
+    }
+    with(|r| r.messages.push(message));
+}
+
+/// Record and print a warning about something that needs attention.
+pub fn warn(message: impl Into<String>) {
+    let message = message.into();
+    directive(format_args!("warning={message}"));
+    with(|r| r.warnings.push(message));
+}
+
+/// Record how SCIP is found: `bundled`, `from-source`, `SCIPOPTDIR`, ...
+pub fn strategy(strategy: &str) {
+    with(|r| r.strategy = Some(strategy.to_string()));
+}
+
+/// Record a location that was looked at for SCIP, and the result.
+pub fn probe(location: impl Into<String>, result: impl Into<String>) {
+    let (location, result) = (location.into(), result.into());
+    note(format!("{location}: {result}"));
+    with(|r| r.probes.push((location, result)));
+}
+
+/// The locations probed so far, with the result.
+pub fn attempts() -> Vec<(String, String)> {
+    with(|r| r.probes.clone())
+}
+
+pub fn include_dir(include_dir: &Path) {
+    with(|r| r.include_dir = Some(include_dir.to_string_lossy().into_owned()));
+}
+
+pub fn library(library: &Path) {
+    with(|r| r.library = Some(library.to_string_lossy().into_owned()));
+}
+
+pub fn build_info(version: Option<ScipVersion>, tpi: &str, components: &[(&str, bool)]) {
+    with(|r| {
+        r.version = version;
+        r.tpi = Some(tpi.to_string());
+        r.components = components
+            .iter()
+            .map(|&(name, enabled)| (name.to_string(), enabled))
+            .collect();
+    });
+}
+
+/// Print a `cargo:rustc-link-*` directive and record it.
+pub fn link(link: String) {
+    directive(&link);
+    with(|r| r.link.push(link));
+}
+
+/// Record where the bindings came from: `prebuilt`, `generated`, `cached`, `pregenerated` or
+/// `user`.
+pub fn bindings(kind: &'static str, source: Option<&Path>) {
+    let source = source.map(|s| s.to_string_lossy().into_owned());
+    with(|r| r.bindings = Some((kind, source)));
+}
+
+/// Write `<out_path>/scip-report.json`, publish it as `DEP_SCIP_REPORT` and print the summary.
+pub fn finish(out_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let path = out_path.join("scip-report.json");
+    std::fs::write(&path, with(|r| to_json(r)))?;
+    println!("cargo:report={}", path.to_string_lossy());
+
+    let summary = with(|r| {
+        let version = r
+            .version
+            .map_or("an unknown SCIP version".to_string(), |v| {
+                format!("SCIP {v}")
+            });
+        let location = r
+            .include_dir
+            .as_deref()
+            .map(Path::new)
+            .map(|include| include.parent().unwrap_or(include))
+            .map_or(String::new(), |root| {
+                format!(" from {}", root.to_string_lossy())
+            });
+        format!(
+            "Using {version}{location} ({}, {} bindings), report in {}",
+            r.strategy.as_deref().unwrap_or("unknown"),
+            r.bindings.as_ref().map_or("unknown", |(kind, _)| kind),
+            path.to_string_lossy()
+        )
+    });
+    println!("cargo:warning={summary}");
+    Ok(path)
+}
+
+fn to_json(r: &Report) -> String {
+    let list = |items: &[String]| {
+        let items: Vec<String> = items.iter().map(|i| json_string(Some(i))).collect();
+        format!("[{}]", items.join(", "))
+    };
+    let probes: Vec<String> = r
+        .probes
+        .iter()
+        .map(|(location, result)| {
+            format!(
+                "{{\"location\": {}, \"result\": {}}}",
+                json_string(Some(location)),
+                json_string(Some(result))
+            )
+        })
+        .collect();
+    let components: Vec<String> = r
+        .components
+        .iter()
+        .map(|(name, enabled)| format!("{}: {enabled}", json_string(Some(name))))
+        .collect();
+    let (bindings, bindings_source) = match &r.bindings {
+        Some((kind, source)) => (Some(*kind), source.as_deref()),
+        None => (None, None),
+    };
+
+    let mut out = String::from("{\n");
+    let mut field = |name: &str, value: String| {
+        if out.len() > 2 {
+            out.push_str(",\n");
+        }
+        write!(out, "  \"{name}\": {value}").unwrap();
+    };
+    field("strategy", json_string(r.strategy.as_deref()));
+    field("probes", format!("[{}]", probes.join(", ")));
+    field("include_dir", json_string(r.include_dir.as_deref()));
+    field("library", json_string(r.library.as_deref()));
+    field(
+        "version",
+        json_string(
+            r.version
+                .map(|v| format!("{}.{}.{}", v.major, v.minor, v.patch))
+                .as_deref(),
+        ),
+    );
+    field(
+        "api_version",
+        r.version.map_or("null".to_string(), |v| v.api.to_string()),
+    );
+    field("tpi", json_string(r.tpi.as_deref()));
+    field("components", format!("{{{}}}", components.join(", ")));
+    field("link", list(&r.link));
+    field("bindings", json_string(bindings));
+    field("bindings_source", json_string(bindings_source));
+    field("messages", list(&r.messages));
+    field("warnings", list(&r.warnings));
+    out.push_str("\n}\n");
+    out
+}
+
+/// A JSON string, or `null`.
+pub fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
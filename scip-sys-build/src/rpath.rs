@@ -0,0 +1,141 @@
+//! How the binaries of this package find libscip at runtime, chosen with `SCIP_SYS_RPATH`:
+//!
+//! - `absolute` (default): an rpath to the directory SCIP was found in;
+//! - `origin`: an rpath relative to the binary (`$ORIGIN`, `@loader_path` on macOS), for
+//!   packaging libscip next to it;
+//! - `copy`: copy libscip, and the libraries bundled with it, into the target profile directory
+//!   and use an `origin` rpath, so `cargo run` and packaging work without `LD_LIBRARY_PATH`;
+//! - `none`: no rpath, libscip must be on the search path of the system loader.
+//!
+//! An rpath only applies to the binaries of this package, so the chosen one is passed on as
+//! `DEP_SCIP_RPATH` for dependent crates to apply to theirs.
+
+use crate::error::{BuildError, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    None,
+    Absolute,
+    Origin,
+    Copy,
+}
+
+/// The policy selected with `SCIP_SYS_RPATH`.
+pub fn policy() -> Result<Policy> {
+    println!("cargo:rerun-if-env-changed=SCIP_SYS_RPATH");
+    let value = env::var_os("SCIP_SYS_RPATH").unwrap_or_default();
+    Ok(match value.to_string_lossy().as_ref() {
+        "" | "absolute" => Policy::Absolute,
+        "none" => Policy::None,
+        "origin" => Policy::Origin,
+        "copy" => Policy::Copy,
+        other => {
+            return Err(
+                BuildError::new(format!("SCIP_SYS_RPATH={other} is not supported"))
+                    .remedy("Set SCIP_SYS_RPATH to one of `absolute`, `origin`, `copy` or `none`"),
+            )
+        }
+    })
+}
+
+/// Apply the rpath policy for libscip found in `lib_dir` (with its DLLs in `bin_dir` on
+/// Windows).
+pub fn emit(lib_dir: &Path, bin_dir: Option<&Path>) -> Result<()> {
+    let rpath = match policy()? {
+        Policy::None => None,
+        Policy::Absolute => Some(crate::paths::utf8(lib_dir)?),
+        Policy::Origin => Some(origin().to_string()),
+        Policy::Copy => {
+            let profile_dir = profile_dir()?;
+            copy_libraries(lib_dir, &profile_dir)?;
+            if let Some(bin_dir) = bin_dir {
+                copy_libraries(bin_dir, &profile_dir)?;
+            }
+            // cargo puts the search paths inside the target directory on the library path of
+            // `cargo run` and `cargo test`, also for dependent crates
+            crate::report::link(format!(
+                "rustc-link-search=native={}",
+                crate::paths::utf8(&profile_dir)?
+            ));
+            Some(origin().to_string())
+        }
+    };
+
+    // Windows has no rpath; DLLs are found next to the executable or on PATH
+    let windows = env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows");
+    if let (Some(rpath), false) = (&rpath, windows) {
+        crate::report::link(format!("rustc-link-arg=-Wl,-rpath,{rpath}"));
+    }
+    println!("cargo:rpath={}", rpath.unwrap_or_default());
+    Ok(())
+}
+
+/// The rpath entry for the directory of the binary.
+fn origin() -> &'static str {
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("macos") {
+        "@loader_path"
+    } else {
+        "$ORIGIN"
+    }
+}
+
+/// `target/<profile>` (or `target/<triple>/<profile>`), three levels above `OUT_DIR`.
+fn profile_dir() -> Result<PathBuf> {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let profile_dir = out_dir.ancestors().nth(3).ok_or_else(|| {
+        BuildError::new(format!(
+            "OUT_DIR={} is not inside a target directory",
+            out_dir.display()
+        ))
+        .remedy("Use SCIP_SYS_RPATH=absolute or SCIP_SYS_RPATH=origin instead of `copy`")
+    })?;
+    Ok(profile_dir.to_path_buf())
+}
+
+/// Copy the shared libraries of `from` into `to`, keeping symbolic links as links.
+///
+/// A directory SCIP was downloaded or built into (below `OUT_DIR`) only holds SCIP and the
+/// libraries it is bundled with, so all of them are copied. From anywhere else, e.g. a conda
+/// environment, only libscip itself is.
+fn copy_libraries(from: &Path, to: &Path) -> Result<()> {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let bundled = from.starts_with(&out_dir);
+    let entries = fs::read_dir(from)
+        .map_err(|e| BuildError::new(format!("failed to read {}", from.display())).caused_by(e))?;
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let name = file_name.to_string_lossy();
+        let shared = name.contains(".so") || name.ends_with(".dylib") || name.ends_with(".dll");
+        if !shared || !(bundled || name.starts_with("libscip") || name.starts_with("scip")) {
+            continue;
+        }
+
+        let source = entry.path();
+        let destination = to.join(&file_name);
+        let _ = fs::remove_file(&destination);
+        let copied = match fs::read_link(&source) {
+            #[cfg(unix)]
+            Ok(target) => std::os::unix::fs::symlink(target, &destination),
+            _ => fs::copy(&source, &destination).map(|_| ()),
+        };
+        copied.map_err(|e| {
+            BuildError::new(format!(
+                "failed to copy {} to {}",
+                source.display(),
+                to.display()
+            ))
+            .caused_by(e)
+            .remedy("Use SCIP_SYS_RPATH=absolute to link libscip where it is")
+        })?;
+    }
+    crate::report::note(format!(
+        "Copied the SCIP libraries from {} to {}",
+        from.display(),
+        to.display()
+    ));
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! Vendored archives, for builds without network access: a directory holding the precompiled
+//! releases and source archives ahead of time, filled by `scip-sys-vendor`.
+//!
+//! Each archive is stored under the host and path of its URL, e.g.
+//! `github.com/scipopt/scip/releases/download/v10.0.2/scipoptsuite-10.0.2.tgz`, so that the
+//! archives of several releases fit into one directory. `SHA256SUMS` lists their checksums in the
+//! format of `sha256sum`, so `sha256sum -c SHA256SUMS` checks the directory too. A build with
+//! `SCIP_SYS_VENDOR_DIR` set takes its archives from there and never goes online.
+
+use crate::download::{sha256_hex, verify_sha256};
+use crate::error::{BuildError, Result};
+use crate::report;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The variable naming the vendor directory of a build.
+pub const ENV_VAR: &str = "SCIP_SYS_VENDOR_DIR";
+
+/// The checksum manifest of a vendor directory.
+pub const CHECKSUMS: &str = "SHA256SUMS";
+
+/// The vendor directory named by `SCIP_SYS_VENDOR_DIR`, if it is set.
+pub fn dir() -> Option<PathBuf> {
+    report::directive(format_args!("rerun-if-env-changed={ENV_VAR}"));
+    env::var_os(ENV_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The path of the archive at `url` in a vendor directory, `/` separated.
+pub fn path_of(url: &str) -> Result<String> {
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.len() < 2 || segments.iter().any(|&s| s == "." || s == "..") {
+        return Err(BuildError::new(format!(
+            "{url} is not the URL of a file that can be vendored"
+        )));
+    }
+    Ok(segments.join("/"))
+}
+
+/// The checksums recorded in the vendor directory `dir`, by path. A directory without
+/// `SHA256SUMS` has none.
+pub fn checksums(dir: &Path) -> Result<BTreeMap<String, String>> {
+    let path = dir.join(CHECKSUMS);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => {
+            return Err(BuildError::new(format!("failed to read {}", path.display())).caused_by(e))
+        }
+    };
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            // `<sha256>  <path>`, or `<sha256> *<path>` for binary mode
+            let (sha256, file) = line.split_once(' ').ok_or_else(|| {
+                BuildError::new(format!(
+                    "{}: `{line}` is not `<sha256>  <path>`",
+                    path.display()
+                ))
+            })?;
+            let file = file.trim_start_matches([' ', '*']);
+            Ok((file.to_string(), sha256.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// The archive at `url` from the vendor directory `dir`, checked against `SHA256SUMS`.
+pub fn read(dir: &Path, url: &str) -> Result<Vec<u8>> {
+    let path = path_of(url)?;
+    let file = dir.join(&path);
+    let remedies = |error: BuildError| {
+        error
+            .remedy(format!(
+                "Run `scip-sys-vendor` with the targets of this build into {} on a machine with \
+                 network access",
+                dir.display()
+            ))
+            .remedy(format!("Unset {ENV_VAR} to download {url}"))
+    };
+    let data = fs::read(&file).map_err(|e| {
+        remedies(
+            BuildError::new(format!(
+                "{url} is not vendored in {ENV_VAR}={}",
+                dir.display()
+            ))
+            .caused_by(e),
+        )
+    })?;
+    let checksums = checksums(dir)?;
+    let sha256 = checksums.get(&path).ok_or_else(|| {
+        remedies(BuildError::new(format!(
+            "{} has no checksum for {path}",
+            dir.join(CHECKSUMS).display()
+        )))
+    })?;
+    verify_sha256(&file.display().to_string(), &data, sha256)?;
+    report::probe(file.display().to_string(), "vendored");
+    Ok(data)
+}
+
+/// Store `data`, the archive at `url`, in the vendor directory `dir` and record its checksum.
+/// Returns the file it was written to.
+pub fn add(dir: &Path, url: &str, data: &[u8]) -> Result<PathBuf> {
+    let path = path_of(url)?;
+    let file = dir.join(&path);
+    let write = |file: &Path, data: &[u8]| {
+        fs::create_dir_all(file.parent().unwrap())
+            .and_then(|_| fs::write(file, data))
+            .map_err(|e| {
+                BuildError::new(format!("failed to write {}", file.display())).caused_by(e)
+            })
+    };
+    write(&file, data)?;
+
+    let mut checksums = checksums(dir)?;
+    checksums.insert(path, sha256_hex(data));
+    let manifest: String = checksums
+        .iter()
+        .map(|(path, sha256)| format!("{sha256}  {path}\n"))
+        .collect();
+    write(&dir.join(CHECKSUMS), manifest.as_bytes())?;
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archives_are_stored_under_their_url() {
+        assert_eq!(
+            path_of("https://github.com/scipopt/scipoptsuite-deploy/releases/download/v0.12.0/libscip-linux.zip").unwrap(),
+            "github.com/scipopt/scipoptsuite-deploy/releases/download/v0.12.0/libscip-linux.zip"
+        );
+        assert_eq!(
+            path_of("https://example.com//a/b.tgz?download=1").unwrap(),
+            "example.com/a/b.tgz"
+        );
+        assert!(path_of("https://example.com/../b.tgz").is_err());
+        assert!(path_of("https://example.com").is_err());
+    }
+
+    #[test]
+    fn reads_back_what_was_added() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = "https://example.com/v1/libscip-linux.zip";
+        let error = read(dir.path(), url).unwrap_err().to_string();
+        assert!(error.contains("is not vendored"), "{error}");
+
+        add(dir.path(), url, b"zip").unwrap();
+        add(dir.path(), "https://example.com/v1/source.tgz", b"tgz").unwrap();
+        assert_eq!(read(dir.path(), url).unwrap(), b"zip");
+        let sums = fs::read_to_string(dir.path().join(CHECKSUMS)).unwrap();
+        assert_eq!(sums.lines().count(), 2);
+        assert!(sums.contains(&format!(
+            "{}  example.com/v1/libscip-linux.zip",
+            sha256_hex(b"zip")
+        )));
+
+        fs::write(
+            dir.path().join("example.com/v1/libscip-linux.zip"),
+            b"tampered",
+        )
+        .unwrap();
+        let error = read(dir.path(), url).unwrap_err().to_string();
+        assert!(error.contains("was expected"), "{error}");
+    }
+}
@@ -0,0 +1,164 @@
+//! The SCIP version of an installation, read from its headers, and of a set of bindings.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// A SCIP release and the version of its API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScipVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// `SCIP_APIVERSION`, which changes with every change of the C API
+    pub api: u32,
+}
+
+impl fmt::Display for ScipVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{} (API {})",
+            self.major, self.minor, self.patch, self.api
+        )
+    }
+}
+
+impl ScipVersion {
+    /// Read the version from `scip/config.h`, which defines `SCIP_VERSION_MAJOR`, ... since SCIP
+    /// 8, and from `scip/def.h`, which defines `SCIP_VERSION` and `SCIP_APIVERSION` in older
+    /// releases.
+    pub fn from_headers(include_dir: &Path) -> Option<ScipVersion> {
+        let defines = header_defines(include_dir)
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value.parse().ok()?)))
+            .collect();
+        Self::from_defines(&defines)
+    }
+
+    /// Read the version from the `SCIP_VERSION*` constants of generated bindings.
+    pub fn from_bindings(bindings: &str) -> Option<ScipVersion> {
+        let defines = bindings
+            .lines()
+            .filter_map(|line| {
+                let (name, value) = line
+                    .strip_prefix("pub const SCIP_")?
+                    .split_once(": u32 = ")?;
+                Some((
+                    format!("SCIP_{name}"),
+                    value.strip_suffix(';')?.parse().ok()?,
+                ))
+            })
+            .collect();
+        Self::from_defines(&defines)
+    }
+
+    fn from_defines(defines: &HashMap<String, u32>) -> Option<ScipVersion> {
+        let get = |name: &str| defines.get(name).copied();
+        let api = get("SCIP_VERSION_API").or(get("SCIP_APIVERSION"))?;
+        if let (Some(major), Some(minor), Some(patch)) = (
+            get("SCIP_VERSION_MAJOR"),
+            get("SCIP_VERSION_MINOR"),
+            get("SCIP_VERSION_PATCH"),
+        ) {
+            return Some(ScipVersion {
+                major,
+                minor,
+                patch,
+                api,
+            });
+        }
+        // `SCIP_VERSION` is 100 * major + 10 * minor + patch, e.g. 802 for 8.0.2
+        let version = get("SCIP_VERSION")?;
+        Some(ScipVersion {
+            major: version / 100,
+            minor: version / 10 % 10,
+            patch: version % 10,
+            api,
+        })
+    }
+}
+
+/// The macros defined in `scip/config.h` and `scip/def.h` with their values, which are empty for
+/// flags like `SCIP_WITH_ZLIB`. The first definition of a macro wins.
+pub fn header_defines(include_dir: &Path) -> HashMap<String, String> {
+    let mut defines = HashMap::new();
+    for header in ["config.h", "def.h"] {
+        let Ok(content) = std::fs::read_to_string(include_dir.join("scip").join(header)) else {
+            continue;
+        };
+        for line in content.lines() {
+            let Some(rest) = line.trim_start().strip_prefix("#define") else {
+                continue;
+            };
+            let mut words = rest.split_whitespace();
+            if let Some(name) = words.next() {
+                let value = words.next().unwrap_or_default();
+                defines
+                    .entry(name.to_string())
+                    .or_insert_with(|| value.to_string());
+            }
+        }
+    }
+    defines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn headers(config_h: &str, def_h: &str) -> tempfile::TempDir {
+        let include = tempfile::tempdir().unwrap();
+        let scip = include.path().join("scip");
+        fs::create_dir_all(&scip).unwrap();
+        fs::write(scip.join("config.h"), config_h).unwrap();
+        fs::write(scip.join("def.h"), def_h).unwrap();
+        include
+    }
+
+    #[test]
+    fn reads_the_version_of_an_installation() {
+        let scip10 = headers(
+            "#define SCIP_VERSION_MAJOR 10\n#define SCIP_VERSION_MINOR 0\n\
+             #define SCIP_VERSION_PATCH 2\n#define SCIP_VERSION_API 156\n#define SCIP_WITH_ZLIB\n",
+            "#define SCIP_APIVERSION 155\n",
+        );
+        let version = ScipVersion::from_headers(scip10.path()).unwrap();
+        assert_eq!(version.to_string(), "10.0.2 (API 156)");
+        assert_eq!(header_defines(scip10.path())["SCIP_WITH_ZLIB"], "");
+
+        let scip8 = headers(
+            "",
+            "#define SCIP_VERSION 802\n#define SCIP_APIVERSION 104\n",
+        );
+        assert_eq!(
+            ScipVersion::from_headers(scip8.path()),
+            Some(ScipVersion {
+                major: 8,
+                minor: 0,
+                patch: 2,
+                api: 104
+            })
+        );
+
+        let unknown = headers("", "#define SCIP_VERSION 802\n");
+        assert_eq!(ScipVersion::from_headers(unknown.path()), None);
+    }
+
+    #[test]
+    fn reads_the_version_of_bindings() {
+        let bindings = "pub const SCIP_VERSION_MAJOR: u32 = 10;\npub const SCIP_VERSION_MINOR: u32 = 0;\n\
+                        pub const SCIP_VERSION_PATCH: u32 = 1;\npub const SCIP_APIVERSION: u32 = 156;\n";
+        assert_eq!(
+            ScipVersion::from_bindings(bindings),
+            Some(ScipVersion {
+                major: 10,
+                minor: 0,
+                patch: 1,
+                api: 156
+            })
+        );
+        assert_eq!(ScipVersion::from_bindings("pub const X: u32 = 1;"), None);
+    }
+}
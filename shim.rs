@@ -0,0 +1,26 @@
+//! Compiles `src/shim.c`, the C side of the `shim` module, against the headers of the SCIP that
+//! is linked.
+
+use crate::error::{BuildError, Result};
+use std::path::Path;
+
+/// Compile the shim into a static library and link it.
+pub fn compile(include_dir: &Path) -> Result<()> {
+    println!("cargo:rerun-if-changed=src/shim.c");
+    cc::Build::new()
+        .file("src/shim.c")
+        .include(include_dir)
+        // like SCIP's release builds, so the macros resolve to the same code
+        .define("NDEBUG", None)
+        .warnings(false)
+        .try_compile("scip_sys_shim")
+        .map_err(|e| {
+            BuildError::new(format!(
+                "could not compile src/shim.c against the SCIP headers in {}",
+                include_dir.display()
+            ))
+            .caused_by(e.to_string())
+            .remedy("Install a C compiler, or set CC to one")
+            .remedy("Check that the headers are complete, e.g. that scip/config.h exists")
+        })
+}
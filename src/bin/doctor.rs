@@ -0,0 +1,621 @@
+//! `scip-sys-doctor`: diagnose why a build or a program using scip-sys does not find SCIP, or
+//! finds the wrong one.
+//!
+//! It goes through the usual checklist and says what to do about every problem it finds:
+//! - what the build of scip-sys found (its build report),
+//! - where SCIP is found in the current environment,
+//! - whether libclang is there, for builds that generate the bindings,
+//! - whether the library directory of the build (`DEP_SCIP_LIBDIR`) still holds libscip,
+//! - which libscip the loader opens at runtime, and whether it is the one of the build,
+//! - whether the versions of all of them agree, asking the library itself for its version.
+//!
+//! Run it with the features of the build in question, e.g.
+//! `cargo run --features doctor,bundled --bin scip-sys-doctor`, and `--json` for a report tools
+//! can read. It opens libscip itself (`runtime-load`), so it also runs where the loader cannot
+//! find it.
+
+use scip_sys::runtime_load::{load_candidates, LoadError};
+use scip_sys::{build_info, ScipLibrary};
+use scip_sys_build::report::{self, json_string};
+use scip_sys_build::{discover, paths, Installation, ScipVersion};
+use std::env;
+use std::ffi::c_int;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// The report of the build script, see `scip_sys_build::report`.
+const BUILD_REPORT: &str = include_str!(concat!(env!("OUT_DIR"), "/scip-report.json"));
+
+/// The directory containing the libscip the crate was built against, published as
+/// `DEP_SCIP_LIBDIR`.
+const LIB_DIR: Option<&str> = option_env!("SCIP_SYS_LIB_DIR");
+
+const USAGE: &str = "\
+Usage: scip-sys-doctor [--json]
+
+Check how SCIP is found at build time and at runtime, and print what to do about problems.
+
+Options:
+  --json      Print the report as JSON
+  -h, --help  Print this help";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Warning => "warning",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// The result of one item of the checklist.
+struct Check {
+    name: &'static str,
+    status: Status,
+    summary: String,
+    details: Vec<String>,
+    fixes: Vec<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, summary: impl Into<String>) -> Check {
+        Check {
+            name,
+            status,
+            summary: summary.into(),
+            details: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    fn detail(mut self, detail: impl Into<String>) -> Check {
+        self.details.push(detail.into());
+        self
+    }
+
+    fn fix(mut self, fix: impl Into<String>) -> Check {
+        self.fixes.push(fix.into());
+        self
+    }
+}
+
+fn main() -> ExitCode {
+    let mut json = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => {
+                eprintln!("error: unexpected argument {arg}\n\n{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let build_version = built_against();
+    let (found, discovery) = discovery();
+    let (library, loader) = loader();
+    let mut checks = vec![
+        build(build_version),
+        discovery,
+        libclang(),
+        lib_dir(),
+        loader,
+    ];
+    checks.push(versions(build_version, found.as_ref(), library.as_ref()));
+
+    if json {
+        print!("{}", to_json(&checks));
+    } else {
+        print!("{}", to_text(&checks));
+    }
+    if checks.iter().any(|check| check.status == Status::Error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The SCIP version the crate was built against, if it is known.
+fn built_against() -> Option<ScipVersion> {
+    Some(ScipVersion {
+        major: build_info::VERSION_MAJOR,
+        minor: build_info::VERSION_MINOR,
+        patch: build_info::VERSION_PATCH,
+        api: build_info::API_VERSION,
+    })
+    .filter(|version| version.major != 0)
+}
+
+/// The string field `name` of the build report, if it is set.
+fn report_field(name: &str) -> Option<String> {
+    let line = BUILD_REPORT
+        .lines()
+        .find_map(|line| line.strip_prefix(&format!("  \"{name}\": ")))?;
+    let value = line
+        .trim_end_matches(',')
+        .strip_prefix('"')?
+        .strip_suffix('"')?;
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'u' => {
+                let code: String = chars.by_ref().take(4).collect();
+                out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+/// What the build script of the crate found.
+fn build(version: Option<ScipVersion>) -> Check {
+    let strategy = report_field("strategy").unwrap_or_else(|| "unknown".to_string());
+    let bindings = report_field("bindings").unwrap_or_else(|| "unknown".to_string());
+    let version = version.map_or("an unknown SCIP".to_string(), |v| format!("SCIP {v}"));
+    let mut check = Check::new(
+        "build",
+        Status::Ok,
+        format!(
+            "scip-sys was built against {version}, found by {strategy}, with {bindings} bindings"
+        ),
+    );
+    if let Some(include_dir) = report_field("include_dir") {
+        check = check.detail(format!("headers: {include_dir}"));
+    }
+    if let Some(library) = report_field("library") {
+        check = check.detail(format!("library: {library}"));
+    }
+    check.detail(format!("report: {}/scip-report.json", env!("OUT_DIR")))
+}
+
+/// Where SCIP is found in the current environment, which may differ from the one of the build.
+fn discovery() -> (Option<Installation>, Check) {
+    report::report_only();
+    let found = discover::from_env(&discover::ENV_VARS)
+        .or_else(|| discover::system(&discover::SYSTEM_INCLUDE_DIRS));
+    let attempts = report::attempts()
+        .into_iter()
+        .map(|(location, result)| format!("{location}: {result}"));
+
+    let strategy = report_field("strategy").unwrap_or_default();
+    let mut check = match &found {
+        Some(scip) => {
+            let version = ScipVersion::from_headers(&scip.include_dir)
+                .map_or("SCIP of an unknown version".to_string(), |v| {
+                    format!("SCIP {v}")
+                });
+            let mut check = Check::new(
+                "discovery",
+                Status::Ok,
+                format!("found {version} in {}", scip.root.display()),
+            );
+            let built = report_field("include_dir").map(PathBuf::from);
+            if strategy != "bundled" && built.is_some_and(|built| built != scip.include_dir) {
+                check.status = Status::Warning;
+                check = check
+                    .detail(format!(
+                        "the build used the headers in {}",
+                        report_field("include_dir").unwrap_or_default()
+                    ))
+                    .fix(
+                        "The next build picks up a changed SCIPOPTDIR or CONDA_PREFIX by itself; \
+                         after a change of a system installation, run `cargo clean -p scip-sys` \
+                         to build against the SCIP found now",
+                    );
+            }
+            check
+        }
+        None if strategy == "bundled" || strategy == "from-source" => Check::new(
+            "discovery",
+            Status::Ok,
+            format!("no installed SCIP, the build uses its {strategy} one"),
+        ),
+        None => Check::new("discovery", Status::Error, "no SCIP installation found")
+            .fix("Set the SCIPOPTDIR environment variable to point to your SCIP installation")
+            .fix("Install SCIP in a conda environment: conda install --channel conda-forge scip")
+            .fix("Install SCIP system-wide (headers in /usr/include or /usr/local/include)")
+            .fix("Use --features bundled to download and use a bundled version"),
+    };
+    check.details.extend(attempts);
+    (found, check)
+}
+
+/// Whether libclang, which bindgen needs to generate the bindings, can be found.
+fn libclang() -> Check {
+    let (dirs, patterns): (Vec<PathBuf>, &[&str]) = if cfg!(windows) {
+        (
+            vec![
+                PathBuf::from(r"C:\Program Files\LLVM\bin"),
+                PathBuf::from(r"C:\Program Files\LLVM\lib"),
+            ],
+            &["libclang.dll"],
+        )
+    } else if cfg!(target_os = "macos") {
+        (
+            [
+                "/Library/Developer/CommandLineTools/usr/lib",
+                "/Applications/Xcode.app/Contents/Developer/Toolchains/XcodeDefault.xctoolchain/usr/lib",
+                "/opt/homebrew/opt/llvm/lib",
+                "/usr/local/opt/llvm/lib",
+            ]
+            .map(PathBuf::from)
+            .to_vec(),
+            &["libclang.dylib"],
+        )
+    } else {
+        let mut dirs: Vec<PathBuf> = ["/usr/lib", "/usr/lib64", "/usr/local/lib"]
+            .map(PathBuf::from)
+            .to_vec();
+        dirs.extend(paths::find(Path::new("/usr/lib"), "*-linux-gnu*"));
+        dirs.extend(paths::find(Path::new("/usr/lib"), "llvm-*/lib"));
+        (dirs, &["libclang.so*", "libclang-[0-9]*.so*"])
+    };
+    let llvm_config = std::process::Command::new("llvm-config")
+        .arg("--libdir")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+    let libclang_path = env::var_os("LIBCLANG_PATH").map(PathBuf::from);
+
+    let found = libclang_path
+        .iter()
+        .chain(&llvm_config)
+        .chain(&dirs)
+        .flat_map(|dir| {
+            patterns
+                .iter()
+                .flat_map(|pattern| paths::find(dir, pattern))
+        })
+        .next();
+    if let Some(found) = found {
+        return Check::new("libclang", Status::Ok, format!("found {}", found.display()));
+    }
+    let generated = matches!(
+        report_field("bindings").as_deref(),
+        Some("generated" | "cached")
+    );
+    let check = if generated {
+        Check::new(
+            "libclang",
+            Status::Warning,
+            "libclang was not found here, although the build generated the bindings with it",
+        )
+    } else {
+        Check::new(
+            "libclang",
+            Status::Warning,
+            "libclang was not found; builds that generate the bindings (an installed SCIP of \
+             another version than the prebuilt bindings, `from-source`) need it",
+        )
+    };
+    check
+        .detail(match &libclang_path {
+            Some(dir) => format!("LIBCLANG_PATH={}: no {}", dir.display(), patterns[0]),
+            None => "LIBCLANG_PATH: not set".to_string(),
+        })
+        .fix(
+            "Install libclang: apt install libclang-dev, dnf install clang-devel, brew install \
+             llvm, or LLVM from https://releases.llvm.org on Windows",
+        )
+        .fix("Set LIBCLANG_PATH to the directory containing libclang")
+        .fix("Build with --no-default-features --features bundled,full, which needs no libclang")
+}
+
+/// Whether the library directory of the build, `DEP_SCIP_LIBDIR`, still holds libscip.
+fn lib_dir() -> Check {
+    let Some(lib_dir) = LIB_DIR else {
+        return Check::new(
+            "lib_dir",
+            Status::Warning,
+            "the build found no libscip, so DEP_SCIP_LIBDIR is not set",
+        )
+        .fix("Set SCIPOPTDIR to a SCIP installation with its lib/ directory and rebuild");
+    };
+    let libraries: Vec<PathBuf> = ["lib*scip*", "*scip*.lib", "../bin/*scip*.dll"]
+        .iter()
+        .flat_map(|pattern| paths::find(Path::new(lib_dir), pattern))
+        .collect();
+    match libraries.first() {
+        Some(library) => Check::new(
+            "lib_dir",
+            Status::Ok,
+            format!("DEP_SCIP_LIBDIR={lib_dir} holds {}", library.display()),
+        ),
+        None => Check::new(
+            "lib_dir",
+            Status::Error,
+            format!("DEP_SCIP_LIBDIR={lib_dir} holds no libscip anymore"),
+        )
+        .fix("Reinstall SCIP there, or run `cargo clean -p scip-sys` to build against the SCIP installed now"),
+    }
+}
+
+/// The libscip the loader opens: the one in `SCIPOPTDIR`, or else the one the system loader
+/// finds. Returns the library and the file it was opened from, if that is known.
+fn loader() -> (Option<(libloading::Library, Option<PathBuf>)>, Check) {
+    let mut attempts = Vec::new();
+    for candidate in load_candidates() {
+        match unsafe { libloading::Library::new(&candidate) } {
+            Ok(library) => {
+                let path = if candidate.is_absolute() {
+                    Some(candidate)
+                } else {
+                    loaded_path()
+                };
+                let check = same_library(path.as_deref(), attempts);
+                return (Some((library, path)), check);
+            }
+            Err(error) => attempts.push(format!("{}: {error}", candidate.display())),
+        }
+    }
+
+    let mut check = Check::new("loader", Status::Error, "libscip cannot be opened");
+    check.details = attempts;
+    check = check.fix("Set SCIPOPTDIR to the SCIP installation");
+    if let Some(lib_dir) = LIB_DIR {
+        check = check.fix(format!(
+            "Add {lib_dir} to {}",
+            if cfg!(windows) {
+                "PATH (the directory with the DLL)"
+            } else if cfg!(target_os = "macos") {
+                "DYLD_LIBRARY_PATH"
+            } else {
+                "LD_LIBRARY_PATH"
+            }
+        ));
+    }
+    (None, check)
+}
+
+/// Whether the libscip at `path`, opened after the failed `attempts`, is the one of the build,
+/// and has every function the bindings expect.
+fn same_library(path: Option<&Path>, attempts: Vec<String>) -> Check {
+    let Some(path) = path else {
+        let mut check = Check::new(
+            "loader",
+            Status::Warning,
+            "libscip was opened, but it is unknown from which file",
+        );
+        check.details = attempts;
+        return check;
+    };
+    let mut check = Check::new("loader", Status::Ok, format!("opens {}", path.display()));
+    check.details = attempts;
+    check = check.detail(
+        "programs linking libscip find it through the rpath scip-sys sets (see SCIP_SYS_RPATH), \
+         which this check does not see",
+    );
+
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if let Some(lib_dir) = LIB_DIR {
+        let opened_dir = path.parent().map(canonical);
+        if opened_dir.is_some_and(|dir| dir != canonical(Path::new(lib_dir))) {
+            check.status = Status::Warning;
+            check = check
+                .detail(format!("the build used the libscip in {lib_dir}"))
+                .fix(format!(
+                    "Set SCIPOPTDIR to the installation in {}, or put {lib_dir} first in the \
+                     library search path",
+                    Path::new(lib_dir)
+                        .parent()
+                        .unwrap_or(Path::new(lib_dir))
+                        .display()
+                ));
+        }
+    }
+
+    match unsafe { ScipLibrary::load_from(path) } {
+        Ok(_) => check,
+        Err(LoadError::MissingSymbols(missing)) => {
+            check.status = Status::Error;
+            let mut summary = format!(
+                "{} lacks {} functions the bindings expect: ",
+                path.display(),
+                missing.len()
+            );
+            summary.push_str(
+                &missing
+                    .iter()
+                    .take(5)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            if missing.len() > 5 {
+                summary.push_str(", ...");
+            }
+            check.summary = summary;
+            check.fix("Install the SCIP scip-sys was built against, or rebuild scip-sys against this libscip")
+        }
+        Err(error) => {
+            check.status = Status::Error;
+            check.detail(error.to_string())
+        }
+    }
+}
+
+/// The file the loaded libscip was mapped from, where the system tells.
+fn loaded_path() -> Option<PathBuf> {
+    let maps = std::fs::read_to_string("/proc/self/maps").ok()?;
+    maps.lines()
+        .filter_map(|line| line.split_whitespace().nth(5))
+        .map(PathBuf::from)
+        .find(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("libscip"))
+        })
+}
+
+/// The version of the opened `library`, from its version functions.
+fn library_version(library: &libloading::Library) -> Option<(u32, u32, u32)> {
+    let call = |name: &[u8]| unsafe {
+        let function = library.get::<unsafe extern "C" fn() -> c_int>(name).ok()?;
+        u32::try_from(function()).ok()
+    };
+    Some((
+        call(b"SCIPmajorVersion\0")?,
+        call(b"SCIPminorVersion\0")?,
+        call(b"SCIPtechVersion\0")?,
+    ))
+}
+
+/// Whether the versions of the build, of the headers found now and of the opened library agree.
+fn versions(
+    build: Option<ScipVersion>,
+    found: Option<&Installation>,
+    library: Option<&(libloading::Library, Option<PathBuf>)>,
+) -> Check {
+    let triple = |v: ScipVersion| (v.major, v.minor, v.patch);
+    let build = build.map(triple);
+    let headers = found
+        .and_then(|scip| ScipVersion::from_headers(&scip.include_dir))
+        .map(triple);
+    let runtime = library.and_then(|(library, _)| library_version(library));
+    let show = |version: Option<(u32, u32, u32)>| {
+        version.map_or("unknown".to_string(), |(major, minor, patch)| {
+            format!("{major}.{minor}.{patch}")
+        })
+    };
+
+    let mut check = Check::new("versions", Status::Ok, "the SCIP versions agree")
+        .detail(format!("build: {}", show(build)))
+        .detail(format!("headers found now: {}", show(headers)))
+        .detail(format!("opened libscip: {}", show(runtime)));
+    if let (Some(build), Some(runtime)) = (build, runtime) {
+        if (build.0, build.1) != (runtime.0, runtime.1) {
+            check.status = Status::Error;
+            check.summary = format!(
+                "the opened libscip is SCIP {}, but scip-sys was built against SCIP {}, whose \
+                 ABI differs",
+                show(Some(runtime)),
+                show(Some(build))
+            );
+        } else if build != runtime {
+            check.status = Status::Warning;
+            check.summary = format!(
+                "the opened libscip is SCIP {}, scip-sys was built against SCIP {}",
+                show(Some(runtime)),
+                show(Some(build))
+            );
+        }
+    }
+    if let (Some(build), Some(headers)) = (build, headers) {
+        if build != headers && check.status == Status::Ok {
+            check.status = Status::Warning;
+            check.summary = format!(
+                "SCIP {} is installed now, but scip-sys was built against SCIP {}",
+                show(Some(headers)),
+                show(Some(build))
+            );
+        }
+    }
+    if runtime.is_none() && library.is_some() {
+        check = check.detail("the opened libscip has no version functions");
+    }
+    if check.status != Status::Ok {
+        check = check
+            .fix(format!(
+                "Install SCIP {} and point SCIPOPTDIR to it",
+                show(build)
+            ))
+            .fix("Or run `cargo clean -p scip-sys` and rebuild against the SCIP installed now");
+    }
+    check
+}
+
+fn to_text(checks: &[Check]) -> String {
+    let mut out = format!(
+        "scip-sys-doctor {} ({})\n\n",
+        env!("CARGO_PKG_VERSION"),
+        option_env!("SCIP_SYS_TARGET").unwrap_or("unknown target")
+    );
+    for check in checks {
+        let status = format!("[{}]", check.status.name());
+        writeln!(out, "{status:<10}{}: {}", check.name, check.summary).unwrap();
+        for detail in &check.details {
+            writeln!(out, "{:10}  {detail}", "").unwrap();
+        }
+        for fix in &check.fixes {
+            writeln!(out, "{:10}  fix: {fix}", "").unwrap();
+        }
+    }
+    let worst = checks.iter().map(|check| check.status).max();
+    writeln!(
+        out,
+        "\n{}",
+        match worst.unwrap_or(Status::Ok) {
+            Status::Ok => "Everything looks fine.",
+            Status::Warning => "There are warnings, see the fixes above.",
+            Status::Error => "There are problems, see the fixes above.",
+        }
+    )
+    .unwrap();
+    out
+}
+
+fn to_json(checks: &[Check]) -> String {
+    let list = |items: &[String]| {
+        let items: Vec<String> = items.iter().map(|i| json_string(Some(i))).collect();
+        format!("[{}]", items.join(", "))
+    };
+    let checks: Vec<String> = checks
+        .iter()
+        .map(|check| {
+            format!(
+                "    {{\"name\": {}, \"status\": {}, \"summary\": {}, \"details\": {}, \"fixes\": {}}}",
+                json_string(Some(check.name)),
+                json_string(Some(check.status.name())),
+                json_string(Some(&check.summary)),
+                list(&check.details),
+                list(&check.fixes)
+            )
+        })
+        .collect();
+    format!(
+        "{{\n  \"version\": {},\n  \"target\": {},\n  \"checks\": [\n{}\n  ],\n  \"build_report\": {}}}\n",
+        json_string(Some(env!("CARGO_PKG_VERSION"))),
+        json_string(option_env!("SCIP_SYS_TARGET")),
+        checks.join(",\n"),
+        BUILD_REPORT.trim_end()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_build_report() {
+        assert!(report_field("strategy").is_some());
+        assert_eq!(report_field("no_such_field"), None);
+        let json = to_json(&[
+            Check::new("build", Status::Warning, "a \"quoted\" summary").fix("do \\ this")
+        ]);
+        assert!(
+            json.contains(r#"{"name": "build", "status": "warning", "summary": "a \"quoted\" summary", "details": [], "fixes": ["do \\ this"]}"#),
+            "{json}"
+        );
+    }
+}
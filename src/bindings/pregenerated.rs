@@ -0,0 +1,5916 @@
+//@ common 1
+/* Pre-generated bindings for docs.rs builds (no network access).
+   These bindings were generated from SCIP headers and are used
+   when building documentation on docs.rs. */
+//@ common 137
+pub const _STDIO_H: u32 = 1;
+pub const _FEATURES_H: u32 = 1;
+pub const _DEFAULT_SOURCE: u32 = 1;
+pub const __GLIBC_USE_ISOC2X: u32 = 0;
+pub const __USE_ISOC11: u32 = 1;
+pub const __USE_ISOC99: u32 = 1;
+pub const __USE_ISOC95: u32 = 1;
+pub const __USE_POSIX_IMPLICITLY: u32 = 1;
+pub const _POSIX_SOURCE: u32 = 1;
+pub const _POSIX_C_SOURCE: u32 = 200809;
+pub const __USE_POSIX: u32 = 1;
+pub const __USE_POSIX2: u32 = 1;
+pub const __USE_POSIX199309: u32 = 1;
+pub const __USE_POSIX199506: u32 = 1;
+pub const __USE_XOPEN2K: u32 = 1;
+pub const __USE_XOPEN2K8: u32 = 1;
+pub const _ATFILE_SOURCE: u32 = 1;
+pub const __WORDSIZE: u32 = 64;
+pub const __WORDSIZE_TIME64_COMPAT32: u32 = 1;
+pub const __SYSCALL_WORDSIZE: u32 = 64;
+pub const __TIMESIZE: u32 = 64;
+pub const __USE_MISC: u32 = 1;
+pub const __USE_ATFILE: u32 = 1;
+pub const __USE_FORTIFY_LEVEL: u32 = 0;
+pub const __GLIBC_USE_DEPRECATED_GETS: u32 = 0;
+pub const __GLIBC_USE_DEPRECATED_SCANF: u32 = 0;
+pub const __GLIBC_USE_C2X_STRTOL: u32 = 0;
+pub const _STDC_PREDEF_H: u32 = 1;
+pub const __STDC_IEC_559__: u32 = 1;
+pub const __STDC_IEC_60559_BFP__: u32 = 201404;
+pub const __STDC_IEC_559_COMPLEX__: u32 = 1;
+pub const __STDC_IEC_60559_COMPLEX__: u32 = 201404;
+pub const __STDC_ISO_10646__: u32 = 201706;
+pub const __GNU_LIBRARY__: u32 = 6;
+pub const __GLIBC__: u32 = 2;
+pub const __GLIBC_MINOR__: u32 = 39;
+pub const _SYS_CDEFS_H: u32 = 1;
+pub const __glibc_c99_flexarr_available: u32 = 1;
+pub const __LDOUBLE_REDIRECTS_TO_FLOAT128_ABI: u32 = 0;
+pub const __HAVE_GENERIC_SELECTION: u32 = 1;
+pub const __GLIBC_USE_LIB_EXT2: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_BFP_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_BFP_EXT_C2X: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_FUNCS_EXT: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_FUNCS_EXT_C2X: u32 = 0;
+pub const __GLIBC_USE_IEC_60559_TYPES_EXT: u32 = 0;
+pub const _BITS_TYPES_H: u32 = 1;
+pub const _BITS_TYPESIZES_H: u32 = 1;
+pub const __OFF_T_MATCHES_OFF64_T: u32 = 1;
+pub const __INO_T_MATCHES_INO64_T: u32 = 1;
+pub const __RLIM_T_MATCHES_RLIM64_T: u32 = 1;
+pub const __STATFS_MATCHES_STATFS64: u32 = 1;
+pub const __KERNEL_OLD_TIMEVAL_MATCHES_TIMEVAL64: u32 = 1;
+pub const __FD_SETSIZE: u32 = 1024;
+pub const _BITS_TIME64_H: u32 = 1;
+pub const _____fpos_t_defined: u32 = 1;
+pub const ____mbstate_t_defined: u32 = 1;
+pub const _____fpos64_t_defined: u32 = 1;
+pub const ____FILE_defined: u32 = 1;
+pub const __FILE_defined: u32 = 1;
+pub const __struct_FILE_defined: u32 = 1;
+pub const _IO_EOF_SEEN: u32 = 16;
+pub const _IO_ERR_SEEN: u32 = 32;
+pub const _IO_USER_LOCK: u32 = 32768;
+pub const __cookie_io_functions_t_defined: u32 = 1;
+pub const _IOFBF: u32 = 0;
+pub const _IOLBF: u32 = 1;
+pub const _IONBF: u32 = 2;
+pub const BUFSIZ: u32 = 8192;
+//@ common 1
+pub const SEEK_SET: u32 = 0;
+pub const SEEK_CUR: u32 = 1;
+pub const SEEK_END: u32 = 2;
+pub const P_tmpdir: &[u8; 5] = b"/tmp\0";
+pub const L_tmpnam: u32 = 20;
+pub const TMP_MAX: u32 = 238328;
+pub const _BITS_STDIO_LIM_H: u32 = 1;
+pub const FILENAME_MAX: u32 = 4096;
+pub const L_ctermid: u32 = 9;
+pub const FOPEN_MAX: u32 = 16;
+pub const __HAVE_FLOAT128: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT128: u32 = 0;
+pub const __HAVE_FLOAT64X: u32 = 1;
+pub const __HAVE_FLOAT64X_LONG_DOUBLE: u32 = 1;
+pub const __HAVE_FLOAT16: u32 = 0;
+pub const __HAVE_FLOAT32: u32 = 1;
+pub const __HAVE_FLOAT64: u32 = 1;
+pub const __HAVE_FLOAT32X: u32 = 1;
+pub const __HAVE_FLOAT128X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT16: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT32: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT64: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT32X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT64X: u32 = 0;
+pub const __HAVE_DISTINCT_FLOAT128X: u32 = 0;
+pub const __HAVE_FLOATN_NOT_TYPEDEF: u32 = 0;
+pub const _STDINT_H: u32 = 1;
+pub const _BITS_WCHAR_H: u32 = 1;
+pub const _BITS_STDINT_INTN_H: u32 = 1;
+pub const _BITS_STDINT_UINTN_H: u32 = 1;
+pub const _BITS_STDINT_LEAST_H: u32 = 1;
+pub const INT8_MIN: i32 = -128;
+pub const INT16_MIN: i32 = -32768;
+pub const INT32_MIN: i32 = -2147483648;
+pub const INT8_MAX: u32 = 127;
+pub const INT16_MAX: u32 = 32767;
+pub const INT32_MAX: u32 = 2147483647;
+pub const UINT8_MAX: u32 = 255;
+pub const UINT16_MAX: u32 = 65535;
+pub const UINT32_MAX: u32 = 4294967295;
+pub const INT_LEAST8_MIN: i32 = -128;
+pub const INT_LEAST16_MIN: i32 = -32768;
+pub const INT_LEAST32_MIN: i32 = -2147483648;
+pub const INT_LEAST8_MAX: u32 = 127;
+pub const INT_LEAST16_MAX: u32 = 32767;
+pub const INT_LEAST32_MAX: u32 = 2147483647;
+pub const UINT_LEAST8_MAX: u32 = 255;
+pub const UINT_LEAST16_MAX: u32 = 65535;
+pub const UINT_LEAST32_MAX: u32 = 4294967295;
+pub const INT_FAST8_MIN: i32 = -128;
+pub const INT_FAST16_MIN: i64 = -9223372036854775808;
+pub const INT_FAST32_MIN: i64 = -9223372036854775808;
+pub const INT_FAST8_MAX: u32 = 127;
+pub const INT_FAST16_MAX: u64 = 9223372036854775807;
+pub const INT_FAST32_MAX: u64 = 9223372036854775807;
+pub const UINT_FAST8_MAX: u32 = 255;
+pub const UINT_FAST16_MAX: i32 = -1;
+pub const UINT_FAST32_MAX: i32 = -1;
+pub const INTPTR_MIN: i64 = -9223372036854775808;
+pub const INTPTR_MAX: u64 = 9223372036854775807;
+pub const UINTPTR_MAX: i32 = -1;
+pub const PTRDIFF_MIN: i64 = -9223372036854775808;
+pub const PTRDIFF_MAX: u64 = 9223372036854775807;
+pub const SIG_ATOMIC_MIN: i32 = -2147483648;
+pub const SIG_ATOMIC_MAX: u32 = 2147483647;
+pub const SIZE_MAX: i32 = -1;
+pub const WINT_MIN: u32 = 0;
+pub const WINT_MAX: u32 = 4294967295;
+pub const _MATH_H: u32 = 1;
+pub const _BITS_LIBM_SIMD_DECL_STUBS_H: u32 = 1;
+pub const __FP_LOGB0_IS_MIN: u32 = 1;
+pub const __FP_LOGBNAN_IS_MIN: u32 = 1;
+pub const FP_ILOGB0: i32 = -2147483648;
+pub const FP_ILOGBNAN: i32 = -2147483648;
+pub const __MATH_DECLARING_DOUBLE: u32 = 1;
+pub const __MATH_DECLARING_FLOATN: u32 = 0;
+pub const __MATH_DECLARE_LDOUBLE: u32 = 1;
+//@ common 2
+pub const math_errhandling: u32 = 3;
+pub const M_E: f64 = 2.718281828459045;
+pub const M_LOG2E: f64 = 1.4426950408889634;
+pub const M_LOG10E: f64 = 0.4342944819032518;
+pub const M_LN2: f64 = 0.6931471805599453;
+pub const M_LN10: f64 = 2.302585092994046;
+pub const M_PI: f64 = 3.141592653589793;
+pub const M_PI_2: f64 = 1.5707963267948966;
+pub const M_PI_4: f64 = 0.7853981633974483;
+pub const M_1_PI: f64 = 0.3183098861837907;
+pub const M_2_PI: f64 = 0.6366197723675814;
+pub const M_2_SQRTPI: f64 = 1.1283791670955126;
+pub const M_SQRT2: f64 = 1.4142135623730951;
+pub const M_SQRT1_2: f64 = 0.7071067811865476;
+pub const _LIBC_LIMITS_H_: u32 = 1;
+pub const MB_LEN_MAX: u32 = 16;
+pub const _BITS_POSIX1_LIM_H: u32 = 1;
+pub const _POSIX_AIO_LISTIO_MAX: u32 = 2;
+pub const _POSIX_AIO_MAX: u32 = 1;
+pub const _POSIX_ARG_MAX: u32 = 4096;
+pub const _POSIX_CHILD_MAX: u32 = 25;
+pub const _POSIX_DELAYTIMER_MAX: u32 = 32;
+pub const _POSIX_HOST_NAME_MAX: u32 = 255;
+pub const _POSIX_LINK_MAX: u32 = 8;
+pub const _POSIX_LOGIN_NAME_MAX: u32 = 9;
+pub const _POSIX_MAX_CANON: u32 = 255;
+pub const _POSIX_MAX_INPUT: u32 = 255;
+pub const _POSIX_MQ_OPEN_MAX: u32 = 8;
+pub const _POSIX_MQ_PRIO_MAX: u32 = 32;
+pub const _POSIX_NAME_MAX: u32 = 14;
+pub const _POSIX_NGROUPS_MAX: u32 = 8;
+pub const _POSIX_OPEN_MAX: u32 = 20;
+pub const _POSIX_PATH_MAX: u32 = 256;
+pub const _POSIX_PIPE_BUF: u32 = 512;
+pub const _POSIX_RE_DUP_MAX: u32 = 255;
+pub const _POSIX_RTSIG_MAX: u32 = 8;
+pub const _POSIX_SEM_NSEMS_MAX: u32 = 256;
+pub const _POSIX_SEM_VALUE_MAX: u32 = 32767;
+pub const _POSIX_SIGQUEUE_MAX: u32 = 32;
+pub const _POSIX_SSIZE_MAX: u32 = 32767;
+pub const _POSIX_STREAM_MAX: u32 = 8;
+pub const _POSIX_SYMLINK_MAX: u32 = 255;
+pub const _POSIX_SYMLOOP_MAX: u32 = 8;
+pub const _POSIX_TIMER_MAX: u32 = 32;
+pub const _POSIX_TTY_NAME_MAX: u32 = 9;
+pub const _POSIX_TZNAME_MAX: u32 = 6;
+pub const _POSIX_CLOCKRES_MIN: u32 = 20000000;
+pub const NR_OPEN: u32 = 1024;
+pub const NGROUPS_MAX: u32 = 65536;
+pub const ARG_MAX: u32 = 131072;
+pub const LINK_MAX: u32 = 127;
+pub const MAX_CANON: u32 = 255;
+pub const MAX_INPUT: u32 = 255;
+pub const NAME_MAX: u32 = 255;
+pub const PATH_MAX: u32 = 4096;
+pub const PIPE_BUF: u32 = 4096;
+pub const XATTR_NAME_MAX: u32 = 255;
+pub const XATTR_SIZE_MAX: u32 = 65536;
+pub const XATTR_LIST_MAX: u32 = 65536;
+pub const RTSIG_MAX: u32 = 32;
+pub const _POSIX_THREAD_KEYS_MAX: u32 = 128;
+pub const PTHREAD_KEYS_MAX: u32 = 1024;
+pub const _POSIX_THREAD_DESTRUCTOR_ITERATIONS: u32 = 4;
+pub const PTHREAD_DESTRUCTOR_ITERATIONS: u32 = 4;
+pub const _POSIX_THREAD_THREADS_MAX: u32 = 64;
+pub const AIO_PRIO_DELTA_MAX: u32 = 20;
+pub const PTHREAD_STACK_MIN: u32 = 16384;
+pub const DELAYTIMER_MAX: u32 = 2147483647;
+pub const TTY_NAME_MAX: u32 = 32;
+pub const LOGIN_NAME_MAX: u32 = 256;
+pub const HOST_NAME_MAX: u32 = 64;
+pub const MQ_PRIO_MAX: u32 = 32768;
+pub const SEM_VALUE_MAX: u32 = 2147483647;
+pub const _BITS_POSIX2_LIM_H: u32 = 1;
+pub const _POSIX2_BC_BASE_MAX: u32 = 99;
+pub const _POSIX2_BC_DIM_MAX: u32 = 2048;
+pub const _POSIX2_BC_SCALE_MAX: u32 = 99;
+pub const _POSIX2_BC_STRING_MAX: u32 = 1000;
+pub const _POSIX2_COLL_WEIGHTS_MAX: u32 = 2;
+pub const _POSIX2_EXPR_NEST_MAX: u32 = 32;
+pub const _POSIX2_LINE_MAX: u32 = 2048;
+pub const _POSIX2_RE_DUP_MAX: u32 = 255;
+pub const _POSIX2_CHARCLASS_NAME_MAX: u32 = 14;
+pub const BC_BASE_MAX: u32 = 99;
+pub const BC_DIM_MAX: u32 = 2048;
+pub const BC_SCALE_MAX: u32 = 99;
+pub const BC_STRING_MAX: u32 = 1000;
+pub const COLL_WEIGHTS_MAX: u32 = 255;
+pub const EXPR_NEST_MAX: u32 = 32;
+pub const LINE_MAX: u32 = 2048;
+pub const CHARCLASS_NAME_MAX: u32 = 2048;
+pub const RE_DUP_MAX: u32 = 32767;
+pub const _ASSERT_H: u32 = 1;
+//@ common 13
+pub const SCIP_LONGINT_FORMAT: &[u8; 4] = b"lld\0";
+//@ common 20
+pub const SCIP_MAXMEMSIZE: u32 = 0;
+//@ common 12
+pub const _STDLIB_H: u32 = 1;
+pub const WNOHANG: u32 = 1;
+pub const WUNTRACED: u32 = 2;
+pub const WSTOPPED: u32 = 2;
+pub const WEXITED: u32 = 4;
+pub const WCONTINUED: u32 = 8;
+pub const WNOWAIT: u32 = 16777216;
+pub const __WNOTHREAD: u32 = 536870912;
+pub const __WALL: u32 = 1073741824;
+pub const __WCLONE: u32 = 2147483648;
+pub const __W_CONTINUED: u32 = 65535;
+pub const __WCOREFLAG: u32 = 128;
+pub const __ldiv_t_defined: u32 = 1;
+pub const __lldiv_t_defined: u32 = 1;
+pub const RAND_MAX: u32 = 2147483647;
+pub const EXIT_FAILURE: u32 = 1;
+pub const EXIT_SUCCESS: u32 = 0;
+pub const _SYS_TYPES_H: u32 = 1;
+pub const __clock_t_defined: u32 = 1;
+pub const __clockid_t_defined: u32 = 1;
+pub const __time_t_defined: u32 = 1;
+pub const __timer_t_defined: u32 = 1;
+pub const __BIT_TYPES_DEFINED__: u32 = 1;
+pub const _ENDIAN_H: u32 = 1;
+pub const _BITS_ENDIAN_H: u32 = 1;
+pub const __LITTLE_ENDIAN: u32 = 1234;
+pub const __BIG_ENDIAN: u32 = 4321;
+pub const __PDP_ENDIAN: u32 = 3412;
+pub const _BITS_ENDIANNESS_H: u32 = 1;
+pub const __BYTE_ORDER: u32 = 1234;
+pub const __FLOAT_WORD_ORDER: u32 = 1234;
+pub const LITTLE_ENDIAN: u32 = 1234;
+pub const BIG_ENDIAN: u32 = 4321;
+pub const PDP_ENDIAN: u32 = 3412;
+pub const BYTE_ORDER: u32 = 1234;
+pub const _BITS_BYTESWAP_H: u32 = 1;
+pub const _BITS_UINTN_IDENTITY_H: u32 = 1;
+pub const _SYS_SELECT_H: u32 = 1;
+pub const __sigset_t_defined: u32 = 1;
+pub const __timeval_defined: u32 = 1;
+pub const _STRUCT_TIMESPEC: u32 = 1;
+pub const FD_SETSIZE: u32 = 1024;
+pub const _BITS_PTHREADTYPES_COMMON_H: u32 = 1;
+pub const _THREAD_SHARED_TYPES_H: u32 = 1;
+pub const _BITS_PTHREADTYPES_ARCH_H: u32 = 1;
+pub const __SIZEOF_PTHREAD_MUTEX_T: u32 = 40;
+pub const __SIZEOF_PTHREAD_ATTR_T: u32 = 56;
+pub const __SIZEOF_PTHREAD_RWLOCK_T: u32 = 56;
+pub const __SIZEOF_PTHREAD_BARRIER_T: u32 = 32;
+pub const __SIZEOF_PTHREAD_MUTEXATTR_T: u32 = 4;
+pub const __SIZEOF_PTHREAD_COND_T: u32 = 48;
+pub const __SIZEOF_PTHREAD_CONDATTR_T: u32 = 4;
+pub const __SIZEOF_PTHREAD_RWLOCKATTR_T: u32 = 8;
+pub const __SIZEOF_PTHREAD_BARRIERATTR_T: u32 = 4;
+pub const _THREAD_MUTEX_INTERNAL_H: u32 = 1;
+pub const __PTHREAD_MUTEX_HAVE_PREV: u32 = 1;
+pub const __have_pthread_attr_t: u32 = 1;
+pub const _ALLOCA_H: u32 = 1;
+//@ common 27
+pub const _INTTYPES_H: u32 = 1;
+pub const ____gwchar_t_defined: u32 = 1;
+pub const __PRI64_PREFIX: &[u8; 2] = b"l\0";
+pub const __PRIPTR_PREFIX: &[u8; 2] = b"l\0";
+pub const PRId8: &[u8; 2] = b"d\0";
+pub const PRId16: &[u8; 2] = b"d\0";
+//@ common 1
+pub const PRId64: &[u8; 3] = b"ld\0";
+pub const PRIdLEAST8: &[u8; 2] = b"d\0";
+pub const PRIdLEAST16: &[u8; 2] = b"d\0";
+pub const PRIdLEAST32: &[u8; 2] = b"d\0";
+pub const PRIdLEAST64: &[u8; 3] = b"ld\0";
+pub const PRIdFAST8: &[u8; 2] = b"d\0";
+pub const PRIdFAST16: &[u8; 3] = b"ld\0";
+pub const PRIdFAST32: &[u8; 3] = b"ld\0";
+pub const PRIdFAST64: &[u8; 3] = b"ld\0";
+pub const PRIi8: &[u8; 2] = b"i\0";
+pub const PRIi16: &[u8; 2] = b"i\0";
+//@ common 1
+pub const PRIi64: &[u8; 3] = b"li\0";
+pub const PRIiLEAST8: &[u8; 2] = b"i\0";
+pub const PRIiLEAST16: &[u8; 2] = b"i\0";
+pub const PRIiLEAST32: &[u8; 2] = b"i\0";
+pub const PRIiLEAST64: &[u8; 3] = b"li\0";
+pub const PRIiFAST8: &[u8; 2] = b"i\0";
+pub const PRIiFAST16: &[u8; 3] = b"li\0";
+pub const PRIiFAST32: &[u8; 3] = b"li\0";
+pub const PRIiFAST64: &[u8; 3] = b"li\0";
+pub const PRIo8: &[u8; 2] = b"o\0";
+pub const PRIo16: &[u8; 2] = b"o\0";
+//@ common 1
+pub const PRIo64: &[u8; 3] = b"lo\0";
+pub const PRIoLEAST8: &[u8; 2] = b"o\0";
+pub const PRIoLEAST16: &[u8; 2] = b"o\0";
+pub const PRIoLEAST32: &[u8; 2] = b"o\0";
+pub const PRIoLEAST64: &[u8; 3] = b"lo\0";
+pub const PRIoFAST8: &[u8; 2] = b"o\0";
+pub const PRIoFAST16: &[u8; 3] = b"lo\0";
+pub const PRIoFAST32: &[u8; 3] = b"lo\0";
+pub const PRIoFAST64: &[u8; 3] = b"lo\0";
+pub const PRIu8: &[u8; 2] = b"u\0";
+pub const PRIu16: &[u8; 2] = b"u\0";
+//@ common 1
+pub const PRIu64: &[u8; 3] = b"lu\0";
+pub const PRIuLEAST8: &[u8; 2] = b"u\0";
+pub const PRIuLEAST16: &[u8; 2] = b"u\0";
+pub const PRIuLEAST32: &[u8; 2] = b"u\0";
+pub const PRIuLEAST64: &[u8; 3] = b"lu\0";
+pub const PRIuFAST8: &[u8; 2] = b"u\0";
+pub const PRIuFAST16: &[u8; 3] = b"lu\0";
+pub const PRIuFAST32: &[u8; 3] = b"lu\0";
+pub const PRIuFAST64: &[u8; 3] = b"lu\0";
+pub const PRIx8: &[u8; 2] = b"x\0";
+pub const PRIx16: &[u8; 2] = b"x\0";
+//@ common 1
+pub const PRIx64: &[u8; 3] = b"lx\0";
+pub const PRIxLEAST8: &[u8; 2] = b"x\0";
+pub const PRIxLEAST16: &[u8; 2] = b"x\0";
+pub const PRIxLEAST32: &[u8; 2] = b"x\0";
+pub const PRIxLEAST64: &[u8; 3] = b"lx\0";
+pub const PRIxFAST8: &[u8; 2] = b"x\0";
+pub const PRIxFAST16: &[u8; 3] = b"lx\0";
+pub const PRIxFAST32: &[u8; 3] = b"lx\0";
+pub const PRIxFAST64: &[u8; 3] = b"lx\0";
+pub const PRIX8: &[u8; 2] = b"X\0";
+pub const PRIX16: &[u8; 2] = b"X\0";
+//@ common 1
+pub const PRIX64: &[u8; 3] = b"lX\0";
+pub const PRIXLEAST8: &[u8; 2] = b"X\0";
+pub const PRIXLEAST16: &[u8; 2] = b"X\0";
+//@ common 1
+pub const PRIXLEAST64: &[u8; 3] = b"lX\0";
+pub const PRIXFAST8: &[u8; 2] = b"X\0";
+pub const PRIXFAST16: &[u8; 3] = b"lX\0";
+pub const PRIXFAST32: &[u8; 3] = b"lX\0";
+pub const PRIXFAST64: &[u8; 3] = b"lX\0";
+pub const PRIdMAX: &[u8; 3] = b"ld\0";
+pub const PRIiMAX: &[u8; 3] = b"li\0";
+pub const PRIoMAX: &[u8; 3] = b"lo\0";
+pub const PRIuMAX: &[u8; 3] = b"lu\0";
+pub const PRIxMAX: &[u8; 3] = b"lx\0";
+pub const PRIXMAX: &[u8; 3] = b"lX\0";
+pub const PRIdPTR: &[u8; 3] = b"ld\0";
+pub const PRIiPTR: &[u8; 3] = b"li\0";
+pub const PRIoPTR: &[u8; 3] = b"lo\0";
+pub const PRIuPTR: &[u8; 3] = b"lu\0";
+pub const PRIxPTR: &[u8; 3] = b"lx\0";
+pub const PRIXPTR: &[u8; 3] = b"lX\0";
+//@ common 1
+pub const SCNd16: &[u8; 3] = b"hd\0";
+pub const SCNd32: &[u8; 2] = b"d\0";
+pub const SCNd64: &[u8; 3] = b"ld\0";
+pub const SCNdLEAST8: &[u8; 4] = b"hhd\0";
+pub const SCNdLEAST16: &[u8; 3] = b"hd\0";
+pub const SCNdLEAST32: &[u8; 2] = b"d\0";
+pub const SCNdLEAST64: &[u8; 3] = b"ld\0";
+pub const SCNdFAST8: &[u8; 4] = b"hhd\0";
+pub const SCNdFAST16: &[u8; 3] = b"ld\0";
+pub const SCNdFAST32: &[u8; 3] = b"ld\0";
+pub const SCNdFAST64: &[u8; 3] = b"ld\0";
+//@ common 1
+pub const SCNi16: &[u8; 3] = b"hi\0";
+pub const SCNi32: &[u8; 2] = b"i\0";
+pub const SCNi64: &[u8; 3] = b"li\0";
+pub const SCNiLEAST8: &[u8; 4] = b"hhi\0";
+pub const SCNiLEAST16: &[u8; 3] = b"hi\0";
+pub const SCNiLEAST32: &[u8; 2] = b"i\0";
+pub const SCNiLEAST64: &[u8; 3] = b"li\0";
+pub const SCNiFAST8: &[u8; 4] = b"hhi\0";
+pub const SCNiFAST16: &[u8; 3] = b"li\0";
+pub const SCNiFAST32: &[u8; 3] = b"li\0";
+pub const SCNiFAST64: &[u8; 3] = b"li\0";
+pub const SCNu8: &[u8; 4] = b"hhu\0";
+pub const SCNu16: &[u8; 3] = b"hu\0";
+pub const SCNu32: &[u8; 2] = b"u\0";
+pub const SCNu64: &[u8; 3] = b"lu\0";
+pub const SCNuLEAST8: &[u8; 4] = b"hhu\0";
+pub const SCNuLEAST16: &[u8; 3] = b"hu\0";
+pub const SCNuLEAST32: &[u8; 2] = b"u\0";
+pub const SCNuLEAST64: &[u8; 3] = b"lu\0";
+pub const SCNuFAST8: &[u8; 4] = b"hhu\0";
+pub const SCNuFAST16: &[u8; 3] = b"lu\0";
+pub const SCNuFAST32: &[u8; 3] = b"lu\0";
+pub const SCNuFAST64: &[u8; 3] = b"lu\0";
+//@ common 1
+pub const SCNo16: &[u8; 3] = b"ho\0";
+pub const SCNo32: &[u8; 2] = b"o\0";
+pub const SCNo64: &[u8; 3] = b"lo\0";
+pub const SCNoLEAST8: &[u8; 4] = b"hho\0";
+pub const SCNoLEAST16: &[u8; 3] = b"ho\0";
+pub const SCNoLEAST32: &[u8; 2] = b"o\0";
+pub const SCNoLEAST64: &[u8; 3] = b"lo\0";
+pub const SCNoFAST8: &[u8; 4] = b"hho\0";
+pub const SCNoFAST16: &[u8; 3] = b"lo\0";
+pub const SCNoFAST32: &[u8; 3] = b"lo\0";
+pub const SCNoFAST64: &[u8; 3] = b"lo\0";
+//@ common 3
+pub const SCNx64: &[u8; 3] = b"lx\0";
+//@ common 3
+pub const SCNxLEAST64: &[u8; 3] = b"lx\0";
+//@ common 1
+pub const SCNxFAST16: &[u8; 3] = b"lx\0";
+pub const SCNxFAST32: &[u8; 3] = b"lx\0";
+pub const SCNxFAST64: &[u8; 3] = b"lx\0";
+pub const SCNdMAX: &[u8; 3] = b"ld\0";
+pub const SCNiMAX: &[u8; 3] = b"li\0";
+pub const SCNoMAX: &[u8; 3] = b"lo\0";
+pub const SCNuMAX: &[u8; 3] = b"lu\0";
+pub const SCNxMAX: &[u8; 3] = b"lx\0";
+pub const SCNdPTR: &[u8; 3] = b"ld\0";
+pub const SCNiPTR: &[u8; 3] = b"li\0";
+pub const SCNoPTR: &[u8; 3] = b"lo\0";
+pub const SCNuPTR: &[u8; 3] = b"lu\0";
+pub const SCNxPTR: &[u8; 3] = b"lx\0";
+pub const SCIP_EVENTTYPE_FORMAT: &[u8; 3] = b"lx\0";
+//@ common 34
+pub const _STRING_H: u32 = 1;
+pub const _BITS_TYPES_LOCALE_T_H: u32 = 1;
+pub const _BITS_TYPES___LOCALE_T_H: u32 = 1;
+pub const _STRINGS_H: u32 = 1;
+//@ common 18
+pub type __gnuc_va_list = __builtin_va_list;
+pub type __u_char = ::std::os::raw::c_uchar;
+pub type __u_short = ::std::os::raw::c_ushort;
+pub type __u_int = ::std::os::raw::c_uint;
+pub type __u_long = ::std::os::raw::c_ulong;
+pub type __int8_t = ::std::os::raw::c_schar;
+pub type __uint8_t = ::std::os::raw::c_uchar;
+pub type __int16_t = ::std::os::raw::c_short;
+pub type __uint16_t = ::std::os::raw::c_ushort;
+pub type __int32_t = ::std::os::raw::c_int;
+pub type __uint32_t = ::std::os::raw::c_uint;
+pub type __int64_t = ::std::os::raw::c_long;
+pub type __uint64_t = ::std::os::raw::c_ulong;
+pub type __int_least8_t = __int8_t;
+pub type __uint_least8_t = __uint8_t;
+pub type __int_least16_t = __int16_t;
+pub type __uint_least16_t = __uint16_t;
+pub type __int_least32_t = __int32_t;
+pub type __uint_least32_t = __uint32_t;
+pub type __int_least64_t = __int64_t;
+pub type __uint_least64_t = __uint64_t;
+pub type __quad_t = ::std::os::raw::c_long;
+pub type __u_quad_t = ::std::os::raw::c_ulong;
+pub type __intmax_t = ::std::os::raw::c_long;
+pub type __uintmax_t = ::std::os::raw::c_ulong;
+pub type __dev_t = ::std::os::raw::c_ulong;
+pub type __uid_t = ::std::os::raw::c_uint;
+pub type __gid_t = ::std::os::raw::c_uint;
+pub type __ino_t = ::std::os::raw::c_ulong;
+pub type __ino64_t = ::std::os::raw::c_ulong;
+pub type __mode_t = ::std::os::raw::c_uint;
+pub type __nlink_t = ::std::os::raw::c_ulong;
+pub type __off_t = ::std::os::raw::c_long;
+pub type __off64_t = ::std::os::raw::c_long;
+pub type __pid_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __fsid_t {
+    pub __val: [::std::os::raw::c_int; 2usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __fsid_t"][::std::mem::size_of::<__fsid_t>() - 8usize];
+    ["Alignment of __fsid_t"][::std::mem::align_of::<__fsid_t>() - 4usize];
+    ["Offset of field: __fsid_t::__val"][::std::mem::offset_of!(__fsid_t, __val) - 0usize];
+};
+pub type __clock_t = ::std::os::raw::c_long;
+pub type __rlim_t = ::std::os::raw::c_ulong;
+pub type __rlim64_t = ::std::os::raw::c_ulong;
+pub type __id_t = ::std::os::raw::c_uint;
+pub type __time_t = ::std::os::raw::c_long;
+pub type __useconds_t = ::std::os::raw::c_uint;
+pub type __suseconds_t = ::std::os::raw::c_long;
+pub type __suseconds64_t = ::std::os::raw::c_long;
+pub type __daddr_t = ::std::os::raw::c_int;
+pub type __key_t = ::std::os::raw::c_int;
+pub type __clockid_t = ::std::os::raw::c_int;
+pub type __timer_t = *mut ::std::os::raw::c_void;
+pub type __blksize_t = ::std::os::raw::c_long;
+pub type __blkcnt_t = ::std::os::raw::c_long;
+pub type __blkcnt64_t = ::std::os::raw::c_long;
+pub type __fsblkcnt_t = ::std::os::raw::c_ulong;
+pub type __fsblkcnt64_t = ::std::os::raw::c_ulong;
+pub type __fsfilcnt_t = ::std::os::raw::c_ulong;
+pub type __fsfilcnt64_t = ::std::os::raw::c_ulong;
+pub type __fsword_t = ::std::os::raw::c_long;
+pub type __ssize_t = ::std::os::raw::c_long;
+pub type __syscall_slong_t = ::std::os::raw::c_long;
+pub type __syscall_ulong_t = ::std::os::raw::c_ulong;
+pub type __loff_t = __off64_t;
+pub type __caddr_t = *mut ::std::os::raw::c_char;
+pub type __intptr_t = ::std::os::raw::c_long;
+pub type __socklen_t = ::std::os::raw::c_uint;
+pub type __sig_atomic_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __mbstate_t {
+    pub __count: ::std::os::raw::c_int,
+    pub __value: __mbstate_t__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union __mbstate_t__bindgen_ty_1 {
+    pub __wch: ::std::os::raw::c_uint,
+    pub __wchb: [::std::os::raw::c_char; 4usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __mbstate_t__bindgen_ty_1"]
+        [::std::mem::size_of::<__mbstate_t__bindgen_ty_1>() - 4usize];
+    ["Alignment of __mbstate_t__bindgen_ty_1"]
+        [::std::mem::align_of::<__mbstate_t__bindgen_ty_1>() - 4usize];
+    ["Offset of field: __mbstate_t__bindgen_ty_1::__wch"]
+        [::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wch) - 0usize];
+    ["Offset of field: __mbstate_t__bindgen_ty_1::__wchb"]
+        [::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wchb) - 0usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __mbstate_t"][::std::mem::size_of::<__mbstate_t>() - 8usize];
+    ["Alignment of __mbstate_t"][::std::mem::align_of::<__mbstate_t>() - 4usize];
+    ["Offset of field: __mbstate_t::__count"]
+        [::std::mem::offset_of!(__mbstate_t, __count) - 0usize];
+    ["Offset of field: __mbstate_t::__value"]
+        [::std::mem::offset_of!(__mbstate_t, __value) - 4usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _G_fpos_t {
+    pub __pos: __off_t,
+    pub __state: __mbstate_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of _G_fpos_t"][::std::mem::size_of::<_G_fpos_t>() - 16usize];
+    ["Alignment of _G_fpos_t"][::std::mem::align_of::<_G_fpos_t>() - 8usize];
+    ["Offset of field: _G_fpos_t::__pos"][::std::mem::offset_of!(_G_fpos_t, __pos) - 0usize];
+    ["Offset of field: _G_fpos_t::__state"][::std::mem::offset_of!(_G_fpos_t, __state) - 8usize];
+};
+pub type __fpos_t = _G_fpos_t;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _G_fpos64_t {
+    pub __pos: __off64_t,
+    pub __state: __mbstate_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of _G_fpos64_t"][::std::mem::size_of::<_G_fpos64_t>() - 16usize];
+    ["Alignment of _G_fpos64_t"][::std::mem::align_of::<_G_fpos64_t>() - 8usize];
+    ["Offset of field: _G_fpos64_t::__pos"][::std::mem::offset_of!(_G_fpos64_t, __pos) - 0usize];
+    ["Offset of field: _G_fpos64_t::__state"]
+        [::std::mem::offset_of!(_G_fpos64_t, __state) - 8usize];
+};
+pub type __fpos64_t = _G_fpos64_t;
+pub type __FILE = _IO_FILE;
+pub type FILE = _IO_FILE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_marker {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_codecvt {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_wide_data {
+    _unused: [u8; 0],
+}
+pub type _IO_lock_t = ::std::os::raw::c_void;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_FILE {
+    pub _flags: ::std::os::raw::c_int,
+    pub _IO_read_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_read_end: *mut ::std::os::raw::c_char,
+    pub _IO_read_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_base: *mut ::std::os::raw::c_char,
+    pub _IO_write_ptr: *mut ::std::os::raw::c_char,
+    pub _IO_write_end: *mut ::std::os::raw::c_char,
+    pub _IO_buf_base: *mut ::std::os::raw::c_char,
+    pub _IO_buf_end: *mut ::std::os::raw::c_char,
+    pub _IO_save_base: *mut ::std::os::raw::c_char,
+    pub _IO_backup_base: *mut ::std::os::raw::c_char,
+    pub _IO_save_end: *mut ::std::os::raw::c_char,
+    pub _markers: *mut _IO_marker,
+    pub _chain: *mut _IO_FILE,
+    pub _fileno: ::std::os::raw::c_int,
+    pub _flags2: ::std::os::raw::c_int,
+    pub _old_offset: __off_t,
+    pub _cur_column: ::std::os::raw::c_ushort,
+    pub _vtable_offset: ::std::os::raw::c_schar,
+    pub _shortbuf: [::std::os::raw::c_char; 1usize],
+    pub _lock: *mut _IO_lock_t,
+    pub _offset: __off64_t,
+    pub _codecvt: *mut _IO_codecvt,
+    pub _wide_data: *mut _IO_wide_data,
+    pub _freeres_list: *mut _IO_FILE,
+    pub _freeres_buf: *mut ::std::os::raw::c_void,
+    pub __pad5: usize,
+    pub _mode: ::std::os::raw::c_int,
+    pub _unused2: [::std::os::raw::c_char; 20usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of _IO_FILE"][::std::mem::size_of::<_IO_FILE>() - 216usize];
+    ["Alignment of _IO_FILE"][::std::mem::align_of::<_IO_FILE>() - 8usize];
+    ["Offset of field: _IO_FILE::_flags"][::std::mem::offset_of!(_IO_FILE, _flags) - 0usize];
+    ["Offset of field: _IO_FILE::_IO_read_ptr"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_read_ptr) - 8usize];
+    ["Offset of field: _IO_FILE::_IO_read_end"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_read_end) - 16usize];
+    ["Offset of field: _IO_FILE::_IO_read_base"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_read_base) - 24usize];
+    ["Offset of field: _IO_FILE::_IO_write_base"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_write_base) - 32usize];
+    ["Offset of field: _IO_FILE::_IO_write_ptr"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_write_ptr) - 40usize];
+    ["Offset of field: _IO_FILE::_IO_write_end"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_write_end) - 48usize];
+    ["Offset of field: _IO_FILE::_IO_buf_base"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_buf_base) - 56usize];
+    ["Offset of field: _IO_FILE::_IO_buf_end"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_buf_end) - 64usize];
+    ["Offset of field: _IO_FILE::_IO_save_base"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_save_base) - 72usize];
+    ["Offset of field: _IO_FILE::_IO_backup_base"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_backup_base) - 80usize];
+    ["Offset of field: _IO_FILE::_IO_save_end"]
+        [::std::mem::offset_of!(_IO_FILE, _IO_save_end) - 88usize];
+    ["Offset of field: _IO_FILE::_markers"][::std::mem::offset_of!(_IO_FILE, _markers) - 96usize];
+    ["Offset of field: _IO_FILE::_chain"][::std::mem::offset_of!(_IO_FILE, _chain) - 104usize];
+    ["Offset of field: _IO_FILE::_fileno"][::std::mem::offset_of!(_IO_FILE, _fileno) - 112usize];
+    ["Offset of field: _IO_FILE::_flags2"][::std::mem::offset_of!(_IO_FILE, _flags2) - 116usize];
+    ["Offset of field: _IO_FILE::_old_offset"]
+        [::std::mem::offset_of!(_IO_FILE, _old_offset) - 120usize];
+    ["Offset of field: _IO_FILE::_cur_column"]
+        [::std::mem::offset_of!(_IO_FILE, _cur_column) - 128usize];
+    ["Offset of field: _IO_FILE::_vtable_offset"]
+        [::std::mem::offset_of!(_IO_FILE, _vtable_offset) - 130usize];
+    ["Offset of field: _IO_FILE::_shortbuf"]
+        [::std::mem::offset_of!(_IO_FILE, _shortbuf) - 131usize];
+    ["Offset of field: _IO_FILE::_lock"][::std::mem::offset_of!(_IO_FILE, _lock) - 136usize];
+    ["Offset of field: _IO_FILE::_offset"][::std::mem::offset_of!(_IO_FILE, _offset) - 144usize];
+    ["Offset of field: _IO_FILE::_codecvt"][::std::mem::offset_of!(_IO_FILE, _codecvt) - 152usize];
+    ["Offset of field: _IO_FILE::_wide_data"]
+        [::std::mem::offset_of!(_IO_FILE, _wide_data) - 160usize];
+    ["Offset of field: _IO_FILE::_freeres_list"]
+        [::std::mem::offset_of!(_IO_FILE, _freeres_list) - 168usize];
+    ["Offset of field: _IO_FILE::_freeres_buf"]
+        [::std::mem::offset_of!(_IO_FILE, _freeres_buf) - 176usize];
+    ["Offset of field: _IO_FILE::__pad5"][::std::mem::offset_of!(_IO_FILE, __pad5) - 184usize];
+    ["Offset of field: _IO_FILE::_mode"][::std::mem::offset_of!(_IO_FILE, _mode) - 192usize];
+    ["Offset of field: _IO_FILE::_unused2"][::std::mem::offset_of!(_IO_FILE, _unused2) - 196usize];
+};
+pub type cookie_read_function_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        __cookie: *mut ::std::os::raw::c_void,
+        __buf: *mut ::std::os::raw::c_char,
+        __nbytes: usize,
+    ) -> __ssize_t,
+>;
+pub type cookie_write_function_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        __cookie: *mut ::std::os::raw::c_void,
+        __buf: *const ::std::os::raw::c_char,
+        __nbytes: usize,
+    ) -> __ssize_t,
+>;
+pub type cookie_seek_function_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        __cookie: *mut ::std::os::raw::c_void,
+        __pos: *mut __off64_t,
+        __w: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type cookie_close_function_t = ::std::option::Option<
+    unsafe extern "C" fn(__cookie: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_cookie_io_functions_t {
+    pub read: cookie_read_function_t,
+    pub write: cookie_write_function_t,
+    pub seek: cookie_seek_function_t,
+    pub close: cookie_close_function_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of _IO_cookie_io_functions_t"]
+        [::std::mem::size_of::<_IO_cookie_io_functions_t>() - 32usize];
+    ["Alignment of _IO_cookie_io_functions_t"]
+        [::std::mem::align_of::<_IO_cookie_io_functions_t>() - 8usize];
+    ["Offset of field: _IO_cookie_io_functions_t::read"]
+        [::std::mem::offset_of!(_IO_cookie_io_functions_t, read) - 0usize];
+    ["Offset of field: _IO_cookie_io_functions_t::write"]
+        [::std::mem::offset_of!(_IO_cookie_io_functions_t, write) - 8usize];
+    ["Offset of field: _IO_cookie_io_functions_t::seek"]
+        [::std::mem::offset_of!(_IO_cookie_io_functions_t, seek) - 16usize];
+    ["Offset of field: _IO_cookie_io_functions_t::close"]
+        [::std::mem::offset_of!(_IO_cookie_io_functions_t, close) - 24usize];
+};
+pub type cookie_io_functions_t = _IO_cookie_io_functions_t;
+pub type va_list = __gnuc_va_list;
+pub type off_t = __off_t;
+pub type fpos_t = __fpos_t;
+unsafe extern "C" {
+    pub static mut stdin: *mut FILE;
+}
+unsafe extern "C" {
+    pub static mut stdout: *mut FILE;
+}
+unsafe extern "C" {
+    pub static mut stderr: *mut FILE;
+}
+unsafe extern "C" {
+    pub fn remove(__filename: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn rename(
+        __old: *const ::std::os::raw::c_char,
+        __new: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn renameat(
+        __oldfd: ::std::os::raw::c_int,
+        __old: *const ::std::os::raw::c_char,
+        __newfd: ::std::os::raw::c_int,
+        __new: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fclose(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+//@ common 3
+unsafe extern "C" {
+    pub fn tmpnam(arg1: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn tmpnam_r(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn tempnam(
+        __dir: *const ::std::os::raw::c_char,
+        __pfx: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn fflush(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fflush_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fopen(
+        __filename: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn freopen(
+        __filename: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+        __stream: *mut FILE,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn fdopen(__fd: ::std::os::raw::c_int, __modes: *const ::std::os::raw::c_char)
+    -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn fopencookie(
+        __magic_cookie: *mut ::std::os::raw::c_void,
+        __modes: *const ::std::os::raw::c_char,
+        __io_funcs: cookie_io_functions_t,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn fmemopen(
+        __s: *mut ::std::os::raw::c_void,
+        __len: usize,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn open_memstream(
+        __bufloc: *mut *mut ::std::os::raw::c_char,
+        __sizeloc: *mut usize,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn setbuf(__stream: *mut FILE, __buf: *mut ::std::os::raw::c_char);
+}
+unsafe extern "C" {
+    pub fn setvbuf(
+        __stream: *mut FILE,
+        __buf: *mut ::std::os::raw::c_char,
+        __modes: ::std::os::raw::c_int,
+        __n: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn setbuffer(__stream: *mut FILE, __buf: *mut ::std::os::raw::c_char, __size: usize);
+}
+unsafe extern "C" {
+    pub fn setlinebuf(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn fprintf(
+        __stream: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn printf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn sprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vfprintf(
+        __s: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vprintf(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vsprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn snprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __maxlen: ::std::os::raw::c_ulong,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vsnprintf(
+        __s: *mut ::std::os::raw::c_char,
+        __maxlen: ::std::os::raw::c_ulong,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vasprintf(
+        __ptr: *mut *mut ::std::os::raw::c_char,
+        __f: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __asprintf(
+        __ptr: *mut *mut ::std::os::raw::c_char,
+        __fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn asprintf(
+        __ptr: *mut *mut ::std::os::raw::c_char,
+        __fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vdprintf(
+        __fd: ::std::os::raw::c_int,
+        __fmt: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn dprintf(
+        __fd: ::std::os::raw::c_int,
+        __fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fscanf(
+        __stream: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn scanf(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn sscanf(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+pub type _Float32 = f32;
+pub type _Float64 = f64;
+pub type _Float32x = f64;
+pub type _Float64x = u128;
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_fscanf"]
+    pub fn fscanf1(
+        __stream: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_scanf"]
+    pub fn scanf1(__format: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_sscanf"]
+    pub fn sscanf1(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vfscanf(
+        __s: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vscanf(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn vsscanf(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_vfscanf"]
+    pub fn vfscanf1(
+        __s: *mut FILE,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_vscanf"]
+    pub fn vscanf1(
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__isoc99_vsscanf"]
+    pub fn vsscanf1(
+        __s: *const ::std::os::raw::c_char,
+        __format: *const ::std::os::raw::c_char,
+        __arg: *mut __va_list_tag,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fgetc(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getc(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getchar() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getc_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getchar_unlocked() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fgetc_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fputc(__c: ::std::os::raw::c_int, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn putc(__c: ::std::os::raw::c_int, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn putchar(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fputc_unlocked(__c: ::std::os::raw::c_int, __stream: *mut FILE)
+    -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn putc_unlocked(__c: ::std::os::raw::c_int, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn putchar_unlocked(__c: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getw(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn putw(__w: ::std::os::raw::c_int, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fgets(
+        __s: *mut ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_int,
+        __stream: *mut FILE,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn __getdelim(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut usize,
+        __delimiter: ::std::os::raw::c_int,
+        __stream: *mut FILE,
+    ) -> __ssize_t;
+}
+unsafe extern "C" {
+    pub fn getdelim(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut usize,
+        __delimiter: ::std::os::raw::c_int,
+        __stream: *mut FILE,
+    ) -> __ssize_t;
+}
+unsafe extern "C" {
+    pub fn getline(
+        __lineptr: *mut *mut ::std::os::raw::c_char,
+        __n: *mut usize,
+        __stream: *mut FILE,
+    ) -> __ssize_t;
+}
+unsafe extern "C" {
+    pub fn fputs(__s: *const ::std::os::raw::c_char, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn puts(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ungetc(__c: ::std::os::raw::c_int, __stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fread(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+        __n: ::std::os::raw::c_ulong,
+        __stream: *mut FILE,
+    ) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn fwrite(
+        __ptr: *const ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+        __n: ::std::os::raw::c_ulong,
+        __s: *mut FILE,
+    ) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn fread_unlocked(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: usize,
+        __n: usize,
+        __stream: *mut FILE,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn fwrite_unlocked(
+        __ptr: *const ::std::os::raw::c_void,
+        __size: usize,
+        __n: usize,
+        __stream: *mut FILE,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn fseek(
+        __stream: *mut FILE,
+        __off: ::std::os::raw::c_long,
+        __whence: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ftell(__stream: *mut FILE) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn rewind(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn fseeko(
+        __stream: *mut FILE,
+        __off: __off_t,
+        __whence: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ftello(__stream: *mut FILE) -> __off_t;
+}
+unsafe extern "C" {
+    pub fn fgetpos(__stream: *mut FILE, __pos: *mut fpos_t) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fsetpos(__stream: *mut FILE, __pos: *const fpos_t) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn clearerr(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn feof(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ferror(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn clearerr_unlocked(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn feof_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ferror_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn perror(__s: *const ::std::os::raw::c_char);
+}
+unsafe extern "C" {
+    pub fn fileno(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fileno_unlocked(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn pclose(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn popen(
+        __command: *const ::std::os::raw::c_char,
+        __modes: *const ::std::os::raw::c_char,
+    ) -> *mut FILE;
+}
+unsafe extern "C" {
+    pub fn ctermid(__s: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn flockfile(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn ftrylockfile(__stream: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn funlockfile(__stream: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn __uflow(arg1: *mut FILE) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __overflow(arg1: *mut FILE, arg2: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+pub type int_least8_t = __int_least8_t;
+pub type int_least16_t = __int_least16_t;
+pub type int_least32_t = __int_least32_t;
+pub type int_least64_t = __int_least64_t;
+pub type uint_least8_t = __uint_least8_t;
+pub type uint_least16_t = __uint_least16_t;
+pub type uint_least32_t = __uint_least32_t;
+pub type uint_least64_t = __uint_least64_t;
+pub type int_fast8_t = ::std::os::raw::c_schar;
+pub type int_fast16_t = ::std::os::raw::c_long;
+pub type int_fast32_t = ::std::os::raw::c_long;
+pub type int_fast64_t = ::std::os::raw::c_long;
+pub type uint_fast8_t = ::std::os::raw::c_uchar;
+pub type uint_fast16_t = ::std::os::raw::c_ulong;
+pub type uint_fast32_t = ::std::os::raw::c_ulong;
+pub type uint_fast64_t = ::std::os::raw::c_ulong;
+pub type intmax_t = __intmax_t;
+pub type uintmax_t = __uintmax_t;
+//@ common 2
+unsafe extern "C" {
+    pub fn __fpclassify(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __signbit(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isinf(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __finite(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isnan(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __iseqsig(__x: f64, __y: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __issignaling(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn acos(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __acos(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn asin(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __asin(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn atan(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __atan(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn atan2(__y: f64, __x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __atan2(__y: f64, __x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn cos(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __cos(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn sin(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __sin(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn tan(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __tan(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn cosh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __cosh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn sinh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __sinh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn tanh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __tanh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn acosh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __acosh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn asinh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __asinh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn atanh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __atanh(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn exp(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __exp(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn frexp(__x: f64, __exponent: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn __frexp(__x: f64, __exponent: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn ldexp(__x: f64, __exponent: ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn __ldexp(__x: f64, __exponent: ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn log(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __log(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn log10(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __log10(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn modf(__x: f64, __iptr: *mut f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __modf(__x: f64, __iptr: *mut f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn expm1(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __expm1(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn log1p(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __log1p(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn logb(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __logb(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn exp2(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __exp2(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn log2(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __log2(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn pow(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __pow(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn sqrt(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __sqrt(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn hypot(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __hypot(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn cbrt(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __cbrt(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn ceil(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __ceil(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn fabs(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fabs(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn floor(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __floor(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn fmod(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fmod(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn isinf(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn finite(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn drem(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __drem(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn significand(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __significand(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn copysign(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __copysign(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn nan(__tagb: *const ::std::os::raw::c_char) -> f64;
+}
+unsafe extern "C" {
+    pub fn __nan(__tagb: *const ::std::os::raw::c_char) -> f64;
+}
+unsafe extern "C" {
+    pub fn isnan(__value: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn j0(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __j0(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn j1(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __j1(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn jn(arg1: ::std::os::raw::c_int, arg2: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __jn(arg1: ::std::os::raw::c_int, arg2: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn y0(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __y0(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn y1(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __y1(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn yn(arg1: ::std::os::raw::c_int, arg2: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __yn(arg1: ::std::os::raw::c_int, arg2: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn erf(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __erf(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn erfc(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __erfc(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn lgamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __lgamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn tgamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __tgamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn gamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __gamma(arg1: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn lgamma_r(arg1: f64, __signgamp: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn __lgamma_r(arg1: f64, __signgamp: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn rint(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __rint(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn nextafter(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __nextafter(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn nexttoward(__x: f64, __y: u128) -> f64;
+}
+unsafe extern "C" {
+    pub fn __nexttoward(__x: f64, __y: u128) -> f64;
+}
+unsafe extern "C" {
+    pub fn remainder(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __remainder(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn scalbn(__x: f64, __n: ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn __scalbn(__x: f64, __n: ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn ilogb(__x: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __ilogb(__x: f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn scalbln(__x: f64, __n: ::std::os::raw::c_long) -> f64;
+}
+unsafe extern "C" {
+    pub fn __scalbln(__x: f64, __n: ::std::os::raw::c_long) -> f64;
+}
+unsafe extern "C" {
+    pub fn nearbyint(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __nearbyint(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn round(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __round(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn trunc(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __trunc(__x: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn remquo(__x: f64, __y: f64, __quo: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn __remquo(__x: f64, __y: f64, __quo: *mut ::std::os::raw::c_int) -> f64;
+}
+unsafe extern "C" {
+    pub fn lrint(__x: f64) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lrint(__x: f64) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llrint(__x: f64) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llrint(__x: f64) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn lround(__x: f64) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lround(__x: f64) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llround(__x: f64) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llround(__x: f64) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn fdim(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fdim(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn fmax(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fmax(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn fmin(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fmin(__x: f64, __y: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn fma(__x: f64, __y: f64, __z: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fma(__x: f64, __y: f64, __z: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn scalb(__x: f64, __n: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __scalb(__x: f64, __n: f64) -> f64;
+}
+unsafe extern "C" {
+    pub fn __fpclassifyf(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __signbitf(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isinff(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __finitef(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isnanf(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __iseqsigf(__x: f32, __y: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __issignalingf(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn acosf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __acosf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn asinf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __asinf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn atanf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __atanf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn atan2f(__y: f32, __x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __atan2f(__y: f32, __x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn cosf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __cosf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn sinf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __sinf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn tanf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __tanf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn coshf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __coshf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn sinhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __sinhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn tanhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __tanhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn acoshf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __acoshf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn asinhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __asinhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn atanhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __atanhf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn expf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __expf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn frexpf(__x: f32, __exponent: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn __frexpf(__x: f32, __exponent: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn ldexpf(__x: f32, __exponent: ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn __ldexpf(__x: f32, __exponent: ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn logf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __logf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn log10f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __log10f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn modff(__x: f32, __iptr: *mut f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __modff(__x: f32, __iptr: *mut f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn expm1f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __expm1f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn log1pf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __log1pf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn logbf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __logbf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn exp2f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __exp2f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn log2f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __log2f(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn powf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __powf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn sqrtf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __sqrtf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn hypotf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __hypotf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn cbrtf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __cbrtf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn ceilf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __ceilf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn fabsf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fabsf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn floorf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __floorf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn fmodf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fmodf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn isinff(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn finitef(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn dremf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __dremf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn significandf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __significandf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn copysignf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __copysignf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn nanf(__tagb: *const ::std::os::raw::c_char) -> f32;
+}
+unsafe extern "C" {
+    pub fn __nanf(__tagb: *const ::std::os::raw::c_char) -> f32;
+}
+unsafe extern "C" {
+    pub fn isnanf(__value: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn j0f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __j0f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn j1f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __j1f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn jnf(arg1: ::std::os::raw::c_int, arg2: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __jnf(arg1: ::std::os::raw::c_int, arg2: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn y0f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __y0f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn y1f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __y1f(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn ynf(arg1: ::std::os::raw::c_int, arg2: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __ynf(arg1: ::std::os::raw::c_int, arg2: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn erff(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __erff(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn erfcf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __erfcf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn lgammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __lgammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn tgammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __tgammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn gammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __gammaf(arg1: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn lgammaf_r(arg1: f32, __signgamp: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn __lgammaf_r(arg1: f32, __signgamp: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn rintf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __rintf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn nextafterf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __nextafterf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn nexttowardf(__x: f32, __y: u128) -> f32;
+}
+unsafe extern "C" {
+    pub fn __nexttowardf(__x: f32, __y: u128) -> f32;
+}
+unsafe extern "C" {
+    pub fn remainderf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __remainderf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn scalbnf(__x: f32, __n: ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn __scalbnf(__x: f32, __n: ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn ilogbf(__x: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __ilogbf(__x: f32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn scalblnf(__x: f32, __n: ::std::os::raw::c_long) -> f32;
+}
+unsafe extern "C" {
+    pub fn __scalblnf(__x: f32, __n: ::std::os::raw::c_long) -> f32;
+}
+unsafe extern "C" {
+    pub fn nearbyintf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __nearbyintf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn roundf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __roundf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn truncf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __truncf(__x: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn remquof(__x: f32, __y: f32, __quo: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn __remquof(__x: f32, __y: f32, __quo: *mut ::std::os::raw::c_int) -> f32;
+}
+unsafe extern "C" {
+    pub fn lrintf(__x: f32) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lrintf(__x: f32) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llrintf(__x: f32) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llrintf(__x: f32) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn lroundf(__x: f32) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lroundf(__x: f32) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llroundf(__x: f32) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llroundf(__x: f32) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn fdimf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fdimf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn fmaxf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fmaxf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn fminf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fminf(__x: f32, __y: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn fmaf(__x: f32, __y: f32, __z: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fmaf(__x: f32, __y: f32, __z: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn scalbf(__x: f32, __n: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __scalbf(__x: f32, __n: f32) -> f32;
+}
+unsafe extern "C" {
+    pub fn __fpclassifyl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __signbitl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isinfl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __finitel(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __isnanl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __iseqsigl(__x: u128, __y: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __issignalingl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn acosl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __acosl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn asinl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __asinl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn atanl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __atanl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn atan2l(__y: u128, __x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __atan2l(__y: u128, __x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn cosl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __cosl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn sinl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __sinl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn tanl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __tanl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn coshl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __coshl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn sinhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __sinhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn tanhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __tanhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn acoshl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __acoshl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn asinhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __asinhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn atanhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __atanhl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn expl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __expl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn frexpl(__x: u128, __exponent: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn __frexpl(__x: u128, __exponent: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn ldexpl(__x: u128, __exponent: ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn __ldexpl(__x: u128, __exponent: ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn logl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __logl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn log10l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __log10l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn modfl(__x: u128, __iptr: *mut u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __modfl(__x: u128, __iptr: *mut u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn expm1l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __expm1l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn log1pl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __log1pl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn logbl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __logbl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn exp2l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __exp2l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn log2l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __log2l(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn powl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __powl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn sqrtl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __sqrtl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn hypotl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __hypotl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn cbrtl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __cbrtl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn ceill(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __ceill(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn fabsl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fabsl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn floorl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __floorl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn fmodl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fmodl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn isinfl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn finitel(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn dreml(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __dreml(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn significandl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __significandl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn copysignl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __copysignl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn nanl(__tagb: *const ::std::os::raw::c_char) -> u128;
+}
+unsafe extern "C" {
+    pub fn __nanl(__tagb: *const ::std::os::raw::c_char) -> u128;
+}
+unsafe extern "C" {
+    pub fn isnanl(__value: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn j0l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __j0l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn j1l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __j1l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn jnl(arg1: ::std::os::raw::c_int, arg2: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __jnl(arg1: ::std::os::raw::c_int, arg2: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn y0l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __y0l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn y1l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __y1l(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn ynl(arg1: ::std::os::raw::c_int, arg2: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __ynl(arg1: ::std::os::raw::c_int, arg2: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn erfl(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __erfl(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn erfcl(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __erfcl(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn lgammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __lgammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn tgammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __tgammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn gammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __gammal(arg1: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn lgammal_r(arg1: u128, __signgamp: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn __lgammal_r(arg1: u128, __signgamp: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn rintl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __rintl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn nextafterl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __nextafterl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn nexttowardl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __nexttowardl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn remainderl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __remainderl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn scalbnl(__x: u128, __n: ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn __scalbnl(__x: u128, __n: ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn ilogbl(__x: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __ilogbl(__x: u128) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn scalblnl(__x: u128, __n: ::std::os::raw::c_long) -> u128;
+}
+unsafe extern "C" {
+    pub fn __scalblnl(__x: u128, __n: ::std::os::raw::c_long) -> u128;
+}
+unsafe extern "C" {
+    pub fn nearbyintl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __nearbyintl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn roundl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __roundl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn truncl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __truncl(__x: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn remquol(__x: u128, __y: u128, __quo: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn __remquol(__x: u128, __y: u128, __quo: *mut ::std::os::raw::c_int) -> u128;
+}
+unsafe extern "C" {
+    pub fn lrintl(__x: u128) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lrintl(__x: u128) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llrintl(__x: u128) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llrintl(__x: u128) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn lroundl(__x: u128) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn __lroundl(__x: u128) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llroundl(__x: u128) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn __llroundl(__x: u128) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn fdiml(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fdiml(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn fmaxl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fmaxl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn fminl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fminl(__x: u128, __y: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn fmal(__x: u128, __y: u128, __z: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __fmal(__x: u128, __y: u128, __z: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn scalbl(__x: u128, __n: u128) -> u128;
+}
+unsafe extern "C" {
+    pub fn __scalbl(__x: u128, __n: u128) -> u128;
+}
+unsafe extern "C" {
+    pub static mut signgam: ::std::os::raw::c_int;
+}
+pub const FP_NAN: _bindgen_ty_1 = 0;
+pub const FP_INFINITE: _bindgen_ty_1 = 1;
+pub const FP_ZERO: _bindgen_ty_1 = 2;
+pub const FP_SUBNORMAL: _bindgen_ty_1 = 3;
+pub const FP_NORMAL: _bindgen_ty_1 = 4;
+pub type _bindgen_ty_1 = ::std::os::raw::c_uint;
+unsafe extern "C" {
+    pub fn __assert_fail(
+        __assertion: *const ::std::os::raw::c_char,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_uint,
+        __function: *const ::std::os::raw::c_char,
+    ) -> !;
+}
+unsafe extern "C" {
+    pub fn __assert_perror_fail(
+        __errnum: ::std::os::raw::c_int,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_uint,
+        __function: *const ::std::os::raw::c_char,
+    ) -> !;
+}
+unsafe extern "C" {
+    pub fn __assert(
+        __assertion: *const ::std::os::raw::c_char,
+        __file: *const ::std::os::raw::c_char,
+        __line: ::std::os::raw::c_int,
+    ) -> !;
+}
+pub type wchar_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct div_t {
+    pub quot: ::std::os::raw::c_int,
+    pub rem: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of div_t"][::std::mem::size_of::<div_t>() - 8usize];
+    ["Alignment of div_t"][::std::mem::align_of::<div_t>() - 4usize];
+    ["Offset of field: div_t::quot"][::std::mem::offset_of!(div_t, quot) - 0usize];
+    ["Offset of field: div_t::rem"][::std::mem::offset_of!(div_t, rem) - 4usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ldiv_t {
+    pub quot: ::std::os::raw::c_long,
+    pub rem: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of ldiv_t"][::std::mem::size_of::<ldiv_t>() - 16usize];
+    ["Alignment of ldiv_t"][::std::mem::align_of::<ldiv_t>() - 8usize];
+    ["Offset of field: ldiv_t::quot"][::std::mem::offset_of!(ldiv_t, quot) - 0usize];
+    ["Offset of field: ldiv_t::rem"][::std::mem::offset_of!(ldiv_t, rem) - 8usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct lldiv_t {
+    pub quot: ::std::os::raw::c_longlong,
+    pub rem: ::std::os::raw::c_longlong,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of lldiv_t"][::std::mem::size_of::<lldiv_t>() - 16usize];
+    ["Alignment of lldiv_t"][::std::mem::align_of::<lldiv_t>() - 8usize];
+    ["Offset of field: lldiv_t::quot"][::std::mem::offset_of!(lldiv_t, quot) - 0usize];
+    ["Offset of field: lldiv_t::rem"][::std::mem::offset_of!(lldiv_t, rem) - 8usize];
+};
+unsafe extern "C" {
+    pub fn __ctype_get_mb_cur_max() -> usize;
+}
+unsafe extern "C" {
+    pub fn atof(__nptr: *const ::std::os::raw::c_char) -> f64;
+}
+unsafe extern "C" {
+    pub fn atoi(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn atol(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn atoll(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn strtod(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn strtof(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+    ) -> f32;
+}
+unsafe extern "C" {
+    pub fn strtold(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+    ) -> u128;
+}
+unsafe extern "C" {
+    pub fn strtol(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn strtoul(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn strtoq(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn strtouq(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulonglong;
+}
+unsafe extern "C" {
+    pub fn strtoll(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn strtoull(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_ulonglong;
+}
+unsafe extern "C" {
+    pub fn l64a(__n: ::std::os::raw::c_long) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn a64l(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+}
+pub type u_char = __u_char;
+pub type u_short = __u_short;
+pub type u_int = __u_int;
+pub type u_long = __u_long;
+pub type quad_t = __quad_t;
+pub type u_quad_t = __u_quad_t;
+pub type fsid_t = __fsid_t;
+pub type loff_t = __loff_t;
+pub type ino_t = __ino_t;
+pub type dev_t = __dev_t;
+pub type gid_t = __gid_t;
+pub type mode_t = __mode_t;
+pub type nlink_t = __nlink_t;
+pub type uid_t = __uid_t;
+pub type pid_t = __pid_t;
+pub type id_t = __id_t;
+pub type daddr_t = __daddr_t;
+pub type caddr_t = __caddr_t;
+pub type key_t = __key_t;
+pub type clock_t = __clock_t;
+pub type clockid_t = __clockid_t;
+pub type time_t = __time_t;
+pub type timer_t = __timer_t;
+pub type ulong = ::std::os::raw::c_ulong;
+pub type ushort = ::std::os::raw::c_ushort;
+pub type uint = ::std::os::raw::c_uint;
+pub type u_int8_t = __uint8_t;
+pub type u_int16_t = __uint16_t;
+pub type u_int32_t = __uint32_t;
+pub type u_int64_t = __uint64_t;
+pub type register_t = ::std::os::raw::c_long;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __sigset_t {
+    pub __val: [::std::os::raw::c_ulong; 16usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __sigset_t"][::std::mem::size_of::<__sigset_t>() - 128usize];
+    ["Alignment of __sigset_t"][::std::mem::align_of::<__sigset_t>() - 8usize];
+    ["Offset of field: __sigset_t::__val"][::std::mem::offset_of!(__sigset_t, __val) - 0usize];
+};
+pub type sigset_t = __sigset_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct timeval {
+    pub tv_sec: __time_t,
+    pub tv_usec: __suseconds_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of timeval"][::std::mem::size_of::<timeval>() - 16usize];
+    ["Alignment of timeval"][::std::mem::align_of::<timeval>() - 8usize];
+    ["Offset of field: timeval::tv_sec"][::std::mem::offset_of!(timeval, tv_sec) - 0usize];
+    ["Offset of field: timeval::tv_usec"][::std::mem::offset_of!(timeval, tv_usec) - 8usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct timespec {
+    pub tv_sec: __time_t,
+    pub tv_nsec: __syscall_slong_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of timespec"][::std::mem::size_of::<timespec>() - 16usize];
+    ["Alignment of timespec"][::std::mem::align_of::<timespec>() - 8usize];
+    ["Offset of field: timespec::tv_sec"][::std::mem::offset_of!(timespec, tv_sec) - 0usize];
+    ["Offset of field: timespec::tv_nsec"][::std::mem::offset_of!(timespec, tv_nsec) - 8usize];
+};
+pub type suseconds_t = __suseconds_t;
+pub type __fd_mask = ::std::os::raw::c_long;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct fd_set {
+    pub __fds_bits: [__fd_mask; 16usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of fd_set"][::std::mem::size_of::<fd_set>() - 128usize];
+    ["Alignment of fd_set"][::std::mem::align_of::<fd_set>() - 8usize];
+    ["Offset of field: fd_set::__fds_bits"][::std::mem::offset_of!(fd_set, __fds_bits) - 0usize];
+};
+pub type fd_mask = __fd_mask;
+unsafe extern "C" {
+    pub fn select(
+        __nfds: ::std::os::raw::c_int,
+        __readfds: *mut fd_set,
+        __writefds: *mut fd_set,
+        __exceptfds: *mut fd_set,
+        __timeout: *mut timeval,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn pselect(
+        __nfds: ::std::os::raw::c_int,
+        __readfds: *mut fd_set,
+        __writefds: *mut fd_set,
+        __exceptfds: *mut fd_set,
+        __timeout: *const timespec,
+        __sigmask: *const __sigset_t,
+    ) -> ::std::os::raw::c_int;
+}
+pub type blksize_t = __blksize_t;
+pub type blkcnt_t = __blkcnt_t;
+pub type fsblkcnt_t = __fsblkcnt_t;
+pub type fsfilcnt_t = __fsfilcnt_t;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union __atomic_wide_counter {
+    pub __value64: ::std::os::raw::c_ulonglong,
+    pub __value32: __atomic_wide_counter__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __atomic_wide_counter__bindgen_ty_1 {
+    pub __low: ::std::os::raw::c_uint,
+    pub __high: ::std::os::raw::c_uint,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __atomic_wide_counter__bindgen_ty_1"]
+        [::std::mem::size_of::<__atomic_wide_counter__bindgen_ty_1>() - 8usize];
+    ["Alignment of __atomic_wide_counter__bindgen_ty_1"]
+        [::std::mem::align_of::<__atomic_wide_counter__bindgen_ty_1>() - 4usize];
+    ["Offset of field: __atomic_wide_counter__bindgen_ty_1::__low"]
+        [::std::mem::offset_of!(__atomic_wide_counter__bindgen_ty_1, __low) - 0usize];
+    ["Offset of field: __atomic_wide_counter__bindgen_ty_1::__high"]
+        [::std::mem::offset_of!(__atomic_wide_counter__bindgen_ty_1, __high) - 4usize];
+};
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __atomic_wide_counter"][::std::mem::size_of::<__atomic_wide_counter>() - 8usize];
+    ["Alignment of __atomic_wide_counter"]
+        [::std::mem::align_of::<__atomic_wide_counter>() - 8usize];
+    ["Offset of field: __atomic_wide_counter::__value64"]
+        [::std::mem::offset_of!(__atomic_wide_counter, __value64) - 0usize];
+    ["Offset of field: __atomic_wide_counter::__value32"]
+        [::std::mem::offset_of!(__atomic_wide_counter, __value32) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __pthread_internal_list {
+    pub __prev: *mut __pthread_internal_list,
+    pub __next: *mut __pthread_internal_list,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __pthread_internal_list"][::std::mem::size_of::<__pthread_internal_list>() - 16usize];
+    ["Alignment of __pthread_internal_list"]
+        [::std::mem::align_of::<__pthread_internal_list>() - 8usize];
+    ["Offset of field: __pthread_internal_list::__prev"]
+        [::std::mem::offset_of!(__pthread_internal_list, __prev) - 0usize];
+    ["Offset of field: __pthread_internal_list::__next"]
+        [::std::mem::offset_of!(__pthread_internal_list, __next) - 8usize];
+};
+pub type __pthread_list_t = __pthread_internal_list;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __pthread_internal_slist {
+    pub __next: *mut __pthread_internal_slist,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __pthread_internal_slist"]
+        [::std::mem::size_of::<__pthread_internal_slist>() - 8usize];
+    ["Alignment of __pthread_internal_slist"]
+        [::std::mem::align_of::<__pthread_internal_slist>() - 8usize];
+    ["Offset of field: __pthread_internal_slist::__next"]
+        [::std::mem::offset_of!(__pthread_internal_slist, __next) - 0usize];
+};
+pub type __pthread_slist_t = __pthread_internal_slist;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __pthread_mutex_s {
+    pub __lock: ::std::os::raw::c_int,
+    pub __count: ::std::os::raw::c_uint,
+    pub __owner: ::std::os::raw::c_int,
+    pub __nusers: ::std::os::raw::c_uint,
+    pub __kind: ::std::os::raw::c_int,
+    pub __spins: ::std::os::raw::c_short,
+    pub __elision: ::std::os::raw::c_short,
+    pub __list: __pthread_list_t,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __pthread_mutex_s"][::std::mem::size_of::<__pthread_mutex_s>() - 40usize];
+    ["Alignment of __pthread_mutex_s"][::std::mem::align_of::<__pthread_mutex_s>() - 8usize];
+    ["Offset of field: __pthread_mutex_s::__lock"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __lock) - 0usize];
+    ["Offset of field: __pthread_mutex_s::__count"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __count) - 4usize];
+    ["Offset of field: __pthread_mutex_s::__owner"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __owner) - 8usize];
+    ["Offset of field: __pthread_mutex_s::__nusers"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __nusers) - 12usize];
+    ["Offset of field: __pthread_mutex_s::__kind"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __kind) - 16usize];
+    ["Offset of field: __pthread_mutex_s::__spins"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __spins) - 20usize];
+    ["Offset of field: __pthread_mutex_s::__elision"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __elision) - 22usize];
+    ["Offset of field: __pthread_mutex_s::__list"]
+        [::std::mem::offset_of!(__pthread_mutex_s, __list) - 24usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __pthread_rwlock_arch_t {
+    pub __readers: ::std::os::raw::c_uint,
+    pub __writers: ::std::os::raw::c_uint,
+    pub __wrphase_futex: ::std::os::raw::c_uint,
+    pub __writers_futex: ::std::os::raw::c_uint,
+    pub __pad3: ::std::os::raw::c_uint,
+    pub __pad4: ::std::os::raw::c_uint,
+    pub __cur_writer: ::std::os::raw::c_int,
+    pub __shared: ::std::os::raw::c_int,
+    pub __rwelision: ::std::os::raw::c_schar,
+    pub __pad1: [::std::os::raw::c_uchar; 7usize],
+    pub __pad2: ::std::os::raw::c_ulong,
+    pub __flags: ::std::os::raw::c_uint,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __pthread_rwlock_arch_t"][::std::mem::size_of::<__pthread_rwlock_arch_t>() - 56usize];
+    ["Alignment of __pthread_rwlock_arch_t"]
+        [::std::mem::align_of::<__pthread_rwlock_arch_t>() - 8usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__readers"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __readers) - 0usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__writers"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers) - 4usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__wrphase_futex"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __wrphase_futex) - 8usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__writers_futex"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers_futex) - 12usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__pad3"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad3) - 16usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__pad4"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad4) - 20usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__cur_writer"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __cur_writer) - 24usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__shared"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __shared) - 28usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__rwelision"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __rwelision) - 32usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__pad1"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad1) - 33usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__pad2"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad2) - 40usize];
+    ["Offset of field: __pthread_rwlock_arch_t::__flags"]
+        [::std::mem::offset_of!(__pthread_rwlock_arch_t, __flags) - 48usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __pthread_cond_s {
+    pub __wseq: __atomic_wide_counter,
+    pub __g1_start: __atomic_wide_counter,
+    pub __g_refs: [::std::os::raw::c_uint; 2usize],
+    pub __g_size: [::std::os::raw::c_uint; 2usize],
+    pub __g1_orig_size: ::std::os::raw::c_uint,
+    pub __wrefs: ::std::os::raw::c_uint,
+    pub __g_signals: [::std::os::raw::c_uint; 2usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __pthread_cond_s"][::std::mem::size_of::<__pthread_cond_s>() - 48usize];
+    ["Alignment of __pthread_cond_s"][::std::mem::align_of::<__pthread_cond_s>() - 8usize];
+    ["Offset of field: __pthread_cond_s::__wseq"]
+        [::std::mem::offset_of!(__pthread_cond_s, __wseq) - 0usize];
+    ["Offset of field: __pthread_cond_s::__g1_start"]
+        [::std::mem::offset_of!(__pthread_cond_s, __g1_start) - 8usize];
+    ["Offset of field: __pthread_cond_s::__g_refs"]
+        [::std::mem::offset_of!(__pthread_cond_s, __g_refs) - 16usize];
+    ["Offset of field: __pthread_cond_s::__g_size"]
+        [::std::mem::offset_of!(__pthread_cond_s, __g_size) - 24usize];
+    ["Offset of field: __pthread_cond_s::__g1_orig_size"]
+        [::std::mem::offset_of!(__pthread_cond_s, __g1_orig_size) - 32usize];
+    ["Offset of field: __pthread_cond_s::__wrefs"]
+        [::std::mem::offset_of!(__pthread_cond_s, __wrefs) - 36usize];
+    ["Offset of field: __pthread_cond_s::__g_signals"]
+        [::std::mem::offset_of!(__pthread_cond_s, __g_signals) - 40usize];
+};
+pub type __tss_t = ::std::os::raw::c_uint;
+pub type __thrd_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __once_flag {
+    pub __data: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __once_flag"][::std::mem::size_of::<__once_flag>() - 4usize];
+    ["Alignment of __once_flag"][::std::mem::align_of::<__once_flag>() - 4usize];
+    ["Offset of field: __once_flag::__data"][::std::mem::offset_of!(__once_flag, __data) - 0usize];
+};
+pub type pthread_t = ::std::os::raw::c_ulong;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_mutexattr_t {
+    pub __size: [::std::os::raw::c_char; 4usize],
+    pub __align: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_mutexattr_t"][::std::mem::size_of::<pthread_mutexattr_t>() - 4usize];
+    ["Alignment of pthread_mutexattr_t"][::std::mem::align_of::<pthread_mutexattr_t>() - 4usize];
+    ["Offset of field: pthread_mutexattr_t::__size"]
+        [::std::mem::offset_of!(pthread_mutexattr_t, __size) - 0usize];
+    ["Offset of field: pthread_mutexattr_t::__align"]
+        [::std::mem::offset_of!(pthread_mutexattr_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_condattr_t {
+    pub __size: [::std::os::raw::c_char; 4usize],
+    pub __align: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_condattr_t"][::std::mem::size_of::<pthread_condattr_t>() - 4usize];
+    ["Alignment of pthread_condattr_t"][::std::mem::align_of::<pthread_condattr_t>() - 4usize];
+    ["Offset of field: pthread_condattr_t::__size"]
+        [::std::mem::offset_of!(pthread_condattr_t, __size) - 0usize];
+    ["Offset of field: pthread_condattr_t::__align"]
+        [::std::mem::offset_of!(pthread_condattr_t, __align) - 0usize];
+};
+pub type pthread_key_t = ::std::os::raw::c_uint;
+pub type pthread_once_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_attr_t {
+    pub __size: [::std::os::raw::c_char; 56usize],
+    pub __align: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_attr_t"][::std::mem::size_of::<pthread_attr_t>() - 56usize];
+    ["Alignment of pthread_attr_t"][::std::mem::align_of::<pthread_attr_t>() - 8usize];
+    ["Offset of field: pthread_attr_t::__size"]
+        [::std::mem::offset_of!(pthread_attr_t, __size) - 0usize];
+    ["Offset of field: pthread_attr_t::__align"]
+        [::std::mem::offset_of!(pthread_attr_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_mutex_t {
+    pub __data: __pthread_mutex_s,
+    pub __size: [::std::os::raw::c_char; 40usize],
+    pub __align: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_mutex_t"][::std::mem::size_of::<pthread_mutex_t>() - 40usize];
+    ["Alignment of pthread_mutex_t"][::std::mem::align_of::<pthread_mutex_t>() - 8usize];
+    ["Offset of field: pthread_mutex_t::__data"]
+        [::std::mem::offset_of!(pthread_mutex_t, __data) - 0usize];
+    ["Offset of field: pthread_mutex_t::__size"]
+        [::std::mem::offset_of!(pthread_mutex_t, __size) - 0usize];
+    ["Offset of field: pthread_mutex_t::__align"]
+        [::std::mem::offset_of!(pthread_mutex_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_cond_t {
+    pub __data: __pthread_cond_s,
+    pub __size: [::std::os::raw::c_char; 48usize],
+    pub __align: ::std::os::raw::c_longlong,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_cond_t"][::std::mem::size_of::<pthread_cond_t>() - 48usize];
+    ["Alignment of pthread_cond_t"][::std::mem::align_of::<pthread_cond_t>() - 8usize];
+    ["Offset of field: pthread_cond_t::__data"]
+        [::std::mem::offset_of!(pthread_cond_t, __data) - 0usize];
+    ["Offset of field: pthread_cond_t::__size"]
+        [::std::mem::offset_of!(pthread_cond_t, __size) - 0usize];
+    ["Offset of field: pthread_cond_t::__align"]
+        [::std::mem::offset_of!(pthread_cond_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_rwlock_t {
+    pub __data: __pthread_rwlock_arch_t,
+    pub __size: [::std::os::raw::c_char; 56usize],
+    pub __align: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_rwlock_t"][::std::mem::size_of::<pthread_rwlock_t>() - 56usize];
+    ["Alignment of pthread_rwlock_t"][::std::mem::align_of::<pthread_rwlock_t>() - 8usize];
+    ["Offset of field: pthread_rwlock_t::__data"]
+        [::std::mem::offset_of!(pthread_rwlock_t, __data) - 0usize];
+    ["Offset of field: pthread_rwlock_t::__size"]
+        [::std::mem::offset_of!(pthread_rwlock_t, __size) - 0usize];
+    ["Offset of field: pthread_rwlock_t::__align"]
+        [::std::mem::offset_of!(pthread_rwlock_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_rwlockattr_t {
+    pub __size: [::std::os::raw::c_char; 8usize],
+    pub __align: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_rwlockattr_t"][::std::mem::size_of::<pthread_rwlockattr_t>() - 8usize];
+    ["Alignment of pthread_rwlockattr_t"][::std::mem::align_of::<pthread_rwlockattr_t>() - 8usize];
+    ["Offset of field: pthread_rwlockattr_t::__size"]
+        [::std::mem::offset_of!(pthread_rwlockattr_t, __size) - 0usize];
+    ["Offset of field: pthread_rwlockattr_t::__align"]
+        [::std::mem::offset_of!(pthread_rwlockattr_t, __align) - 0usize];
+};
+pub type pthread_spinlock_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_barrier_t {
+    pub __size: [::std::os::raw::c_char; 32usize],
+    pub __align: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_barrier_t"][::std::mem::size_of::<pthread_barrier_t>() - 32usize];
+    ["Alignment of pthread_barrier_t"][::std::mem::align_of::<pthread_barrier_t>() - 8usize];
+    ["Offset of field: pthread_barrier_t::__size"]
+        [::std::mem::offset_of!(pthread_barrier_t, __size) - 0usize];
+    ["Offset of field: pthread_barrier_t::__align"]
+        [::std::mem::offset_of!(pthread_barrier_t, __align) - 0usize];
+};
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union pthread_barrierattr_t {
+    pub __size: [::std::os::raw::c_char; 4usize],
+    pub __align: ::std::os::raw::c_int,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of pthread_barrierattr_t"][::std::mem::size_of::<pthread_barrierattr_t>() - 4usize];
+    ["Alignment of pthread_barrierattr_t"]
+        [::std::mem::align_of::<pthread_barrierattr_t>() - 4usize];
+    ["Offset of field: pthread_barrierattr_t::__size"]
+        [::std::mem::offset_of!(pthread_barrierattr_t, __size) - 0usize];
+    ["Offset of field: pthread_barrierattr_t::__align"]
+        [::std::mem::offset_of!(pthread_barrierattr_t, __align) - 0usize];
+};
+unsafe extern "C" {
+    pub fn random() -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn srandom(__seed: ::std::os::raw::c_uint);
+}
+unsafe extern "C" {
+    pub fn initstate(
+        __seed: ::std::os::raw::c_uint,
+        __statebuf: *mut ::std::os::raw::c_char,
+        __statelen: usize,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn setstate(__statebuf: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct random_data {
+    pub fptr: *mut i32,
+    pub rptr: *mut i32,
+    pub state: *mut i32,
+    pub rand_type: ::std::os::raw::c_int,
+    pub rand_deg: ::std::os::raw::c_int,
+    pub rand_sep: ::std::os::raw::c_int,
+    pub end_ptr: *mut i32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of random_data"][::std::mem::size_of::<random_data>() - 48usize];
+    ["Alignment of random_data"][::std::mem::align_of::<random_data>() - 8usize];
+    ["Offset of field: random_data::fptr"][::std::mem::offset_of!(random_data, fptr) - 0usize];
+    ["Offset of field: random_data::rptr"][::std::mem::offset_of!(random_data, rptr) - 8usize];
+    ["Offset of field: random_data::state"][::std::mem::offset_of!(random_data, state) - 16usize];
+    ["Offset of field: random_data::rand_type"]
+        [::std::mem::offset_of!(random_data, rand_type) - 24usize];
+    ["Offset of field: random_data::rand_deg"]
+        [::std::mem::offset_of!(random_data, rand_deg) - 28usize];
+    ["Offset of field: random_data::rand_sep"]
+        [::std::mem::offset_of!(random_data, rand_sep) - 32usize];
+    ["Offset of field: random_data::end_ptr"]
+        [::std::mem::offset_of!(random_data, end_ptr) - 40usize];
+};
+unsafe extern "C" {
+    pub fn random_r(__buf: *mut random_data, __result: *mut i32) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn srandom_r(
+        __seed: ::std::os::raw::c_uint,
+        __buf: *mut random_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn initstate_r(
+        __seed: ::std::os::raw::c_uint,
+        __statebuf: *mut ::std::os::raw::c_char,
+        __statelen: usize,
+        __buf: *mut random_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn setstate_r(
+        __statebuf: *mut ::std::os::raw::c_char,
+        __buf: *mut random_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn rand() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn srand(__seed: ::std::os::raw::c_uint);
+}
+unsafe extern "C" {
+    pub fn rand_r(__seed: *mut ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn drand48() -> f64;
+}
+unsafe extern "C" {
+    pub fn erand48(__xsubi: *mut ::std::os::raw::c_ushort) -> f64;
+}
+unsafe extern "C" {
+    pub fn lrand48() -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn nrand48(__xsubi: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn mrand48() -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn jrand48(__xsubi: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn srand48(__seedval: ::std::os::raw::c_long);
+}
+unsafe extern "C" {
+    pub fn seed48(__seed16v: *mut ::std::os::raw::c_ushort) -> *mut ::std::os::raw::c_ushort;
+}
+unsafe extern "C" {
+    pub fn lcong48(__param: *mut ::std::os::raw::c_ushort);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct drand48_data {
+    pub __x: [::std::os::raw::c_ushort; 3usize],
+    pub __old_x: [::std::os::raw::c_ushort; 3usize],
+    pub __c: ::std::os::raw::c_ushort,
+    pub __init: ::std::os::raw::c_ushort,
+    pub __a: ::std::os::raw::c_ulonglong,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of drand48_data"][::std::mem::size_of::<drand48_data>() - 24usize];
+    ["Alignment of drand48_data"][::std::mem::align_of::<drand48_data>() - 8usize];
+    ["Offset of field: drand48_data::__x"][::std::mem::offset_of!(drand48_data, __x) - 0usize];
+    ["Offset of field: drand48_data::__old_x"]
+        [::std::mem::offset_of!(drand48_data, __old_x) - 6usize];
+    ["Offset of field: drand48_data::__c"][::std::mem::offset_of!(drand48_data, __c) - 12usize];
+    ["Offset of field: drand48_data::__init"]
+        [::std::mem::offset_of!(drand48_data, __init) - 14usize];
+    ["Offset of field: drand48_data::__a"][::std::mem::offset_of!(drand48_data, __a) - 16usize];
+};
+unsafe extern "C" {
+    pub fn drand48_r(__buffer: *mut drand48_data, __result: *mut f64) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn erand48_r(
+        __xsubi: *mut ::std::os::raw::c_ushort,
+        __buffer: *mut drand48_data,
+        __result: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lrand48_r(
+        __buffer: *mut drand48_data,
+        __result: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn nrand48_r(
+        __xsubi: *mut ::std::os::raw::c_ushort,
+        __buffer: *mut drand48_data,
+        __result: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mrand48_r(
+        __buffer: *mut drand48_data,
+        __result: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn jrand48_r(
+        __xsubi: *mut ::std::os::raw::c_ushort,
+        __buffer: *mut drand48_data,
+        __result: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn srand48_r(
+        __seedval: ::std::os::raw::c_long,
+        __buffer: *mut drand48_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn seed48_r(
+        __seed16v: *mut ::std::os::raw::c_ushort,
+        __buffer: *mut drand48_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lcong48_r(
+        __param: *mut ::std::os::raw::c_ushort,
+        __buffer: *mut drand48_data,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn arc4random() -> __uint32_t;
+}
+unsafe extern "C" {
+    pub fn arc4random_buf(__buf: *mut ::std::os::raw::c_void, __size: usize);
+}
+unsafe extern "C" {
+    pub fn arc4random_uniform(__upper_bound: __uint32_t) -> __uint32_t;
+}
+unsafe extern "C" {
+    pub fn malloc(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn calloc(
+        __nmemb: ::std::os::raw::c_ulong,
+        __size: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn realloc(
+        __ptr: *mut ::std::os::raw::c_void,
+        __size: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn free(__ptr: *mut ::std::os::raw::c_void);
+}
+unsafe extern "C" {
+    pub fn reallocarray(
+        __ptr: *mut ::std::os::raw::c_void,
+        __nmemb: usize,
+        __size: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn alloca(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn valloc(__size: usize) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn posix_memalign(
+        __memptr: *mut *mut ::std::os::raw::c_void,
+        __alignment: usize,
+        __size: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn aligned_alloc(
+        __alignment: ::std::os::raw::c_ulong,
+        __size: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+//@ common 3
+unsafe extern "C" {
+    pub fn atexit(__func: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn at_quick_exit(
+        __func: ::std::option::Option<unsafe extern "C" fn()>,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn on_exit(
+        __func: ::std::option::Option<
+            unsafe extern "C" fn(
+                __status: ::std::os::raw::c_int,
+                __arg: *mut ::std::os::raw::c_void,
+            ),
+        >,
+        __arg: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn exit(__status: ::std::os::raw::c_int) -> !;
+}
+unsafe extern "C" {
+    pub fn quick_exit(__status: ::std::os::raw::c_int) -> !;
+}
+unsafe extern "C" {
+    pub fn _Exit(__status: ::std::os::raw::c_int) -> !;
+}
+unsafe extern "C" {
+    pub fn getenv(__name: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn putenv(__string: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn setenv(
+        __name: *const ::std::os::raw::c_char,
+        __value: *const ::std::os::raw::c_char,
+        __replace: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn unsetenv(__name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn clearenv() -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mktemp(__template: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn mkstemp(__template: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mkstemps(
+        __template: *mut ::std::os::raw::c_char,
+        __suffixlen: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mkdtemp(__template: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn system(__command: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn realpath(
+        __name: *const ::std::os::raw::c_char,
+        __resolved: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+pub type __compar_fn_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        arg1: *const ::std::os::raw::c_void,
+        arg2: *const ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+unsafe extern "C" {
+    pub fn bsearch(
+        __key: *const ::std::os::raw::c_void,
+        __base: *const ::std::os::raw::c_void,
+        __nmemb: usize,
+        __size: usize,
+        __compar: __compar_fn_t,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn qsort(
+        __base: *mut ::std::os::raw::c_void,
+        __nmemb: usize,
+        __size: usize,
+        __compar: __compar_fn_t,
+    );
+}
+unsafe extern "C" {
+    pub fn abs(__x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn labs(__x: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn llabs(__x: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+}
+unsafe extern "C" {
+    pub fn div(__numer: ::std::os::raw::c_int, __denom: ::std::os::raw::c_int) -> div_t;
+}
+unsafe extern "C" {
+    pub fn ldiv(__numer: ::std::os::raw::c_long, __denom: ::std::os::raw::c_long) -> ldiv_t;
+}
+unsafe extern "C" {
+    pub fn lldiv(
+        __numer: ::std::os::raw::c_longlong,
+        __denom: ::std::os::raw::c_longlong,
+    ) -> lldiv_t;
+}
+unsafe extern "C" {
+    pub fn ecvt(
+        __value: f64,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn fcvt(
+        __value: f64,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn gcvt(
+        __value: f64,
+        __ndigit: ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn qecvt(
+        __value: u128,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn qfcvt(
+        __value: u128,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn qgcvt(
+        __value: u128,
+        __ndigit: ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn ecvt_r(
+        __value: f64,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+        __len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn fcvt_r(
+        __value: f64,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+        __len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn qecvt_r(
+        __value: u128,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+        __len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn qfcvt_r(
+        __value: u128,
+        __ndigit: ::std::os::raw::c_int,
+        __decpt: *mut ::std::os::raw::c_int,
+        __sign: *mut ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+        __len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mblen(__s: *const ::std::os::raw::c_char, __n: usize) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mbtowc(
+        __pwc: *mut wchar_t,
+        __s: *const ::std::os::raw::c_char,
+        __n: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wctomb(__s: *mut ::std::os::raw::c_char, __wchar: wchar_t) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn mbstowcs(__pwcs: *mut wchar_t, __s: *const ::std::os::raw::c_char, __n: usize) -> usize;
+}
+unsafe extern "C" {
+    pub fn wcstombs(__s: *mut ::std::os::raw::c_char, __pwcs: *const wchar_t, __n: usize) -> usize;
+}
+unsafe extern "C" {
+    pub fn rpmatch(__response: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getsubopt(
+        __optionp: *mut *mut ::std::os::raw::c_char,
+        __tokens: *const *mut ::std::os::raw::c_char,
+        __valuep: *mut *mut ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn getloadavg(__loadavg: *mut f64, __nelem: ::std::os::raw::c_int)
+    -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct max_align_t {
+    pub __clang_max_align_nonce1: ::std::os::raw::c_longlong,
+    pub __bindgen_padding_0: u64,
+    pub __clang_max_align_nonce2: u128,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of max_align_t"][::std::mem::size_of::<max_align_t>() - 32usize];
+    ["Alignment of max_align_t"][::std::mem::align_of::<max_align_t>() - 16usize];
+    ["Offset of field: max_align_t::__clang_max_align_nonce1"]
+        [::std::mem::offset_of!(max_align_t, __clang_max_align_nonce1) - 0usize];
+    ["Offset of field: max_align_t::__clang_max_align_nonce2"]
+        [::std::mem::offset_of!(max_align_t, __clang_max_align_nonce2) - 16usize];
+};
+//@ common 602
+#[doc = " result codes for SCIP callback methods"]
+pub type SCIP_Result = ::std::os::raw::c_uint;
+//@ common 8
+pub type SCIP_ClockType = ::std::os::raw::c_uint;
+//@ common 29
+#[doc = " represents different confidence levels for (one-sided) hypothesis testing; in order to obtain two-sided confidence\n levels, calculate 2 * c - 1, i.e., if the one-sided confidence level is 90 %, the two-sided level is 80 %"]
+pub type SCIP_Confidencelevel = ::std::os::raw::c_uint;
+//@ common 12
+#[doc = " type of hashmap: are pointers, reals or ints stored, or unknown"]
+pub type SCIP_Hashmaptype = ::std::os::raw::c_uint;
+//@ common 177
+#[doc = " possible parameter types"]
+pub type SCIP_ParamType = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " possible parameter settings - used to determine the behavior of different SCIP components, e.g., heuristics, separators, ..."]
+pub type SCIP_ParamSetting = ::std::os::raw::c_uint;
+//@ common 26
+#[doc = " possible parameter emphases - used to determine the general SCIP behavior"]
+pub type SCIP_ParamEmphasis = ::std::os::raw::c_uint;
+//@ common 20
+pub type __gwchar_t = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct imaxdiv_t {
+    pub quot: ::std::os::raw::c_long,
+    pub rem: ::std::os::raw::c_long,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of imaxdiv_t"][::std::mem::size_of::<imaxdiv_t>() - 16usize];
+    ["Alignment of imaxdiv_t"][::std::mem::align_of::<imaxdiv_t>() - 8usize];
+    ["Offset of field: imaxdiv_t::quot"][::std::mem::offset_of!(imaxdiv_t, quot) - 0usize];
+    ["Offset of field: imaxdiv_t::rem"][::std::mem::offset_of!(imaxdiv_t, rem) - 8usize];
+};
+unsafe extern "C" {
+    pub fn imaxabs(__n: intmax_t) -> intmax_t;
+}
+unsafe extern "C" {
+    pub fn imaxdiv(__numer: intmax_t, __denom: intmax_t) -> imaxdiv_t;
+}
+unsafe extern "C" {
+    pub fn strtoimax(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> intmax_t;
+}
+unsafe extern "C" {
+    pub fn strtoumax(
+        __nptr: *const ::std::os::raw::c_char,
+        __endptr: *mut *mut ::std::os::raw::c_char,
+        __base: ::std::os::raw::c_int,
+    ) -> uintmax_t;
+}
+unsafe extern "C" {
+    pub fn wcstoimax(
+        __nptr: *const __gwchar_t,
+        __endptr: *mut *mut __gwchar_t,
+        __base: ::std::os::raw::c_int,
+    ) -> intmax_t;
+}
+unsafe extern "C" {
+    pub fn wcstoumax(
+        __nptr: *const __gwchar_t,
+        __endptr: *mut *mut __gwchar_t,
+        __base: ::std::os::raw::c_int,
+    ) -> uintmax_t;
+}
+//@ common 155
+#[doc = " solution status after solving LP"]
+pub type SCIP_LPSolStat = ::std::os::raw::c_uint;
+//@ common 6
+#[doc = " type of variable bound: lower or upper bound"]
+pub type SCIP_BoundType = ::std::os::raw::c_uint;
+//@ common 6
+#[doc = " type of row side: left hand or right hand side"]
+pub type SCIP_SideType = ::std::os::raw::c_uint;
+//@ common 12
+#[doc = " type of origin of row"]
+pub type SCIP_RowOriginType = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " type of LP algorithm"]
+pub type SCIP_LPAlgo = ::std::os::raw::c_uint;
+//@ common 40
+#[doc = " status of problem variables"]
+pub type SCIP_Varstatus = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " variable type"]
+pub type SCIP_Vartype = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " implied integral type"]
+pub type SCIP_ImplintType = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " domain change data type"]
+pub type SCIP_DomchgType = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " bound change type"]
+pub type SCIP_BoundchgType = ::std::os::raw::c_uint;
+//@ common 6
+pub type SCIP_LockType = ::std::os::raw::c_uint;
+//@ common 187
+pub type SCIP_NodeType = ::std::os::raw::c_uint;
+//@ common 122
+#[doc = " types of conflicts"]
+pub type SCIP_ConflictType = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " dualray presolving strategy"]
+pub type SCIP_ConflictPresolStrat = ::std::os::raw::c_uint;
+//@ common 18
+#[doc = " origin of solution: where to retrieve uncached elements"]
+pub type SCIP_SolOrigin = ::std::os::raw::c_uint;
+//@ common 26
+#[doc = " type of solution: heuristic or (LP) relaxation solution, or unspecified origin"]
+pub type SCIP_SolType = ::std::os::raw::c_uint;
+//@ common 17
+#[doc = " context for diving statistics"]
+pub type SCIP_DiveContext = ::std::os::raw::c_uint;
+//@ common 102
+#[doc = " linear constraint types recognizable"]
+pub type SCIP_LinConstype = ::std::os::raw::c_uint;
+//@ common 20
+#[doc = " type of values stored in a SCIP_DATATREE"]
+pub type SCIP_Datatree_Valuetype = ::std::os::raw::c_uint;
+//@ common 39
+#[doc = " display activation status of display column"]
+pub type SCIP_DispStatus = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " display activation status of display column"]
+pub type SCIP_DispMode = ::std::os::raw::c_uint;
+//@ common 34
+#[doc = " information if a rational is exactly representable as a floating point number"]
+pub type SCIP_IsFpRepresentable = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " defines the possible rounding direction for a rational number, when converting to a double"]
+pub type SCIP_RoundModeRational = ::std::os::raw::c_uint;
+//@ common 596
+#[doc = " curvature types"]
+pub type SCIP_EXPRCURV = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " monotonicity"]
+pub type SCIP_MONOTONE = ::std::os::raw::c_uint;
+//@ common 59
+#[doc = " mode for expression iterator"]
+pub type SCIP_EXPRITER_TYPE = ::std::os::raw::c_uint;
+//@ common 39
+#[doc = " branching direction for branching on variables"]
+pub type SCIP_BranchDir = ::std::os::raw::c_uint;
+//@ common 157
+pub type SCIP_ReoptType = ::std::os::raw::c_uint;
+//@ common 9
+pub type Reopt_ConsType = ::std::os::raw::c_uint;
+//@ common 73
+#[doc = " NLP solver fast-fail levels"]
+pub type SCIP_NlpParam_FastFail = ::std::os::raw::c_uint;
+//@ common 72
+#[doc = " NLP solution status"]
+pub type SCIP_NlpSolStat = ::std::os::raw::c_uint;
+//@ common 22
+#[doc = " NLP solver termination status"]
+pub type SCIP_NlpTermStat = ::std::os::raw::c_uint;
+//@ common 70
+#[doc = " SCIP solving status"]
+pub type SCIP_Status = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " The parallel mode"]
+pub type SCIP_Parallelmode = ::std::os::raw::c_uint;
+//@ common 52
+pub type SCIP_BendersEnfoType = ::std::os::raw::c_uint;
+//@ common 9
+pub type SCIP_BendersSolveLoop = ::std::os::raw::c_uint;
+//@ common 9
+pub type SCIP_BendersSubStatus = ::std::os::raw::c_uint;
+//@ common 11
+pub type SCIP_BendersSubType = ::std::os::raw::c_uint;
+//@ common 5
+pub type SCIP_BendersObjectiveType = ::std::os::raw::c_uint;
+//@ common 141
+unsafe extern "C" {
+    #[doc = " compares two benderss w. r. to their priority"]
+    pub fn SCIPbendersComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting benderss w.r.t. to their name"]
+    pub fn SCIPbendersCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 363
+unsafe extern "C" {
+    #[doc = " compares two Benders' decomposition cuts w. r. to their priority"]
+    pub fn SCIPbenderscutComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting Benders' decomposition cuts w.r.t. to their name"]
+    pub fn SCIPbenderscutCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 56
+unsafe extern "C" {
+    #[doc = " compares two branching rules w. r. to their priority"]
+    pub fn SCIPbranchruleComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting branching rules w.r.t. to their name"]
+    pub fn SCIPbranchruleCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 97
+unsafe extern "C" {
+    #[doc = " compares two conflict handlers w. r. to their priority"]
+    pub fn SCIPconflicthdlrComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting conflict handler w.r.t. to their name"]
+    pub fn SCIPconflicthdlrCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 45
+unsafe extern "C" {
+    #[doc = " compares two constraint handlers w.r.t. their separation priority"]
+    pub fn SCIPconshdlrCompSepa(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two constraint handlers w.r.t. their enforcing priority"]
+    pub fn SCIPconshdlrCompEnfo(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two constraint handlers w.r.t. their feasibility check priority"]
+    pub fn SCIPconshdlrCompCheck(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two constraints w.r.t. their feasibility check priority"]
+    pub fn SCIPconsCompCheck(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 975
+#[doc = " verbosity levels of output"]
+pub type SCIP_VerbLevel = ::std::os::raw::c_uint;
+//@ common 592
+unsafe extern "C" {
+    #[doc = " compares two expression handler w.r.t. their name"]
+    pub fn SCIPexprhdlrComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 456
+unsafe extern "C" {
+    #[doc = " compares two heuristics w.r.t. to their delay positions and priorities"]
+    pub fn SCIPheurComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two heuristics w.r.t. to their priority values"]
+    pub fn SCIPheurCompPriority(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting heuristics w.r.t. to their name"]
+    pub fn SCIPheurCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 290
+unsafe extern "C" {
+    #[doc = " compares two compressions w. r. to their priority"]
+    pub fn SCIPcomprComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting compressions w.r.t. to their name"]
+    pub fn SCIPcomprCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 143
+unsafe extern "C" {
+    #[doc = " compares two IIS finders w. r. to their priority"]
+    pub fn SCIPiisfinderComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 122
+#[doc = " LP solver parameters"]
+pub type SCIP_LPParam = ::std::os::raw::c_uint;
+//@ common 16
+#[doc = " LP pricing strategy"]
+pub type SCIP_Pricing = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " basis status for columns and rows"]
+pub type SCIP_BaseStat = ::std::os::raw::c_uint;
+//@ common 6
+#[doc = " LP solution quality quantities"]
+pub type SCIP_LPSolQuality = ::std::os::raw::c_uint;
+//@ common 72
+pub type Ps_dualcostsel = ::std::os::raw::c_uint;
+//@ common 105
+unsafe extern "C" {
+    #[doc = " comparison method for sorting rows by non-decreasing index"]
+    pub fn SCIProwComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 166
+unsafe extern "C" {
+    #[doc = " comparison method for sorting rows by non-decreasing index"]
+    pub fn SCIProwExactComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 88
+#[doc = " SCIP operation stage"]
+pub type SCIP_Stage = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " possible settings for enabling/disabling algorithms and other features"]
+pub type SCIP_Setting = ::std::os::raw::c_uint;
+//@ common 304
+unsafe extern "C" {
+    pub fn memcpy(
+        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn memmove(
+        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn memccpy(
+        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn memset(
+        __s: *mut ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn memcmp(
+        __s1: *const ::std::os::raw::c_void,
+        __s2: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn __memcmpeq(
+        __s1: *const ::std::os::raw::c_void,
+        __s2: *const ::std::os::raw::c_void,
+        __n: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn memchr(
+        __s: *const ::std::os::raw::c_void,
+        __c: ::std::os::raw::c_int,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn strcpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strncpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strcat(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strncat(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strcmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strncmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strcoll(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strxfrm(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_ulong;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __locale_struct {
+    pub __locales: [*mut __locale_data; 13usize],
+    pub __ctype_b: *const ::std::os::raw::c_ushort,
+    pub __ctype_tolower: *const ::std::os::raw::c_int,
+    pub __ctype_toupper: *const ::std::os::raw::c_int,
+    pub __names: [*const ::std::os::raw::c_char; 13usize],
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __locale_struct"][::std::mem::size_of::<__locale_struct>() - 232usize];
+    ["Alignment of __locale_struct"][::std::mem::align_of::<__locale_struct>() - 8usize];
+    ["Offset of field: __locale_struct::__locales"]
+        [::std::mem::offset_of!(__locale_struct, __locales) - 0usize];
+    ["Offset of field: __locale_struct::__ctype_b"]
+        [::std::mem::offset_of!(__locale_struct, __ctype_b) - 104usize];
+    ["Offset of field: __locale_struct::__ctype_tolower"]
+        [::std::mem::offset_of!(__locale_struct, __ctype_tolower) - 112usize];
+    ["Offset of field: __locale_struct::__ctype_toupper"]
+        [::std::mem::offset_of!(__locale_struct, __ctype_toupper) - 120usize];
+    ["Offset of field: __locale_struct::__names"]
+        [::std::mem::offset_of!(__locale_struct, __names) - 128usize];
+};
+pub type __locale_t = *mut __locale_struct;
+pub type locale_t = __locale_t;
+unsafe extern "C" {
+    pub fn strcoll_l(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __l: locale_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strxfrm_l(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: usize,
+        __l: locale_t,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn strdup(__s: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strndup(
+        __string: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strchr(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strrchr(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strchrnul(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strcspn(
+        __s: *const ::std::os::raw::c_char,
+        __reject: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn strspn(
+        __s: *const ::std::os::raw::c_char,
+        __accept: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn strpbrk(
+        __s: *const ::std::os::raw::c_char,
+        __accept: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strstr(
+        __haystack: *const ::std::os::raw::c_char,
+        __needle: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strtok(
+        __s: *mut ::std::os::raw::c_char,
+        __delim: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn __strtok_r(
+        __s: *mut ::std::os::raw::c_char,
+        __delim: *const ::std::os::raw::c_char,
+        __save_ptr: *mut *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strtok_r(
+        __s: *mut ::std::os::raw::c_char,
+        __delim: *const ::std::os::raw::c_char,
+        __save_ptr: *mut *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strcasestr(
+        __haystack: *const ::std::os::raw::c_char,
+        __needle: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn memmem(
+        __haystack: *const ::std::os::raw::c_void,
+        __haystacklen: usize,
+        __needle: *const ::std::os::raw::c_void,
+        __needlelen: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn __mempcpy(
+        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __n: usize,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn mempcpy(
+        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn strlen(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulong;
+}
+unsafe extern "C" {
+    pub fn strnlen(__string: *const ::std::os::raw::c_char, __maxlen: usize) -> usize;
+}
+unsafe extern "C" {
+    pub fn strerror(__errnum: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    #[link_name = "\u{1}__xpg_strerror_r"]
+    pub fn strerror_r(
+        __errnum: ::std::os::raw::c_int,
+        __buf: *mut ::std::os::raw::c_char,
+        __buflen: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strerror_l(
+        __errnum: ::std::os::raw::c_int,
+        __l: locale_t,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn bcmp(
+        __s1: *const ::std::os::raw::c_void,
+        __s2: *const ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn bcopy(
+        __src: *const ::std::os::raw::c_void,
+        __dest: *mut ::std::os::raw::c_void,
+        __n: ::std::os::raw::c_ulong,
+    );
+}
+unsafe extern "C" {
+    pub fn bzero(__s: *mut ::std::os::raw::c_void, __n: ::std::os::raw::c_ulong);
+}
+unsafe extern "C" {
+    pub fn index(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn rindex(
+        __s: *const ::std::os::raw::c_char,
+        __c: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn ffs(__i: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ffsl(__l: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn ffsll(__ll: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strcasecmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strncasecmp(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strcasecmp_l(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __loc: locale_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn strncasecmp_l(
+        __s1: *const ::std::os::raw::c_char,
+        __s2: *const ::std::os::raw::c_char,
+        __n: usize,
+        __loc: locale_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn explicit_bzero(__s: *mut ::std::os::raw::c_void, __n: usize);
+}
+unsafe extern "C" {
+    pub fn strsep(
+        __stringp: *mut *mut ::std::os::raw::c_char,
+        __delim: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strsignal(__sig: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn __stpcpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn stpcpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn __stpncpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: usize,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn stpncpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: ::std::os::raw::c_ulong,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn strlcpy(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: usize,
+    ) -> usize;
+}
+unsafe extern "C" {
+    pub fn strlcat(
+        __dest: *mut ::std::os::raw::c_char,
+        __src: *const ::std::os::raw::c_char,
+        __n: usize,
+    ) -> usize;
+}
+//@ common 70
+unsafe extern "C" {
+    #[doc = " prints a message, acting like the vprintf() command"]
+    pub fn SCIPmessageVPrintInfo(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 8
+unsafe extern "C" {
+    #[doc = " prints a message into a file, acting like the vfprintf() command"]
+    pub fn SCIPmessageVFPrintInfo(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        file: *mut FILE,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 7
+unsafe extern "C" {
+    #[doc = " prints a warning message, acting like the vprintf() command"]
+    pub fn SCIPmessageVPrintWarning(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 7
+unsafe extern "C" {
+    #[doc = " prints a warning message into a file, acting like the vfprintf() command"]
+    pub fn SCIPmessageVFPrintWarning(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 7
+unsafe extern "C" {
+    #[doc = " prints a dialog message that requests user interaction, acting like the vprintf() command"]
+    pub fn SCIPmessageVPrintDialog(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 8
+unsafe extern "C" {
+    #[doc = " prints a dialog message that requests user interaction into a file, acting like the vfprintf() command"]
+    pub fn SCIPmessageVFPrintDialog(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        file: *mut FILE,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 9
+unsafe extern "C" {
+    #[doc = " prints a message depending on the verbosity level, acting like the vprintf() command"]
+    pub fn SCIPmessageVPrintVerbInfo(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        verblevel: SCIP_VERBLEVEL,
+        msgverblevel: SCIP_VERBLEVEL,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 10
+unsafe extern "C" {
+    #[doc = " prints a message into a file depending on the verbosity level, acting like the vfprintf() command"]
+    pub fn SCIPmessageVFPrintVerbInfo(
+        messagehdlr: *mut SCIP_MESSAGEHDLR,
+        verblevel: SCIP_VERBLEVEL,
+        msgverblevel: SCIP_VERBLEVEL,
+        file: *mut FILE,
+        formatstr: *const ::std::os::raw::c_char,
+        ap: *mut __va_list_tag,
+    );
+}
+//@ common 10
+unsafe extern "C" {
+    #[doc = " prints an error message, acting like the vprintf() command using the static message handler"]
+    pub fn SCIPmessageVPrintError(formatstr: *const ::std::os::raw::c_char, ap: *mut __va_list_tag);
+}
+//@ common 3257
+unsafe extern "C" {
+    #[doc = " default comparer for integers"]
+    pub fn SCIPsortCompInt(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " implements argsort\n\n The data pointer is a lookup array of integers."]
+    pub fn SCIPsortArgsortInt(
+        dataptr: *mut ::std::os::raw::c_void,
+        ind1: ::std::os::raw::c_int,
+        ind2: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " implements argsort\n\n The data pointer is a lookup array, which are pointer arrays."]
+    pub fn SCIPsortArgsortPtr(
+        dataptr: *mut ::std::os::raw::c_void,
+        ind1: ::std::os::raw::c_int,
+        ind2: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 5694
+unsafe extern "C" {
+    #[doc = " standard hash key comparator for string keys"]
+    pub fn SCIPhashKeyEqString(
+        userptr: *mut ::std::os::raw::c_void,
+        key1: *mut ::std::os::raw::c_void,
+        key2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_uint;
+}
+unsafe extern "C" {
+    #[doc = " standard hashing function for string keys"]
+    pub fn SCIPhashKeyValString(
+        userptr: *mut ::std::os::raw::c_void,
+        key: *mut ::std::os::raw::c_void,
+    ) -> u64;
+}
+unsafe extern "C" {
+    #[doc = " gets the element as the key"]
+    pub fn SCIPhashGetKeyStandard(
+        userptr: *mut ::std::os::raw::c_void,
+        elem: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    #[doc = " returns TRUE iff both keys(pointer) are equal"]
+    pub fn SCIPhashKeyEqPtr(
+        userptr: *mut ::std::os::raw::c_void,
+        key1: *mut ::std::os::raw::c_void,
+        key2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_uint;
+}
+unsafe extern "C" {
+    #[doc = " returns the hash value of the key"]
+    pub fn SCIPhashKeyValPtr(
+        userptr: *mut ::std::os::raw::c_void,
+        key: *mut ::std::os::raw::c_void,
+    ) -> u64;
+}
+//@ common 1106
+unsafe extern "C" {
+    #[doc = " compares two presolvers w. r. to their priority"]
+    pub fn SCIPpresolComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting presolvers w.r.t. to their name"]
+    pub fn SCIPpresolCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 92
+unsafe extern "C" {
+    #[doc = " compares two pricers w. r. to their priority"]
+    pub fn SCIPpricerComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting pricers w.r.t. to their name"]
+    pub fn SCIPpricerCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 84
+unsafe extern "C" {
+    #[doc = " compares two relaxation handlers w. r. to their priority"]
+    pub fn SCIPrelaxComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting relaxators w.r.t. to their name"]
+    pub fn SCIPrelaxCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 166
+unsafe extern "C" {
+    #[doc = " compares two separators w. r. to their priority"]
+    pub fn SCIPsepaComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting separators w.r.t. to their name"]
+    pub fn SCIPsepaCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 192
+unsafe extern "C" {
+    #[doc = " compares two cut selectors w. r. to their priority"]
+    pub fn SCIPcutselComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two propagators w. r. to their priority"]
+    pub fn SCIPpropComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " compares two propagators w. r. to their presolving priority"]
+    pub fn SCIPpropCompPresol(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting propagators w.r.t. to their name"]
+    pub fn SCIPpropCompName(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 252
+unsafe extern "C" {
+    #[doc = " comparison method for sorting solution by decreasing objective value (best solution will be sorted to the end)"]
+    pub fn SCIPsolComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 32
+unsafe extern "C" {
+    #[doc = " node comparator for best lower bound"]
+    pub fn SCIPnodeCompLowerbound(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 184
+unsafe extern "C" {
+    #[doc = " comparison method for sorting active and negated variables by non-decreasing index, active and negated\n variables are handled as the same variables"]
+    pub fn SCIPvarCompActiveAndNegated(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 4
+unsafe extern "C" {
+    #[doc = " comparison method for sorting variables by non-decreasing index"]
+    pub fn SCIPvarComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " comparison method for sorting variables by non-decreasing objective coefficient"]
+    pub fn SCIPvarCompObj(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    #[doc = " hash key retrieval function for variables"]
+    pub fn SCIPvarGetHashkey(
+        userptr: *mut ::std::os::raw::c_void,
+        elem: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    #[doc = " returns TRUE iff the indices of both variables are equal"]
+    pub fn SCIPvarIsHashkeyEq(
+        userptr: *mut ::std::os::raw::c_void,
+        key1: *mut ::std::os::raw::c_void,
+        key2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_uint;
+}
+unsafe extern "C" {
+    #[doc = " returns the hash value of the key"]
+    pub fn SCIPvarGetHashkeyVal(
+        userptr: *mut ::std::os::raw::c_void,
+        key: *mut ::std::os::raw::c_void,
+    ) -> u64;
+}
+//@ common 1716
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SCIP_AggrRow {
+    #[doc = " non-zero coefficients of the cut row"]
+    pub vals: *mut f64,
+    #[doc = " problem indices of variables with a non-zero coefficient in the cut row"]
+    pub inds: *mut ::std::os::raw::c_int,
+    #[doc = " lpposition of rows that have been added to the cutrow"]
+    pub rowsinds: *mut ::std::os::raw::c_int,
+    #[doc = " slacksign of rows that have been added to the cutrow"]
+    pub slacksign: *mut ::std::os::raw::c_int,
+    #[doc = " weights of rows that have been added to the cutrow"]
+    pub rowweights: *mut f64,
+    #[doc = " right hand side of the cut row"]
+    pub rhshi: f64,
+    #[doc = " right hand side of the cut row"]
+    pub rhslo: f64,
+    #[doc = " number of non-zeros in the cut row"]
+    pub nnz: ::std::os::raw::c_int,
+    #[doc = " number of rows that have been added to the cutrow"]
+    pub nrows: ::std::os::raw::c_int,
+    #[doc = " size of the row and slacksign array"]
+    pub rowssize: ::std::os::raw::c_int,
+    #[doc = " rank of the cut row"]
+    pub rank: ::std::os::raw::c_int,
+    #[doc = " is the cut row only valid locally?"]
+    pub local: ::std::os::raw::c_uint,
+    #[doc = " proof index in certificate or SCIP_LONGINT_MAX"]
+    pub certificateline: ::std::os::raw::c_longlong,
+}
+//@ common 5518
+unsafe extern "C" {
+    #[doc = " calls the print callback for an expression\n\n # See also\n\n [`SCIP_DECL_EXPRPRINT`]"]
+    pub fn SCIPcallExprPrint(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        stage: SCIP_EXPRITER_STAGE,
+        currentchild: ::std::os::raw::c_int,
+        parentprecedence: ::std::os::raw::c_uint,
+        file: *mut FILE,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the curvature callback for an expression\n\n Returns unknown curvature if callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRCURVATURE`]"]
+    pub fn SCIPcallExprCurvature(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        exprcurvature: SCIP_EXPRCURV,
+        success: *mut ::std::os::raw::c_uint,
+        childcurv: *mut SCIP_EXPRCURV,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the monotonicity callback for an expression\n\n Returns unknown monotonicity if callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRMONOTONICITY`]"]
+    pub fn SCIPcallExprMonotonicity(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        childidx: ::std::os::raw::c_int,
+        result: *mut SCIP_MONOTONE,
+    ) -> SCIP_RETCODE;
+}
+//@ common 20
+unsafe extern "C" {
+    #[doc = " calls the interval evaluation callback for an expression\n\n Returns entire interval if callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRINTEVAL`]"]
+    pub fn SCIPcallExprInteval(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        interval: *mut SCIP_INTERVAL,
+        intevalvar: ::std::option::Option<
+            unsafe extern "C" fn(
+                scip: *mut SCIP,
+                var: *mut SCIP_VAR,
+                intevalvardata: *mut ::std::os::raw::c_void,
+            ) -> SCIP_INTERVAL,
+        >,
+        intevalvardata: *mut ::std::os::raw::c_void,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the estimate callback for an expression\n\n Returns without success if callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRESTIMATE`]"]
+    pub fn SCIPcallExprEstimate(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        localbounds: *mut SCIP_INTERVAL,
+        globalbounds: *mut SCIP_INTERVAL,
+        refpoint: *mut f64,
+        overestimate: ::std::os::raw::c_uint,
+        targetvalue: f64,
+        coefs: *mut f64,
+        constant: *mut f64,
+        islocal: *mut ::std::os::raw::c_uint,
+        success: *mut ::std::os::raw::c_uint,
+        branchcand: *mut ::std::os::raw::c_uint,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the initial estimators callback for an expression\n\n Returns no estimators if callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRINITESTIMATES`]"]
+    pub fn SCIPcallExprInitestimates(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        bounds: *mut SCIP_INTERVAL,
+        overestimate: ::std::os::raw::c_uint,
+        coefs: *mut *mut f64,
+        constant: *mut f64,
+        nreturned: *mut ::std::os::raw::c_int,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the simplify callback for an expression\n\n Returns unmodified expression if simplify callback not implemented.\n\n Does not simplify descendants (children, etc). Use [`SCIPsimplifyExpr()`] for that.\n\n # See also\n\n [`SCIP_DECL_EXPRSIMPLIFY`]"]
+    pub fn SCIPcallExprSimplify(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        simplifiedexpr: *mut *mut SCIP_EXPR,
+        ownercreate: ::std::option::Option<
+            unsafe extern "C" fn(
+                scip: *mut SCIP,
+                expr: *mut SCIP_EXPR,
+                ownerdata: *mut *mut SCIP_EXPR_OWNERDATA,
+                ownerfree: *mut ::std::option::Option<
+                    unsafe extern "C" fn(
+                        scip: *mut SCIP,
+                        expr: *mut SCIP_EXPR,
+                        ownerdata: *mut *mut SCIP_EXPR_OWNERDATA,
+                    ) -> SCIP_RETCODE,
+                >,
+                ownerprint: *mut ::std::option::Option<
+                    unsafe extern "C" fn(
+                        scip: *mut SCIP,
+                        file: *mut FILE,
+                        expr: *mut SCIP_EXPR,
+                        ownerdata: *mut SCIP_EXPR_OWNERDATA,
+                    ) -> SCIP_RETCODE,
+                >,
+                ownerevalactivity: *mut ::std::option::Option<
+                    unsafe extern "C" fn(
+                        scip: *mut SCIP,
+                        expr: *mut SCIP_EXPR,
+                        ownerdata: *mut SCIP_EXPR_OWNERDATA,
+                    ) -> SCIP_RETCODE,
+                >,
+                ownercreatedata: *mut ::std::os::raw::c_void,
+            ) -> SCIP_RETCODE,
+        >,
+        ownercreatedata: *mut ::std::os::raw::c_void,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the reverse propagation callback for an expression\n\n Returns unmodified `childrenbounds` if reverseprop callback not implemented.\n\n # See also\n\n [`SCIP_DECL_EXPRREVERSEPROP`]"]
+    pub fn SCIPcallExprReverseprop(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        bounds: SCIP_INTERVAL,
+        childrenbounds: *mut SCIP_INTERVAL,
+        infeasible: *mut ::std::os::raw::c_uint,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " calls the symmetry information callback for an expression\n\n Returns NULL pointer if not implemented."]
+    pub fn SCIPcallExprGetSymData(
+        scip: *mut SCIP,
+        expr: *mut SCIP_EXPR,
+        symdata: *mut *mut SYM_EXPRDATA,
+    ) -> SCIP_RETCODE;
+}
+//@ common 1508
+unsafe extern "C" {
+    #[doc = " compares two NLPIs w.r.t. their priority"]
+    pub fn SCIPnlpiComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 290
+unsafe extern "C" {
+    #[doc = " gets internal pointer to NLP solver\n\n Depending on the solver interface, a solver pointer may exist for every NLP problem instance.\n For this case, a NLPI problem can be passed in as well."]
+    pub fn SCIPgetNlpiSolverPointer(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    #[doc = " creates an empty problem instance"]
+    pub fn SCIPcreateNlpiProblem(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut *mut SCIP_NLPIPROBLEM,
+        name: *const ::std::os::raw::c_char,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " frees a problem instance"]
+    pub fn SCIPfreeNlpiProblem(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut *mut SCIP_NLPIPROBLEM,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " gets internal pointer to solver-internal problem instance"]
+    pub fn SCIPgetNlpiProblemPointer(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    #[doc = " add variables to nlpi"]
+    pub fn SCIPaddNlpiVars(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        nvars: ::std::os::raw::c_int,
+        lbs: *const f64,
+        ubs: *const f64,
+        varnames: *mut *const ::std::os::raw::c_char,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " add constraints to nlpi"]
+    pub fn SCIPaddNlpiConstraints(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        nconss: ::std::os::raw::c_int,
+        lhss: *const f64,
+        rhss: *const f64,
+        nlininds: *const ::std::os::raw::c_int,
+        lininds: *const *mut ::std::os::raw::c_int,
+        linvals: *const *mut f64,
+        exprs: *mut *mut SCIP_EXPR,
+        names: *mut *const ::std::os::raw::c_char,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " sets or overwrites objective, a minimization problem is expected"]
+    pub fn SCIPsetNlpiObjective(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        nlins: ::std::os::raw::c_int,
+        lininds: *const ::std::os::raw::c_int,
+        linvals: *const f64,
+        expr: *mut SCIP_EXPR,
+        constant: f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " change variable bounds"]
+    pub fn SCIPchgNlpiVarBounds(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        nvars: ::std::os::raw::c_int,
+        indices: *const ::std::os::raw::c_int,
+        lbs: *const f64,
+        ubs: *const f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " change constraint sides"]
+    pub fn SCIPchgNlpiConsSides(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        nconss: ::std::os::raw::c_int,
+        indices: *const ::std::os::raw::c_int,
+        lhss: *const f64,
+        rhss: *const f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " delete a set of variables"]
+    pub fn SCIPdelNlpiVarSet(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        dstats: *mut ::std::os::raw::c_int,
+        dstatssize: ::std::os::raw::c_int,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " delete a set of constraints"]
+    pub fn SCIPdelNlpiConsSet(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        dstats: *mut ::std::os::raw::c_int,
+        dstatssize: ::std::os::raw::c_int,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " changes or adds linear coefficients in a constraint or objective"]
+    pub fn SCIPchgNlpiLinearCoefs(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        idx: ::std::os::raw::c_int,
+        nvals: ::std::os::raw::c_int,
+        varidxs: *const ::std::os::raw::c_int,
+        vals: *const f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " change the expression in the nonlinear part"]
+    pub fn SCIPchgNlpiExpr(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        idxcons: ::std::os::raw::c_int,
+        expr: *mut SCIP_EXPR,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " change the constant offset in the objective"]
+    pub fn SCIPchgNlpiObjConstant(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        objconstant: f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " sets initial guess"]
+    pub fn SCIPsetNlpiInitialGuess(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        primalvalues: *mut f64,
+        consdualvalues: *mut f64,
+        varlbdualvalues: *mut f64,
+        varubdualvalues: *mut f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " try to solve NLP with all parameters given as SCIP_NLPPARAM struct\n\n Typical use is\n\n SCIP_NLPPARAM nlparam = { SCIP_NLPPARAM_DEFAULT(scip); }\n nlpparam.iterlimit = 42;\n SCIP_CALL( SCIPsolveNlpiParam(scip, nlpi, nlpiproblem, nlpparam) );\n\n or, in \"one\" line:\n\n SCIP_CALL( SCIPsolveNlpiParam(scip, nlpi, nlpiproblem,\n (SCIP_NLPPARAM){ SCIP_NLPPARAM_DEFAULT(scip), .iterlimit = 42 }) );\n\n To get the latter, also [`SCIPsolveNlpi`] can be used."]
+    pub fn SCIPsolveNlpiParam(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        param: SCIP_NLPPARAM,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " gives solution status"]
+    pub fn SCIPgetNlpiSolstat(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+    ) -> SCIP_NLPSOLSTAT;
+}
+unsafe extern "C" {
+    #[doc = " gives termination reason"]
+    pub fn SCIPgetNlpiTermstat(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+    ) -> SCIP_NLPTERMSTAT;
+}
+unsafe extern "C" {
+    #[doc = " gives primal and dual solution\n for a ranged constraint, the dual variable is positive if the right hand side is active and negative if the left hand side is active"]
+    pub fn SCIPgetNlpiSolution(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        primalvalues: *mut *mut f64,
+        consdualvalues: *mut *mut f64,
+        varlbdualvalues: *mut *mut f64,
+        varubdualvalues: *mut *mut f64,
+        objval: *mut f64,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " gives solve statistics"]
+    pub fn SCIPgetNlpiStatistics(
+        scip: *mut SCIP,
+        nlpi: *mut SCIP_NLPI,
+        problem: *mut SCIP_NLPIPROBLEM,
+        statistics: *mut SCIP_NLPSTATISTICS,
+    ) -> SCIP_RETCODE;
+}
+//@ common 7176
+unsafe extern "C" {
+    #[doc = " dialog execution method for the count command"]
+    pub fn SCIPdialogExecCountPresolve(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the count command"]
+    pub fn SCIPdialogExecCount(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " execution method of dialog for writing all solutions"]
+    pub fn SCIPdialogExecWriteAllsolutions(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+//@ common 13524
+#[doc = " define symmetry types detectable by SCIP"]
+pub type SYM_Symtype = ::std::os::raw::c_uint;
+//@ common 10
+#[doc = " define type of nodes in symmetry detection expression trees"]
+pub type SYM_Nodetype = ::std::os::raw::c_uint;
+//@ common 36
+#[doc = " define type of simple constraints/operators in symmetry detection"]
+pub type SYM_Consoptype = ::std::os::raw::c_uint;
+//@ common 9
+#[doc = " selection rules for leaders in SST cuts"]
+pub type SCIP_LeaderRule = ::std::os::raw::c_uint;
+//@ common 9
+#[doc = " tie breaks for leader rule based on the leader's orbit"]
+pub type SCIP_LeaderTiebreakRule = ::std::os::raw::c_uint;
+//@ common 6
+#[doc = " variable types for leader in Schreier Sims cuts"]
+pub type SCIP_SSTType = ::std::os::raw::c_uint;
+//@ common 8
+#[doc = " type of orbitope constraint: full, packing, or partitioning orbitope"]
+pub type SCIP_OrbitopeType = ::std::os::raw::c_uint;
+//@ common 238
+#[doc = " type of setppc constraint: set partitioning, set packing, or set covering"]
+pub type SCIP_SetppcType = ::std::os::raw::c_uint;
+//@ common 357
+unsafe extern "C" {
+    #[doc = " dialog execution method for the [`SCIPtransformMinUC()`] command"]
+    pub fn SCIPdialogExecChangeMinUC(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+//@ common 214
+unsafe extern "C" {
+    #[doc = " standard menu dialog execution method, that displays it's help screen if the remaining command line is empty"]
+    pub fn SCIPdialogExecMenu(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " standard menu dialog execution method, that doesn't display it's help screen"]
+    pub fn SCIPdialogExecMenuLazy(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the change add constraint"]
+    pub fn SCIPdialogExecChangeAddCons(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the change bounds command"]
+    pub fn SCIPdialogExecChangeBounds(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the freetransproblem command"]
+    pub fn SCIPdialogExecChangeFreetransproblem(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the changing the objective sense"]
+    pub fn SCIPdialogExecChangeObjSense(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the checksol command"]
+    pub fn SCIPdialogExecChecksol(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the cliquegraph command"]
+    pub fn SCIPdialogExecCliquegraph(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display benders command"]
+    pub fn SCIPdialogExecDisplayBenders(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display branching command"]
+    pub fn SCIPdialogExecDisplayBranching(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display compression command"]
+    pub fn SCIPdialogExecDisplayCompression(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display conflict command"]
+    pub fn SCIPdialogExecDisplayConflict(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display conshdlrs command"]
+    pub fn SCIPdialogExecDisplayConshdlrs(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display displaycols command"]
+    pub fn SCIPdialogExecDisplayDisplaycols(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display exprhdlrs command"]
+    pub fn SCIPdialogExecDisplayExprhdlrs(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display cutselectors command"]
+    pub fn SCIPdialogExecDisplayCutselectors(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display heuristics command"]
+    pub fn SCIPdialogExecDisplayHeuristics(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display IIS command"]
+    pub fn SCIPdialogExecDisplayIIS(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display memory command"]
+    pub fn SCIPdialogExecDisplayMemory(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display nodeselectors command"]
+    pub fn SCIPdialogExecDisplayNodeselectors(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display nlpi command"]
+    pub fn SCIPdialogExecDisplayNlpi(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display parameters command"]
+    pub fn SCIPdialogExecDisplayParameters(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display presolvers command"]
+    pub fn SCIPdialogExecDisplayPresolvers(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display pricer command"]
+    pub fn SCIPdialogExecDisplayPricers(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display problem command"]
+    pub fn SCIPdialogExecDisplayProblem(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display propagators command"]
+    pub fn SCIPdialogExecDisplayPropagators(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display readers command"]
+    pub fn SCIPdialogExecDisplayReaders(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display relaxators command"]
+    pub fn SCIPdialogExecDisplayRelaxators(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display separators command"]
+    pub fn SCIPdialogExecDisplaySeparators(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display solution command"]
+    pub fn SCIPdialogExecDisplaySolution(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display finitesolution command"]
+    pub fn SCIPdialogExecDisplayFiniteSolution(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display dual solution command"]
+    pub fn SCIPdialogExecDisplayDualSolution(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display of solutions in the pool command"]
+    pub fn SCIPdialogExecDisplaySolutionPool(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display subproblem command"]
+    pub fn SCIPdialogExecDisplaySubproblem(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display subsolution command"]
+    pub fn SCIPdialogExecDisplaySubSolution(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display statistics command"]
+    pub fn SCIPdialogExecDisplayStatistics(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display symmetry command"]
+    pub fn SCIPdialogExecDisplaySymmetry(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display reoptstatistics command"]
+    pub fn SCIPdialogExecDisplayReoptStatistics(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display transproblem command"]
+    pub fn SCIPdialogExecDisplayTransproblem(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display value command"]
+    pub fn SCIPdialogExecDisplayValue(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display varbranchstatistics command"]
+    pub fn SCIPdialogExecDisplayVarbranchstatistics(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display LP solution quality command"]
+    pub fn SCIPdialogExecDisplayLPSolutionQuality(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the display transsolution command"]
+    pub fn SCIPdialogExecDisplayTranssolution(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the help command"]
+    pub fn SCIPdialogExecHelp(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the free command"]
+    pub fn SCIPdialogExecFree(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the newstart command"]
+    pub fn SCIPdialogExecNewstart(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the transform command"]
+    pub fn SCIPdialogExecTransform(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the optimize command"]
+    pub fn SCIPdialogExecOptimize(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the parallelopt command"]
+    pub fn SCIPdialogExecConcurrentOpt(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the presolve command"]
+    pub fn SCIPdialogExecPresolve(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the iis command"]
+    pub fn SCIPdialogExecIIS(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the quit command"]
+    pub fn SCIPdialogExecQuit(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the read command"]
+    pub fn SCIPdialogExecRead(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set default command"]
+    pub fn SCIPdialogExecSetDefault(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set load command"]
+    pub fn SCIPdialogExecSetLoad(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set save command"]
+    pub fn SCIPdialogExecSetSave(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set diffsave command"]
+    pub fn SCIPdialogExecSetDiffsave(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set parameter command"]
+    pub fn SCIPdialogExecSetParam(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog description method for the set parameter command"]
+    pub fn SCIPdialogDescSetParam(scip: *mut SCIP, dialog: *mut SCIP_DIALOG) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the fix parameter command"]
+    pub fn SCIPdialogExecFixParam(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog description method for the fix parameter command"]
+    pub fn SCIPdialogDescFixParam(scip: *mut SCIP, dialog: *mut SCIP_DIALOG) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set branching direction command"]
+    pub fn SCIPdialogExecSetBranchingDirection(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set branching priority command"]
+    pub fn SCIPdialogExecSetBranchingPriority(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set heuristics aggressive command"]
+    pub fn SCIPdialogExecSetHeuristicsAggressive(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set heuristics default command"]
+    pub fn SCIPdialogExecSetHeuristicsDefault(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set heuristics fast command"]
+    pub fn SCIPdialogExecSetHeuristicsFast(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set heuristics off command"]
+    pub fn SCIPdialogExecSetHeuristicsOff(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set presolving aggressive command"]
+    pub fn SCIPdialogExecSetPresolvingAggressive(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set presolving default command"]
+    pub fn SCIPdialogExecSetPresolvingDefault(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set presolving fast command"]
+    pub fn SCIPdialogExecSetPresolvingFast(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set presolving off command"]
+    pub fn SCIPdialogExecSetPresolvingOff(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set separating aggressive command"]
+    pub fn SCIPdialogExecSetSeparatingAggressive(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set separating default command"]
+    pub fn SCIPdialogExecSetSeparatingDefault(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set separating fast command"]
+    pub fn SCIPdialogExecSetSeparatingFast(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set separating off command"]
+    pub fn SCIPdialogExecSetSeparatingOff(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis counter command"]
+    pub fn SCIPdialogExecSetEmphasisCounter(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis cpsolver command"]
+    pub fn SCIPdialogExecSetEmphasisCpsolver(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis easy CIP command"]
+    pub fn SCIPdialogExecSetEmphasisEasycip(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis feasibility command"]
+    pub fn SCIPdialogExecSetEmphasisFeasibility(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis hard LP command"]
+    pub fn SCIPdialogExecSetEmphasisHardlp(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis optimality command"]
+    pub fn SCIPdialogExecSetEmphasisOptimality(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis numerics command"]
+    pub fn SCIPdialogExecSetEmphasisNumerics(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set emphasis benchmark command"]
+    pub fn SCIPdialogExecSetEmphasisBenchmark(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for the set limits objective command"]
+    pub fn SCIPdialogExecSetLimitsObjective(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+unsafe extern "C" {
+    #[doc = " dialog execution method for linear constraint type classification"]
+    pub fn SCIPdialogExecDisplayLinearConsClassification(
+        scip: *mut SCIP,
+        dialog: *mut SCIP_DIALOG,
+        dialoghdlr: *mut SCIP_DIALOGHDLR,
+        nextdialog: *mut *mut SCIP_DIALOG,
+    ) -> SCIP_RETCODE;
+}
+//@ common 1330
+unsafe extern "C" {
+    #[doc = " compares two nonlinear handlers by detection priority\n\n if handlers have same detection priority, then compare by name"]
+    pub fn SCIPnlhdlrComp(
+        elem1: *mut ::std::os::raw::c_void,
+        elem2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+//@ common 1434
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+    pub gp_offset: ::std::os::raw::c_uint,
+    pub fp_offset: ::std::os::raw::c_uint,
+    pub overflow_arg_area: *mut ::std::os::raw::c_void,
+    pub reg_save_area: *mut ::std::os::raw::c_void,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of __va_list_tag"][::std::mem::size_of::<__va_list_tag>() - 24usize];
+    ["Alignment of __va_list_tag"][::std::mem::align_of::<__va_list_tag>() - 8usize];
+    ["Offset of field: __va_list_tag::gp_offset"]
+        [::std::mem::offset_of!(__va_list_tag, gp_offset) - 0usize];
+    ["Offset of field: __va_list_tag::fp_offset"]
+        [::std::mem::offset_of!(__va_list_tag, fp_offset) - 4usize];
+    ["Offset of field: __va_list_tag::overflow_arg_area"]
+        [::std::mem::offset_of!(__va_list_tag, overflow_arg_area) - 8usize];
+    ["Offset of field: __va_list_tag::reg_save_area"]
+        [::std::mem::offset_of!(__va_list_tag, reg_save_area) - 16usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __locale_data {
+    pub _address: u8,
+}
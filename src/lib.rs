@@ -1,4 +1,8 @@
 //! This crate exposes automatically generated raw bindings to [SCIP](https://scipopt.org/)'s C-API. The documentation is automatically generated from the C-API docs, for further info please refer to SCIP's original [documentation](https://scipopt.org/doc/html/).
+//!
+//! All items are available at the crate root. They are also grouped into one module per SCIP
+//! header, e.g. `scip_sys::scip_var` for `scip/scip_var.h` or `scip_sys::cons::linear` for
+//! `scip/cons_linear.h`.
 
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
@@ -6,9 +10,45 @@
 #![allow(dead_code)]
 #![allow(improper_ctypes)]
 
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+// bindgen's output is kept as it is. Clippy flags its bitfield accessors (`missing_safety_doc`,
+// `ptr_offset_with_cast`), the enum conversions (`useless_transmute`), the math constants of the
+// system headers (`approx_constant`), SCIP's long parameter lists and, with `runtime-load`, the
+// function pointers of `ScipLibrary` (`type_complexity`). A lint attribute on `include!` itself
+// is ignored, hence the module.
+#[allow(
+    clippy::missing_safety_doc,
+    clippy::ptr_offset_with_cast,
+    clippy::useless_transmute,
+    clippy::approx_constant,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
+pub use bindings::*;
+
+// Modules re-exporting the items of each SCIP header, e.g. `scip_var` or `cons::linear`
+include!(concat!(env!("OUT_DIR"), "/modules.rs"));
+
+#[cfg(not(feature = "runtime-load"))]
+pub mod shim;
+
+#[cfg(feature = "runtime-load")]
+pub mod runtime_load;
+
+/// The version of the SCIP the crate was built against, the optional components it includes,
+/// and the licenses and SBOM of what the crate links.
+///
+/// The build script also sets the `scip_version_major`, `scip_version_minor`, `scip_api_version`
+/// and `scip_tpi` cfgs and the `scip_with_<component>` flags, and passes the same facts to the
+/// build scripts of dependent crates as `DEP_SCIP_VERSION_MAJOR`, `DEP_SCIP_WITH_EXACT`, ...
+pub mod build_info {
+    include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+    include!(concat!(env!("OUT_DIR"), "/licenses.rs"));
+}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "cons-linear", not(feature = "runtime-load")))]
 mod tests {
     use super::*;
     use std::ffi::CString;
@@ -0,0 +1,142 @@
+//! Opening libscip at runtime, for the `runtime-load` feature.
+//!
+//! With this feature the crate does not link against libscip. Its functions are fields and
+//! methods of [`ScipLibrary`] instead, which is created by opening the
+//! library with [`ScipLibrary::load`](crate::ScipLibrary::load) or
+//! [`ScipLibrary::load_from`](crate::ScipLibrary::load_from).
+
+use crate::ScipLibrary;
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Why libscip could not be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// No library could be opened. Holds every path that was tried, with its error.
+    NotFound(Vec<(PathBuf, libloading::Error)>),
+    /// The library was opened, but lacks these functions, e.g. because it is an older SCIP.
+    MissingSymbols(Vec<&'static str>),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::NotFound(attempts) => {
+                write!(f, "could not open libscip")?;
+                for (path, error) in attempts {
+                    write!(f, "\n  {}: {}", path.display(), error)?;
+                }
+                Ok(())
+            }
+            LoadError::MissingSymbols(names) => write!(
+                f,
+                "libscip lacks {} functions of SCIP {}.{}.{}: {}",
+                names.len(),
+                crate::build_info::VERSION_MAJOR,
+                crate::build_info::VERSION_MINOR,
+                crate::build_info::VERSION_PATCH,
+                names.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+/// The file names libscip may have on this platform.
+fn library_file_names() -> Vec<OsString> {
+    let mut names = vec![libloading::library_filename("scip")];
+    if cfg!(windows) {
+        names.push("libscip.dll".into());
+    } else if cfg!(target_os = "macos") {
+        names.push(
+            format!(
+                "libscip.{}.{}.dylib",
+                crate::build_info::VERSION_MAJOR,
+                crate::build_info::VERSION_MINOR
+            )
+            .into(),
+        );
+    } else {
+        names.push(
+            format!(
+                "libscip.so.{}.{}",
+                crate::build_info::VERSION_MAJOR,
+                crate::build_info::VERSION_MINOR
+            )
+            .into(),
+        );
+    }
+    names
+}
+
+/// The paths [`ScipLibrary::load`] tries, in this order: the library in `SCIPOPTDIR`, then the
+/// bare file names, which the system loader looks up in its search path.
+pub fn load_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = env::var_os("SCIPOPTDIR") {
+        let lib_dir = Path::new(&dir).join(if cfg!(windows) { "bin" } else { "lib" });
+        candidates.extend(library_file_names().iter().map(|name| lib_dir.join(name)));
+    }
+    candidates.extend(library_file_names().into_iter().map(PathBuf::from));
+    candidates
+}
+
+impl ScipLibrary {
+    /// Open libscip from `$SCIPOPTDIR/lib` (`bin` on Windows) if `SCIPOPTDIR` is set, or else
+    /// through the search path of the system loader.
+    ///
+    /// # Safety
+    /// Opening a library runs its initialization code, and the library must be a libscip with
+    /// the API these bindings were made for.
+    pub unsafe fn load() -> Result<Self, LoadError> {
+        Self::open_first(load_candidates())
+    }
+
+    /// Open libscip from `path`, which is either the library itself or the directory containing
+    /// it.
+    ///
+    /// # Safety
+    /// See [`ScipLibrary::load`].
+    pub unsafe fn load_from(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            Self::open_first(library_file_names().iter().map(|name| path.join(name)))
+        } else {
+            Self::open_first([path.to_path_buf()])
+        }
+    }
+
+    unsafe fn open_first(candidates: impl IntoIterator<Item = PathBuf>) -> Result<Self, LoadError> {
+        let mut attempts = Vec::new();
+        for path in candidates {
+            match libloading::Library::new(&path) {
+                Ok(library) => return Self::from_library(library),
+                Err(error) => attempts.push((path, error)),
+            }
+        }
+        Err(LoadError::NotFound(attempts))
+    }
+}
+
+/// The function `name` (NUL terminated) of `library`, or `None` after adding it to `missing`.
+///
+/// # Safety
+/// `T` must be the function pointer type of the symbol.
+#[doc(hidden)]
+pub unsafe fn symbol<T: Copy>(
+    library: &libloading::Library,
+    name: &'static str,
+    missing: &mut Vec<&'static str>,
+) -> Option<T> {
+    match library.get::<T>(name.as_bytes()) {
+        Ok(symbol) => Some(*symbol),
+        Err(_) => {
+            missing.push(name.trim_end_matches('\0'));
+            None
+        }
+    }
+}
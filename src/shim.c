@@ -0,0 +1,116 @@
+/* Real symbols for the parts of SCIP's API that only exist as macros or static inline functions
+ * in the headers, so that they can be called from Rust. See src/shim.rs for the declarations.
+ *
+ * The file is compiled by build.rs against the headers of the SCIP that is linked, with NDEBUG
+ * defined like SCIP's own release builds, so the wrappers get the same inlined implementations a
+ * C plugin would. Functions that are macros only in release builds (SCIPisEQ, SCIPvarGetLbLocal,
+ * ...) are still exported by libscip and bound directly, so they have no wrapper here.
+ */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "scip/scip.h"
+
+/*
+ * memory (scip_mem.h)
+ *
+ * The macros derive the element size from the pointer type; here it is passed explicitly and the
+ * allocation is done in bytes.
+ */
+
+/** size of num elements of typesize bytes, or 0 if that overflows */
+static size_t arraySize(size_t num, size_t typesize)
+{
+   if( typesize != 0 && num > SIZE_MAX / typesize )
+      return 0;
+   return num * typesize;
+}
+
+SCIP_RETCODE scip_sys_SCIPallocMemoryArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   (void)scip;
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocMemorySize(scip, ptr, size);
+}
+
+void scip_sys_SCIPfreeMemoryArray(SCIP* scip, void** ptr)
+{
+   (void)scip;
+   SCIPfreeMemorySize(scip, ptr);
+}
+
+SCIP_RETCODE scip_sys_SCIPallocBlockMemoryArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocBlockMemorySize(scip, ptr, size);
+}
+
+SCIP_RETCODE scip_sys_SCIPallocClearBlockMemoryArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocClearBlockMemoryArray(scip, (char**)ptr, size);
+}
+
+void scip_sys_SCIPfreeBlockMemoryArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   SCIPfreeBlockMemorySize(scip, ptr, arraySize(num, typesize));
+}
+
+SCIP_RETCODE scip_sys_SCIPallocBufferArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocBufferArray(scip, (char**)ptr, size);
+}
+
+SCIP_RETCODE scip_sys_SCIPallocClearBufferArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocClearBufferArray(scip, (char**)ptr, size);
+}
+
+void scip_sys_SCIPfreeBufferArray(SCIP* scip, void** ptr)
+{
+   SCIPfreeBufferArray(scip, (char**)ptr);
+}
+
+SCIP_RETCODE scip_sys_SCIPallocCleanBufferArray(SCIP* scip, void** ptr, size_t num, size_t typesize)
+{
+   size_t size = arraySize(num, typesize);
+   if( size == 0 && num != 0 )
+      return SCIP_NOMEMORY;
+   return SCIPallocCleanBufferArray(scip, (char**)ptr, size);
+}
+
+void scip_sys_SCIPfreeCleanBufferArray(SCIP* scip, void** ptr)
+{
+   SCIPfreeCleanBufferArray(scip, (char**)ptr);
+}
+
+/*
+ * messages (pub_message.h)
+ *
+ * The message macros are variadic and pick up __FILE__ and __LINE__ at the call site; the
+ * wrappers take an already formatted message and the location.
+ */
+
+void scip_sys_SCIPdebugMsg(SCIP* scip, const char* sourcefile, int sourceline, const char* msg)
+{
+   SCIPprintDebugMessage(scip, sourcefile, sourceline, "%s\n", msg);
+}
+
+void scip_sys_SCIPerrorMessage(const char* sourcefile, int sourceline, const char* msg)
+{
+   SCIPmessagePrintErrorHeader(sourcefile, sourceline);
+   SCIPmessagePrintError("%s\n", msg);
+}
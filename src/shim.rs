@@ -0,0 +1,285 @@
+//! Helpers that SCIP only provides as macros or `static inline` functions, and which are therefore
+//! missing from the generated bindings.
+//!
+//! They are backed by a small C library (`src/shim.c`) that the build script compiles against the
+//! SCIP headers. The memory functions take the element type from the pointer, like the C macros
+//! do. Functions that SCIP replaces by macros in release builds but still exports, like
+//! `SCIPisEQ` or `SCIPvarGetLbLocal`, are bound at the crate root and not repeated here.
+//!
+//! [`scip_call!`](crate::scip_call), [`scip_debug_msg!`](crate::scip_debug_msg) and
+//! [`scip_error_message!`](crate::scip_error_message) stand in for `SCIP_CALL`, `SCIPdebugMsg` and
+//! `SCIPerrorMessage`.
+
+use crate::{SCIP, SCIP_RETCODE};
+use std::ffi::{c_char, c_int, c_void, CString};
+use std::mem::size_of;
+
+unsafe extern "C" {
+    fn scip_sys_SCIPallocMemoryArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPfreeMemoryArray(scip: *mut SCIP, ptr: *mut *mut c_void);
+    fn scip_sys_SCIPallocBlockMemoryArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPallocClearBlockMemoryArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPfreeBlockMemoryArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    );
+    fn scip_sys_SCIPallocBufferArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPallocClearBufferArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPfreeBufferArray(scip: *mut SCIP, ptr: *mut *mut c_void);
+    fn scip_sys_SCIPallocCleanBufferArray(
+        scip: *mut SCIP,
+        ptr: *mut *mut c_void,
+        num: usize,
+        typesize: usize,
+    ) -> SCIP_RETCODE;
+    fn scip_sys_SCIPfreeCleanBufferArray(scip: *mut SCIP, ptr: *mut *mut c_void);
+
+    fn scip_sys_SCIPdebugMsg(
+        scip: *mut SCIP,
+        sourcefile: *const c_char,
+        sourceline: c_int,
+        msg: *const c_char,
+    );
+    fn scip_sys_SCIPerrorMessage(sourcefile: *const c_char, sourceline: c_int, msg: *const c_char);
+}
+
+/// `SCIPallocMemory(scip, ptr)`: allocates a `T` with `malloc`.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes.
+pub unsafe fn SCIPallocMemory<T>(scip: *mut SCIP, ptr: *mut *mut T) -> SCIP_RETCODE {
+    SCIPallocMemoryArray(scip, ptr, 1)
+}
+
+/// `SCIPallocMemoryArray(scip, ptr, num)`: allocates `num` `T`s with `malloc`.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes.
+pub unsafe fn SCIPallocMemoryArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocMemoryArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPfreeMemory(scip, ptr)` and `SCIPfreeMemoryArray(scip, ptr)`: frees memory allocated by
+/// [`SCIPallocMemory`] or [`SCIPallocMemoryArray`] and sets `*ptr` to null.
+///
+/// # Safety
+/// `*ptr` must have been allocated by one of those functions and not been freed yet.
+pub unsafe fn SCIPfreeMemoryArray<T>(scip: *mut SCIP, ptr: *mut *mut T) {
+    scip_sys_SCIPfreeMemoryArray(scip, ptr.cast())
+}
+
+/// `SCIPallocBlockMemory(scip, ptr)`: allocates a `T` in SCIP's block memory.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes.
+pub unsafe fn SCIPallocBlockMemory<T>(scip: *mut SCIP, ptr: *mut *mut T) -> SCIP_RETCODE {
+    SCIPallocBlockMemoryArray(scip, ptr, 1)
+}
+
+/// `SCIPallocBlockMemoryArray(scip, ptr, num)`: allocates `num` `T`s in SCIP's block memory.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes.
+pub unsafe fn SCIPallocBlockMemoryArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocBlockMemoryArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPallocClearBlockMemoryArray(scip, ptr, num)`: like [`SCIPallocBlockMemoryArray`], with the
+/// memory set to zero.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes.
+pub unsafe fn SCIPallocClearBlockMemoryArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocClearBlockMemoryArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPfreeBlockMemory(scip, ptr)`: frees a `T` allocated by [`SCIPallocBlockMemory`] and sets
+/// `*ptr` to null.
+///
+/// # Safety
+/// `*ptr` must have been allocated by [`SCIPallocBlockMemory`] for the same `scip`.
+pub unsafe fn SCIPfreeBlockMemory<T>(scip: *mut SCIP, ptr: *mut *mut T) {
+    SCIPfreeBlockMemoryArray(scip, ptr, 1)
+}
+
+/// `SCIPfreeBlockMemoryArray(scip, ptr, num)`: frees `num` `T`s allocated in block memory and
+/// sets `*ptr` to null.
+///
+/// # Safety
+/// `*ptr` must have been allocated in the block memory of `scip` with the same `num`.
+pub unsafe fn SCIPfreeBlockMemoryArray<T>(scip: *mut SCIP, ptr: *mut *mut T, num: usize) {
+    scip_sys_SCIPfreeBlockMemoryArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPallocBuffer(scip, ptr)`: allocates a `T` in SCIP's buffer memory.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes. Buffers must be freed
+/// in the reverse order of their allocation.
+pub unsafe fn SCIPallocBuffer<T>(scip: *mut SCIP, ptr: *mut *mut T) -> SCIP_RETCODE {
+    SCIPallocBufferArray(scip, ptr, 1)
+}
+
+/// `SCIPallocBufferArray(scip, ptr, num)`: allocates `num` `T`s in SCIP's buffer memory.
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance and `ptr` must be valid for writes. Buffers must be freed
+/// in the reverse order of their allocation.
+pub unsafe fn SCIPallocBufferArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocBufferArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPallocClearBufferArray(scip, ptr, num)`: like [`SCIPallocBufferArray`], with the memory
+/// set to zero.
+///
+/// # Safety
+/// See [`SCIPallocBufferArray`].
+pub unsafe fn SCIPallocClearBufferArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocClearBufferArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPfreeBuffer(scip, ptr)` and `SCIPfreeBufferArray(scip, ptr)`: frees a buffer and sets
+/// `*ptr` to null.
+///
+/// # Safety
+/// `*ptr` must be the buffer of `scip` allocated last and not freed yet.
+pub unsafe fn SCIPfreeBufferArray<T>(scip: *mut SCIP, ptr: *mut *mut T) {
+    scip_sys_SCIPfreeBufferArray(scip, ptr.cast())
+}
+
+/// `SCIPallocCleanBufferArray(scip, ptr, num)`: allocates `num` `T`s in SCIP's clean buffer,
+/// whose memory is zero and must be zero again when it is freed.
+///
+/// # Safety
+/// See [`SCIPallocBufferArray`].
+pub unsafe fn SCIPallocCleanBufferArray<T>(
+    scip: *mut SCIP,
+    ptr: *mut *mut T,
+    num: usize,
+) -> SCIP_RETCODE {
+    scip_sys_SCIPallocCleanBufferArray(scip, ptr.cast(), num, size_of::<T>())
+}
+
+/// `SCIPfreeCleanBufferArray(scip, ptr)`: frees a clean buffer and sets `*ptr` to null.
+///
+/// # Safety
+/// `*ptr` must be the clean buffer of `scip` allocated last, with all its memory set to zero.
+pub unsafe fn SCIPfreeCleanBufferArray<T>(scip: *mut SCIP, ptr: *mut *mut T) {
+    scip_sys_SCIPfreeCleanBufferArray(scip, ptr.cast())
+}
+
+/// A C string of `text`, without the interior NULs it may contain.
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "")).unwrap()
+}
+
+/// Prints `msg` as a debug message of `scip`, see [`scip_debug_msg!`](crate::scip_debug_msg).
+///
+/// # Safety
+/// `scip` must be a valid SCIP instance.
+pub unsafe fn debug_message(scip: *mut SCIP, sourcefile: &str, sourceline: u32, msg: &str) {
+    let sourcefile = c_string(sourcefile);
+    let msg = c_string(msg);
+    scip_sys_SCIPdebugMsg(scip, sourcefile.as_ptr(), sourceline as c_int, msg.as_ptr())
+}
+
+/// Prints `msg` as an error message, see [`scip_error_message!`](crate::scip_error_message).
+pub fn error_message(sourcefile: &str, sourceline: u32, msg: &str) {
+    let sourcefile = c_string(sourcefile);
+    let msg = c_string(msg);
+    unsafe { scip_sys_SCIPerrorMessage(sourcefile.as_ptr(), sourceline as c_int, msg.as_ptr()) }
+}
+
+/// `SCIP_CALL(x)`: evaluates a call returning a `SCIP_RETCODE` and, unless it is `SCIP_OKAY`,
+/// prints an error message and returns the code from the enclosing function.
+#[macro_export]
+macro_rules! scip_call {
+    ($call:expr) => {{
+        let retcode: $crate::SCIP_RETCODE = $call;
+        if retcode != $crate::SCIP_Retcode_SCIP_OKAY {
+            $crate::shim::error_message(
+                file!(),
+                line!(),
+                &format!("Error <{}> in function call", retcode),
+            );
+            return retcode;
+        }
+    }};
+}
+
+/// `SCIPdebugMsg(scip, ...)`: prints a debug message with the source location. Like defining
+/// `SCIP_DEBUG` in C, this is opt-in: nothing is printed unless scip-sys is built with
+/// `--cfg scip_debug`, e.g. through `RUSTFLAGS="--cfg scip_debug"`.
+///
+/// # Safety
+/// Like the calls in [`scip_call!`](crate::scip_call), it has to be used in an `unsafe` block or
+/// function: it calls [`debug_message`], so `scip` must be a valid SCIP instance. This also holds
+/// without `scip_debug`, where nothing is printed.
+#[macro_export]
+macro_rules! scip_debug_msg {
+    ($scip:expr, $($arg:tt)*) => {
+        if $crate::shim::DEBUG_MESSAGES {
+            $crate::shim::debug_message($scip, file!(), line!(), &format!($($arg)*));
+        }
+    };
+}
+
+/// Whether [`scip_debug_msg!`](crate::scip_debug_msg) prints, i.e. scip-sys was built with
+/// `--cfg scip_debug`. The macro expands in the calling crate, so it cannot check the cfg itself.
+#[doc(hidden)]
+pub const DEBUG_MESSAGES: bool = cfg!(scip_debug);
+
+/// `SCIPerrorMessage(...)`: prints an error message with the source location.
+#[macro_export]
+macro_rules! scip_error_message {
+    ($($arg:tt)*) => {
+        $crate::shim::error_message(file!(), line!(), &format!($($arg)*))
+    };
+}
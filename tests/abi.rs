@@ -0,0 +1,341 @@
+//! Checks the bindings the crate was built with against the SCIP it was built against, so that
+//! bindings that drifted from the headers are caught on any machine, not only where they are
+//! generated:
+//!
+//! - the sizes, alignments and field offsets of the bindgen layout tests, and the values of the
+//!   enums, become `_Static_assert`s in a C file that is compiled against the SCIP headers;
+//! - every function of libscip in the bindings must be exported by the linked libscip (read from
+//!   its ELF dynamic symbol table, so only for a shared libscip on Linux).
+//!
+//! The build script passes the SCIP it found in `SCIP_SYS_INCLUDE_DIR` and `SCIP_SYS_LIB_DIR`.
+//! Builds without SCIP (docs.rs, check-only) have neither, and the checks are skipped.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+const BINDINGS: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+const MODULES: &str = include_str!(concat!(env!("OUT_DIR"), "/modules.rs"));
+
+/// Marks the messages of the generated assertions in the compiler output.
+const MARKER: &str = "scip-sys abi:";
+
+/// Field names bindgen appends `_` to.
+const RUST_KEYWORDS: [&str; 20] = [
+    "as", "box", "break", "const", "continue", "crate", "else", "enum", "fn", "impl", "in", "loop",
+    "match", "mod", "move", "ref", "self", "type", "use", "where",
+];
+
+#[test]
+fn layouts_and_enums_match_the_headers() {
+    // both are set together by the build script, neither for builds without SCIP
+    let (Some(include_dir), Some(target)) = (
+        option_env!("SCIP_SYS_INCLUDE_DIR").map(Path::new),
+        option_env!("SCIP_SYS_TARGET"),
+    ) else {
+        eprintln!("skipped: the crate was built without SCIP headers");
+        return;
+    };
+    let tags = header_tags(include_dir);
+    let (source, count) = assertions(&tags);
+    assert!(count > 0, "found nothing to check in the bindings");
+
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("scip-sys-abi");
+    fs::create_dir_all(&dir).unwrap();
+    let c_file = dir.join("abi.c");
+    fs::write(&c_file, source).unwrap();
+
+    let compiler = cc::Build::new()
+        .include(include_dir)
+        // like the shim, so the headers are read as for the bindings
+        .define("NDEBUG", None)
+        .target(target)
+        .host(target)
+        .opt_level(0)
+        .cargo_metadata(false)
+        .warnings(false)
+        .try_get_compiler()
+        .unwrap();
+    let mut command = compiler.to_command();
+    if compiler.is_like_msvc() {
+        command
+            .arg("/std:c11")
+            .arg("/c")
+            .arg(format!("/Fo{}", dir.join("abi.obj").display()));
+    } else {
+        command.arg("-c").arg("-o").arg(dir.join("abi.o"));
+    }
+    let output = command.arg(&c_file).output().unwrap();
+    if output.status.success() {
+        return;
+    }
+
+    // the failed assertions, told apart from the source lines compilers quote with the errors
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let errors: Vec<&str> = stderr
+        .lines()
+        .chain(stdout.lines())
+        .filter(|line| line.contains("error"))
+        .collect();
+    let mismatches: BTreeSet<&str> = errors
+        .iter()
+        .filter_map(|line| {
+            let (error, message) = line.split_once(MARKER)?;
+            (!error.contains("_Static_assert")).then_some(message)
+        })
+        .map(|message| message.trim().trim_end_matches(['"', '\'', ')', ';']))
+        .collect();
+    let other_errors: Vec<&str> = errors
+        .iter()
+        .filter(|line| !line.contains(MARKER))
+        .take(20)
+        .copied()
+        .collect();
+    panic!(
+        "{} of {count} checks of the bindings failed against the headers in {} \
+         (the C file is {}):\n  {}\n{}",
+        mismatches.len(),
+        include_dir.display(),
+        c_file.display(),
+        mismatches.into_iter().collect::<Vec<_>>().join("\n  "),
+        other_errors.join("\n")
+    );
+}
+
+#[test]
+fn functions_are_exported_by_libscip() {
+    let Some(lib_dir) = option_env!("SCIP_SYS_LIB_DIR").map(Path::new) else {
+        eprintln!("skipped: the crate was built without a SCIP library");
+        return;
+    };
+    let library = fs::read_dir(lib_dir)
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name == "libscip.so" || name.starts_with("libscip.so.")
+        })
+        .min();
+    let Some(exported) = library.as_deref().and_then(exported_symbols) else {
+        eprintln!("skipped: no shared ELF libscip in {}", lib_dir.display());
+        return;
+    };
+
+    let functions = scip_functions();
+    assert!(!functions.is_empty(), "found no functions in the bindings");
+    let missing: Vec<&str> = functions
+        .into_iter()
+        .filter(|function| !exported.contains(*function))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "{} of the functions in the bindings are not exported by {}:\n  {}",
+        missing.len(),
+        library.unwrap().display(),
+        missing.join("\n  ")
+    );
+}
+
+/// The `struct` and `union` tags defined below `include_dir`, with their keyword and the header
+/// defining them.
+fn header_tags(include_dir: &Path) -> HashMap<String, (&'static str, String)> {
+    let mut tags = HashMap::new();
+    let mut dirs = vec![include_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for path in fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+        {
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|extension| extension != "h") {
+                continue;
+            }
+            let Ok(contents) = fs::read(&path) else {
+                continue;
+            };
+            let contents = String::from_utf8_lossy(&contents);
+            let header = path.strip_prefix(include_dir).unwrap();
+            let header = header.to_string_lossy().replace('\\', "/");
+            let words: Vec<&str> = contents
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '{'))
+                .filter(|word| !word.is_empty())
+                .collect();
+            for window in words.windows(3) {
+                let keyword = match window[0] {
+                    "struct" => "struct",
+                    "union" => "union",
+                    _ => continue,
+                };
+                if window[2].starts_with('{') || window[1].ends_with('{') {
+                    let name = window[1].trim_end_matches('{');
+                    tags.entry(name.to_string())
+                        .or_insert_with(|| (keyword, header.clone()));
+                }
+            }
+        }
+    }
+    tags
+}
+
+/// The C source asserting the layouts and enum values of the bindings, and the number of
+/// assertions.
+fn assertions(tags: &HashMap<String, (&'static str, String)>) -> (String, usize) {
+    let mut headers = BTreeSet::new();
+    let mut checks = Vec::new();
+
+    // bindgen's layout tests: `["Size of T"][::std::mem::size_of::<T>() - 8usize];`
+    let mut rest = BINDINGS;
+    while let Some(start) = rest.find("[\"") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("\"]") else { break };
+        let label = &rest[..end];
+        let Some(value) = rest[end..]
+            .split_once("usize]")
+            .and_then(|(expression, _)| expression.rsplit_once("- "))
+            .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let (what, expression) = if let Some(name) = label.strip_prefix("Size of ") {
+            let Some(c_type) = c_type(name, tags, &mut headers) else {
+                continue;
+            };
+            (format!("size of {name}"), format!("sizeof({c_type})"))
+        } else if let Some(name) = label.strip_prefix("Alignment of ") {
+            let Some(c_type) = c_type(name, tags, &mut headers) else {
+                continue;
+            };
+            (
+                format!("alignment of {name}"),
+                format!("_Alignof({c_type})"),
+            )
+        } else if let Some(field) = label.strip_prefix("Offset of field: ") {
+            let Some((name, field)) = field.split_once("::") else {
+                continue;
+            };
+            if field.starts_with("_bitfield") || field.contains("__bindgen") {
+                continue;
+            }
+            let Some(c_type) = c_type(name, tags, &mut headers) else {
+                continue;
+            };
+            let field = match field.strip_suffix('_') {
+                Some(keyword) if RUST_KEYWORDS.contains(&keyword) => keyword,
+                _ => field.trim_start_matches("r#"),
+            };
+            (
+                format!("offset of {name}::{field}"),
+                format!("offsetof({c_type}, {field})"),
+            )
+        } else {
+            continue;
+        };
+        checks.push((what, expression, value.to_string()));
+    }
+
+    // constified enums: `pub type T = ::std::os::raw::c_int;` and `pub const T_VARIANT: T = -1;`
+    let enums: BTreeSet<&str> = BINDINGS
+        .lines()
+        .filter_map(|line| {
+            let (name, ty) = line.strip_prefix("pub type ")?.split_once(" = ")?;
+            ty.starts_with("::std::os::raw::c_").then_some(name)
+        })
+        .filter(|name| !name.contains("_bindgen_ty_"))
+        .collect();
+    for line in BINDINGS.lines() {
+        let Some((name, rest)) = line
+            .strip_prefix("pub const ")
+            .and_then(|l| l.split_once(": "))
+        else {
+            continue;
+        };
+        let Some((ty, value)) = rest.strip_suffix(';').and_then(|r| r.split_once(" = ")) else {
+            continue;
+        };
+        let Some(variant) = name.strip_prefix(ty).and_then(|n| n.strip_prefix('_')) else {
+            continue;
+        };
+        if !enums.contains(ty) || value.parse::<i64>().is_err() {
+            continue;
+        }
+        checks.push((
+            format!("value of {variant} ({ty})"),
+            variant.to_string(),
+            value.to_string(),
+        ));
+    }
+
+    let mut source = String::from("#include <stddef.h>\n");
+    for header in ["scip/def.h", "scip/scip.h", "scip/scipdefplugins.h"] {
+        source.push_str(&format!("#include \"{header}\"\n"));
+    }
+    for header in &headers {
+        source.push_str(&format!("#include \"{header}\"\n"));
+    }
+    source.push('\n');
+    for (what, expression, value) in &checks {
+        source.push_str(&format!(
+            "_Static_assert({expression} == {value}, \"{MARKER} {what} is {value} in the bindings\");\n"
+        ));
+    }
+    (source, checks.len())
+}
+
+/// How the type `name` of the bindings is written in C, collecting the header defining it. Types
+/// bindgen made up and those of the C library are not checked.
+fn c_type(
+    name: &str,
+    tags: &HashMap<String, (&'static str, String)>,
+    headers: &mut BTreeSet<String>,
+) -> Option<String> {
+    if name.contains("__bindgen") {
+        return None;
+    }
+    if let Some((keyword, header)) = tags.get(name) {
+        headers.insert(header.clone());
+        return Some(format!("{keyword} {name}"));
+    }
+    // a typedef of an anonymous struct or union, e.g. `SCIP_EXPRITER_USERDATA`
+    name.starts_with("SCIP_").then(|| name.to_string())
+}
+
+/// The functions of libscip in the bindings: those re-exported by a header module, or fields of
+/// `ScipLibrary` with `runtime-load`. The C library's functions are in neither.
+fn scip_functions() -> BTreeSet<&'static str> {
+    let items: BTreeSet<&str> = MODULES
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub use crate::{"))
+        .flat_map(|list| list.trim_end_matches("};").split(", "))
+        .collect();
+    let mut functions = BTreeSet::new();
+    let mut in_extern = false;
+    for line in BINDINGS.lines() {
+        if line.starts_with("unsafe extern \"C\" {") || line.starts_with("extern \"C\" {") {
+            in_extern = true;
+        } else if line == "}" {
+            in_extern = false;
+        } else if let Some(rest) = line.trim().strip_prefix("pub fn ").filter(|_| in_extern) {
+            let name = &rest[..rest.find('(').unwrap_or(rest.len())];
+            if items.contains(name) {
+                functions.insert(name);
+            }
+        } else if let Some((_, rest)) = line.split_once("runtime_load::symbol(&library, \"") {
+            functions.extend(rest.split_once("\\0").map(|(name, _)| name));
+        }
+    }
+    functions
+}
+
+/// The symbols an ELF shared library defines in its dynamic symbol table. `None` for anything
+/// else.
+fn exported_symbols(path: &Path) -> Option<BTreeSet<String>> {
+    let data = fs::read(path).ok()?;
+    scip_sys_build::elf::Elf::parse(&data)?.defined_symbols()
+}
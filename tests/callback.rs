@@ -0,0 +1,226 @@
+//! Checks how the bindgen callback evaluates SCIP's constant macros, see `callback.rs`.
+
+#[path = "../callback.rs"]
+#[allow(dead_code)]
+mod callback;
+#[path = "../doxygen.rs"]
+#[allow(dead_code)]
+mod doxygen;
+
+use bindgen::callbacks::{IntKind, ParseCallbacks, Token, TokenKind};
+use callback::{CastKind, DeriveCastedConstant, DoxygenComments};
+
+fn derive_casted_constant() -> DeriveCastedConstant {
+    DeriveCastedConstant::new()
+        .prefix("SCIP_")
+        .cast_type("SCIP_Real", CastKind::Float)
+        .cast_type(
+            "SCIP_Longint",
+            CastKind::Integer {
+                bits: 64,
+                signed: true,
+            },
+        )
+        .cast_type(
+            "SCIP_EVENTTYPE",
+            CastKind::Integer {
+                bits: 64,
+                signed: false,
+            },
+        )
+        .literal("SIZE_MAX", "18446744073709551615ULL")
+}
+
+/// Split a macro definition like clang does: identifiers, keywords, literals and punctuation.
+fn tokens(definition: &str) -> Vec<Token> {
+    const KEYWORDS: &[&str] = &[
+        "double", "float", "char", "short", "int", "long", "signed", "unsigned", "_Bool", "const",
+    ];
+    const PUNCTS: &[&str] = &["<<", ">>", "<=", ">=", "==", "!=", "&&", "||"];
+
+    let mut tokens = Vec::new();
+    let mut rest = definition.trim_start();
+    while !rest.is_empty() {
+        let word_end = |rest: &str| {
+            rest.char_indices()
+                .find(|&(i, c)| {
+                    let exponent_sign = c == '+' && rest[..i].ends_with(['e', 'E']);
+                    !(c.is_ascii_alphanumeric() || c == '_' || c == '.' || exponent_sign)
+                })
+                .map_or(rest.len(), |(i, _)| i)
+        };
+        let (len, kind) = if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            (word_end(rest), TokenKind::Literal)
+        } else if rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            let len = word_end(rest);
+            match KEYWORDS.contains(&&rest[..len]) {
+                true => (len, TokenKind::Keyword),
+                false => (len, TokenKind::Identifier),
+            }
+        } else {
+            let len = PUNCTS
+                .iter()
+                .find(|p| rest.starts_with(*p))
+                .map_or(1, |p| p.len());
+            (len, TokenKind::Punctuation)
+        };
+        tokens.push(Token {
+            kind,
+            raw: rest.as_bytes()[..len].into(),
+        });
+        rest = rest[len..].trim_start();
+    }
+    tokens
+}
+
+/// Run the callback on `#define <definition>` and return the resulting macro body.
+fn define(callback: &DeriveCastedConstant, definition: &str) -> String {
+    let mut tokens = tokens(definition);
+    let name = String::from_utf8(tokens[0].raw.to_vec()).unwrap();
+    callback.modify_macro(&name, &mut tokens);
+    let body: Vec<String> = tokens[1..]
+        .iter()
+        .map(|t| String::from_utf8(t.raw.to_vec()).unwrap())
+        .collect();
+    body.join(" ")
+}
+
+#[test]
+fn float_casts_keep_integers_floating_point() {
+    let callback = derive_casted_constant();
+    assert_eq!(define(&callback, "SCIP_ONE (double)1"), "1.0");
+    assert_eq!(define(&callback, "SCIP_INVALID (double)1e+99"), "1e99");
+    assert_eq!(define(&callback, "SCIP_HALF (SCIP_Real)1 / 2"), "0.5");
+}
+
+#[test]
+fn integer_casts_set_width_and_signedness() {
+    let callback = derive_casted_constant();
+    assert_eq!(
+        define(&callback, "SCIP_BIG (SCIP_Longint)1 << 40"),
+        "1099511627776"
+    );
+    assert_eq!(define(&callback, "SCIP_UMAX (unsigned)~0"), "4294967295");
+    assert_eq!(define(&callback, "SCIP_UMAX2 ~(unsigned)0"), "4294967295");
+    assert_eq!(define(&callback, "SCIP_MINUS_ONE ~0"), "- 1");
+    assert_eq!(define(&callback, "SCIP_TRUNC (int)2.5"), "2");
+    assert_eq!(define(&callback, "SCIP_WRAP (unsigned char)300"), "44");
+    assert_eq!(
+        define(&callback, "SCIP_MEM_NOLIMIT (SCIP_Longint)(SIZE_MAX>>20)"),
+        "17592186044415"
+    );
+    // Unsigned arithmetic wraps, `-1 < 0u` compares as unsigned
+    assert_eq!(define(&callback, "SCIP_NEG (unsigned)0 - 1"), "4294967295");
+    assert_eq!(define(&callback, "SCIP_CMP -1 < 0u"), "0");
+}
+
+#[test]
+fn int_c_macros_append_their_suffix() {
+    let callback = derive_casted_constant();
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_VARFIXED UINT64_C(0x000000002)"),
+        "2"
+    );
+    // Without the `ULL` of `UINT64_C` this would shift an `int` out of range
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_HIGH UINT64_C(0x1) << 40"),
+        "1099511627776"
+    );
+}
+
+#[test]
+fn referenced_macros_keep_their_type() {
+    let callback = derive_casted_constant();
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_A UINT64_C(0x000000001)"),
+        "1"
+    );
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_B UINT64_C(0x000000004)"),
+        "4"
+    );
+    assert_eq!(
+        define(
+            &callback,
+            "SCIP_EVENTTYPE_AB (SCIP_EVENTTYPE_A | SCIP_EVENTTYPE_B)"
+        ),
+        "5"
+    );
+    // `SCIP_EVENTTYPE_A` is 64 bits wide, so this is no overflow of an `int`
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_SHIFTED SCIP_EVENTTYPE_A << 40"),
+        "1099511627776"
+    );
+}
+
+#[test]
+fn constants_keep_their_c_type() {
+    let callback = derive_casted_constant();
+    let kind = |definition| {
+        let value = define(&callback, definition);
+        let name = definition.split_whitespace().next().unwrap();
+        (value, callback.int_macro(name, 0))
+    };
+    assert_eq!(
+        kind("SCIP_LONGINT_MAX (SCIP_Longint)(SIZE_MAX>>1)"),
+        ("9223372036854775807".to_string(), Some(IntKind::I64))
+    );
+    assert_eq!(
+        kind("SCIP_EVENTTYPE_VARFIXED UINT64_C(0x000000002)"),
+        ("2".to_string(), Some(IntKind::U64))
+    );
+    assert_eq!(
+        kind("SCIP_UMAX (unsigned)~0"),
+        ("4294967295".to_string(), Some(IntKind::U32))
+    );
+    // Plain `int` constants and the ones the callback leaves alone keep bindgen's type
+    assert_eq!(kind("SCIP_MAXSTRLEN 1024"), ("1024".to_string(), None));
+    assert_eq!(kind("SCIP_REAL_ONE (double)1"), ("1.0".to_string(), None));
+}
+
+#[test]
+fn unsigned_constants_may_exceed_i64() {
+    let callback = derive_casted_constant();
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_TOP UINT64_C(1) << 63"),
+        "9223372036854775808"
+    );
+    assert_eq!(
+        define(&callback, "SCIP_EVENTTYPE_ALL (SCIP_EVENTTYPE)~0"),
+        "18446744073709551615"
+    );
+    assert_eq!(
+        callback.int_macro("SCIP_EVENTTYPE_TOP", i64::MIN),
+        Some(IntKind::U64)
+    );
+}
+
+#[test]
+fn non_evaluable_macros_stay_untouched() {
+    let callback = derive_casted_constant();
+    assert_eq!(
+        define(&callback, "SCIP_CALL_FOO (SCIP_Real)foo(1)"),
+        "( SCIP_Real ) foo ( 1 )"
+    );
+    assert_eq!(
+        define(&callback, "SCIP_LONG (SCIP_Longint)1 << 63"),
+        "( SCIP_Longint ) 1 << 63"
+    );
+    // Signed overflow is undefined in C
+    assert_eq!(define(&callback, "SCIP_OVERFLOW 1 << 40"), "1 << 40");
+
+    let untranslated = callback.untranslated();
+    let untranslated = untranslated.lock().unwrap();
+    assert!(untranslated.contains("SCIP_CALL_FOO"));
+    assert!(untranslated.contains("SCIP_LONG"));
+}
+
+#[test]
+fn comments_escape_brackets_and_drop_empty_docs() {
+    assert_eq!(
+        DoxygenComments.process_comment("returns x[i] of SCIPgetVars()"),
+        Some(" returns x\\[i\\] of [`SCIPgetVars()`]".to_string())
+    );
+    // bindgen leaves out an empty doc, but would keep the raw comment for `None`
+    assert_eq!(DoxygenComments.process_comment("@{"), Some(String::new()));
+}
@@ -0,0 +1,107 @@
+//! Checks that the API features remove their items, from a header map like the one committed for
+//! the builds without SCIP headers (docs.rs, check-only) and in the bindings the crate was built
+//! with.
+
+#[path = "../feature_groups.rs"]
+#[allow(dead_code)]
+mod feature_groups;
+#[path = "../header_modules.rs"]
+#[allow(dead_code)]
+mod header_modules;
+#[path = "../prebuilt.rs"]
+#[allow(dead_code)]
+mod prebuilt;
+
+use std::path::Path;
+
+const BINDINGS: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+/// Core items, one of them from `pub_misc.h`
+const CORE: [&str; 3] = ["SCIPcreate", "SCIPsolve", "SCIPcalcMachineEpsilon"];
+
+fn declares(bindings: &str, function: &str) -> bool {
+    bindings.contains(&format!("fn {function}("))
+}
+
+/// The headers of a few items, as `header_modules::write_header_map` records them.
+fn header_map() -> header_modules::HeaderMap {
+    [
+        ("SCIPcreate", "scip/scip_general.h"),
+        ("SCIPsolve", "scip/scip_solve.h"),
+        ("SCIPcalcMachineEpsilon", "scip/pub_misc.h"),
+        ("SCIPcreateConsBasicLinear", "scip/cons_linear.h"),
+        ("SCIPlpiCreate", "lpi/lpi.h"),
+    ]
+    .into_iter()
+    .map(|(item, header)| (item.to_string(), header.to_string()))
+    .collect()
+}
+
+#[test]
+fn header_map_strips_disabled_groups() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    let bindings = prebuilt::read(&dir, "pregenerated").unwrap().unwrap();
+    assert!(declares(&bindings, "SCIPcreateConsBasicLinear"));
+
+    // no `CARGO_FEATURE_*` is set for tests, so this is a build without `full`
+    let (stripped, removed) = feature_groups::strip_disabled(&bindings, &header_map());
+    assert_eq!(removed, 2);
+    assert!(!declares(&stripped, "SCIPcreateConsBasicLinear"));
+    assert!(!declares(&stripped, "SCIPlpiCreate"));
+    for function in CORE {
+        assert!(declares(&stripped, function), "{function} is core");
+    }
+}
+
+#[test]
+fn built_bindings_follow_the_features() {
+    // without a header map the build keeps every item
+    if option_env!("SCIP_SYS_HEADER_MAP") == Some("none") {
+        return;
+    }
+    assert_eq!(
+        declares(BINDINGS, "SCIPcreateConsBasicLinear"),
+        cfg!(feature = "cons-linear"),
+    );
+    assert_eq!(declares(BINDINGS, "SCIPlpiCreate"), cfg!(feature = "lpi"));
+    for function in CORE {
+        assert!(declares(BINDINGS, function), "{function} is core");
+    }
+}
+
+#[test]
+fn items_split_around_brackets_in_literals_and_comments() {
+    let bindings = r##"/* a comment [ spanning
+   two lines */
+#[doc = " the interval [lb,ub"]
+pub const SCIP_A: &[u8; 3] = b"{(\0";
+pub const SCIP_B: u8 = b'}';
+#[doc = r#" a raw "[" string"#]
+unsafe extern "C" {
+    // a comment with an unmatched ( bracket
+    pub fn SCIPfoo(x: ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+
+pub type SCIP_C<'a> = &'a [u8];
+"##;
+    let lines: Vec<&str> = bindings.lines().collect();
+    let items = feature_groups::split_items(&lines);
+    let firsts: Vec<&str> = items.iter().map(|item| item[0]).collect();
+    assert_eq!(
+        firsts,
+        [
+            "/* a comment [ spanning",
+            "#[doc = \" the interval [lb,ub\"]",
+            "pub const SCIP_B: u8 = b'}';",
+            "#[doc = r#\" a raw \"[\" string\"#]",
+            "",
+            "pub type SCIP_C<'a> = &'a [u8];",
+        ]
+    );
+    assert_eq!(items[3].len(), 5);
+    let inner = feature_groups::split_items(&items[3][2..4]);
+    assert_eq!(inner.len(), 2);
+    assert_eq!(feature_groups::item_name(&inner[1]), Some("SCIPfoo"));
+}
@@ -0,0 +1,54 @@
+//! Checks the post-processing of the bindings in `header_modules.rs`.
+
+#[path = "../header_modules.rs"]
+#[allow(dead_code)]
+mod header_modules;
+
+const BINDINGS: &str = r#"pub const SCIP_Retcode_SCIP_OKAY: SCIP_Retcode = 1;
+pub type SCIP_Retcode = ::std::os::raw::c_int;
+unsafe extern "C" {
+    #[doc = " frees `x[i]` with [`SCIPfreeProb()`], see [`SCIPgone()`] and [`SCIP_MACRO`]\n\n # Returns\n\n * [`SCIP_OKAY`](SCIP_Retcode_SCIP_OKAY) - done, [`SCIP_GONE`](SCIP_Retcode_SCIP_GONE) - never"]
+    pub fn SCIPfree(scip: *mut *mut SCIP) -> SCIP_Retcode;
+}
+unsafe extern "C" {
+    #[doc = " the bound \\[`lb`\\] of [`SCIPfree()`]"]
+    pub fn SCIPfreeProb(scip: *mut SCIP) -> SCIP_Retcode;
+}
+"#;
+
+#[test]
+fn links_to_missing_items_become_code() {
+    let (bindings, unlinked) = header_modules::unlink_missing(BINDINGS);
+    assert_eq!(unlinked, 3);
+    assert!(bindings.contains(
+        r#"#[doc = " frees `x[i]` with [`SCIPfreeProb()`], see `SCIPgone()` and `SCIP_MACRO`\n\n # Returns\n\n * [`SCIP_OKAY`](SCIP_Retcode_SCIP_OKAY) - done, `SCIP_GONE` - never"]"#
+    ));
+    // escaped brackets are no link
+    assert!(bindings.contains(r#"#[doc = " the bound \\[`lb`\\] of [`SCIPfree()`]"]"#));
+    assert_eq!(
+        header_modules::unlink_missing(&bindings),
+        (bindings.clone(), 0)
+    );
+}
+
+#[test]
+fn bare_brackets_are_escaped() {
+    let bindings = header_modules::escape_brackets(BINDINGS);
+    // links, code spans and escaped brackets stay as they are
+    assert_eq!(bindings, BINDINGS);
+
+    let bindings = header_modules::escape_brackets(concat!(
+        r#"    #[doc = " the interval [lb,ub] of `x[i]`, \\[0\\] and coefs[0] of [`SCIPfree()`]"]"#,
+        "\n",
+        "pub const SCIP_ARRAY: [u8; 2] = [0, 1];\n",
+    ));
+    assert_eq!(
+        bindings,
+        concat!(
+            r#"    #[doc = " the interval \\[lb,ub\\] of `x[i]`, \\[0\\] and coefs\\[0\\] of [`SCIPfree()`]"]"#,
+            "\n",
+            "pub const SCIP_ARRAY: [u8; 2] = [0, 1];\n",
+        )
+    );
+    assert_eq!(header_modules::escape_brackets(&bindings), bindings);
+}
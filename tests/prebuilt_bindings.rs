@@ -0,0 +1,239 @@
+//! Checks the splitting of the committed bindings into `src/bindings/common.rs` and a file per
+//! target, that the committed files put together again, that they are for the release pinned in
+//! `versions.toml`, and that they match what the generator produces today.
+
+#[path = "../header_modules.rs"]
+#[allow(dead_code)]
+mod header_modules;
+#[path = "../prebuilt.rs"]
+#[allow(dead_code)]
+mod prebuilt;
+
+use scip_sys_build::manifest::Manifest;
+use scip_sys_build::ScipVersion;
+use std::fs;
+use std::path::Path;
+
+const LINUX: &str = "\
+/* automatically generated by rust-bindgen 0.72.1 */
+
+pub const SCIP_VERSION_MAJOR: u32 = 10;
+pub const __GLIBC__: u32 = 2;
+pub type SCIP_Longint = ::std::os::raw::c_long;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SCIP_Var {
+    _unused: [u8; 0],
+}
+unsafe extern \"C\" {
+    pub fn SCIPcreate(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+}
+unsafe extern \"C\" {
+    pub fn SCIPfree(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+}
+";
+
+const WINDOWS: &str = "\
+/* automatically generated by rust-bindgen 0.72.1 */
+
+pub const SCIP_VERSION_MAJOR: u32 = 10;
+pub type SCIP_Longint = ::std::os::raw::c_longlong;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SCIP_Var {
+    _unused: [u8; 0],
+}
+
+
+unsafe extern \"C\" {
+    pub fn SCIPfree(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+}
+unsafe extern \"C\" {
+    pub fn SCIPcreate(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+}
+pub const _WIN32: u32 = 1;";
+
+const PREGENERATED: &str = "\
+/* automatically generated by rust-bindgen 0.72.1 */
+/* Pre-generated bindings for docs.rs builds. */
+
+pub const SCIP_VERSION_MAJOR: u32 = 10;
+pub type SCIP_Longint = ::std::os::raw::c_long;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SCIP_Var {
+    _unused: [u8; 0],
+}
+unsafe extern \"C\" {
+    pub fn SCIPcreate(scip: *mut *mut SCIP) -> SCIP_RETCODE;
+}
+";
+
+#[test]
+fn split_bindings_assemble_to_the_original() {
+    let all = [LINUX, WINDOWS, PREGENERATED];
+    let (common, deltas) = prebuilt::split(&all);
+    assert_eq!(deltas.len(), all.len());
+    for (bindings, delta) in all.iter().zip(&deltas) {
+        assert_eq!(&prebuilt::assemble(&common, delta).unwrap(), bindings);
+    }
+
+    // the items all of them have are shared, whole and with their attributes
+    assert!(common.contains("pub const SCIP_VERSION_MAJOR: u32 = 10;\n"));
+    assert!(common.contains("#[repr(C)]\n#[derive(Debug, Copy, Clone)]\npub struct SCIP_Var {"));
+    for item in ["__GLIBC__", "_WIN32", "c_long", "docs.rs"] {
+        assert!(!common.contains(item), "{item} is not in all bindings");
+    }
+    assert!(deltas[1].contains("pub type SCIP_Longint = ::std::os::raw::c_longlong;\n"));
+    assert!(deltas.iter().all(|delta| delta.contains("//@ common ")));
+}
+
+#[test]
+fn split_bindings_are_stable() {
+    let all = [LINUX, WINDOWS, PREGENERATED];
+    let (common, deltas) = prebuilt::split(&all);
+    let assembled: Vec<String> = deltas
+        .iter()
+        .map(|delta| prebuilt::assemble(&common, delta).unwrap())
+        .collect();
+    let assembled: Vec<&str> = assembled.iter().map(String::as_str).collect();
+    assert_eq!(prebuilt::split(&assembled), (common, deltas));
+}
+
+#[test]
+fn broken_files_do_not_assemble() {
+    let common = "pub const A: u32 = 1;\npub const B: u32 = 2;\n";
+    assert_eq!(
+        prebuilt::assemble(common, "//@ common 2\npub const C: u32 = 3;\n").unwrap(),
+        "pub const A: u32 = 1;\npub const B: u32 = 2;\npub const C: u32 = 3;\n"
+    );
+    for delta in [
+        "//@ common 3\n",
+        "//@ common 1\n",
+        "//@ common two\n",
+        "pub const C: u32 = 3;\n",
+    ] {
+        assert!(prebuilt::assemble(common, delta).is_err(), "{delta:?}");
+    }
+}
+
+#[test]
+fn committed_bindings_assemble_and_split_as_committed() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    let mut all = Vec::new();
+    for name in prebuilt::NAMES {
+        let bindings = prebuilt::read(&dir, name)
+            .unwrap()
+            .unwrap_or_else(|| panic!("src/bindings/{name}.rs is missing"));
+        assert!(
+            bindings.contains("pub const SCIP_APIVERSION: u32 = "),
+            "the {name} bindings have no SCIP version"
+        );
+        all.push((name, bindings));
+    }
+
+    // splitting them again must not change the committed files
+    let resplit = Path::new(env!("CARGO_TARGET_TMPDIR")).join("scip-sys-prebuilt");
+    let _ = fs::remove_dir_all(&resplit);
+    prebuilt::write(&resplit, &all).unwrap();
+    for file in ["common"].into_iter().chain(prebuilt::NAMES) {
+        let file = format!("{file}.rs");
+        assert!(
+            fs::read(dir.join(&file)).unwrap() == fs::read(resplit.join(&file)).unwrap(),
+            "src/bindings/{file} is not split as the build script would"
+        );
+    }
+}
+
+#[test]
+fn committed_bindings_have_the_decl_aliases() {
+    // The bindings must come from the generator with the `decl_aliases.rs` wrapper header,
+    // the README's callback example relies on these aliases
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    for name in prebuilt::NAMES {
+        let bindings = prebuilt::read(&dir, name).unwrap().unwrap();
+        for alias in ["SCIP_DECL_HEUREXEC", "SCIP_DECL_EVENTEXEC"] {
+            assert!(
+                bindings.contains(&format!("pub type {alias} = ")),
+                "the {name} bindings have no `{alias}`, regenerate them with the \
+                 generate-bindings workflow"
+            );
+        }
+    }
+}
+
+#[test]
+fn pregenerated_bindings_follow_the_linux_ones() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    let linux = prebuilt::read(&dir, prebuilt::PREGENERATED_FROM)
+        .unwrap()
+        .unwrap();
+    let pregenerated = prebuilt::read(&dir, "pregenerated").unwrap().unwrap();
+    assert!(
+        pregenerated == prebuilt::pregenerated(&linux),
+        "the pregenerated bindings differ from the {} ones, import the bindings again",
+        prebuilt::PREGENERATED_FROM
+    );
+}
+
+#[test]
+fn committed_bindings_are_for_the_pinned_release() {
+    // the build only checks the bindings of its own target, see `manifest::check_bindings`
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let manifest = Manifest::read(&root.join("versions.toml")).unwrap();
+    let expected = manifest.scip_version().unwrap();
+    let dir = root.join("src").join("bindings");
+    for name in prebuilt::NAMES {
+        let bindings = prebuilt::read(&dir, name).unwrap().unwrap();
+        let version = ScipVersion::from_bindings(&bindings)
+            .unwrap_or_else(|| panic!("the {name} bindings have no SCIP version constants"));
+        assert_eq!(
+            version, expected,
+            "the {name} bindings are for SCIP {version}"
+        );
+    }
+}
+
+/// The `pub const SCIP_APIVERSION` of `bindings`.
+fn api_version(bindings: &str) -> Option<&str> {
+    bindings
+        .lines()
+        .find_map(|line| line.strip_prefix("pub const SCIP_APIVERSION: u32 = "))
+}
+
+#[test]
+fn generated_bindings_expose_the_committed_items() {
+    // Only a build for a target with committed bindings that generated all API groups for the
+    // same API version compares anything, e.g. CI's from-source job, which sets
+    // `SCIP_SYS_REGENERATE_BINDINGS`
+    let Some(name) = option_env!("SCIP_SYS_PREBUILT") else {
+        return;
+    };
+    if !cfg!(feature = "full") || cfg!(feature = "runtime-load") {
+        return;
+    }
+    let built = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    let committed = prebuilt::read(&dir, name).unwrap().unwrap();
+    if api_version(built).is_none() || api_version(built) != api_version(&committed) {
+        return;
+    }
+
+    let built = header_modules::binding_items(built);
+    let committed = header_modules::binding_items(&committed);
+    let missing: Vec<_> = committed.difference(&built).collect();
+    let added: Vec<_> = built.difference(&committed).collect();
+    assert!(
+        missing.is_empty() && added.is_empty(),
+        "the {name} bindings differ from the generated ones, regenerate them with the \
+         generate-bindings workflow\nonly committed: {missing:?}\nonly generated: {added:?}"
+    );
+}